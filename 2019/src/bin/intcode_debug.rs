@@ -0,0 +1,44 @@
+//! Opens the intcode debugger REPL on a program, see `common::intcode::debugger`.
+//!
+//! The program is the input of a day (`data/inputs/<day>.txt`) or any file with a program, e.g.
+//! `cargo run --bin intcode_debug -- 9` or `cargo run --bin intcode_debug -- path/to/program.txt`.
+use std::io;
+use std::{env, fs, process};
+
+use advent_of_code::common::intcode::{Computer, Debugger, Program};
+use advent_of_code::template::try_read_file;
+use advent_of_code::Day;
+
+fn read_program(arg: &str) -> io::Result<String> {
+    match arg.parse::<Day>() {
+        Ok(day) => try_read_file("inputs", day),
+        Err(_) => fs::read_to_string(arg),
+    }
+}
+
+fn main() {
+    let arg = match env::args().nth(1) {
+        Some(arg) => arg,
+        None => {
+            eprintln!("Usage: intcode_debug <day|file>");
+            process::exit(2);
+        }
+    };
+    let program = match read_program(&arg).map(|contents| Program::parse(contents.trim())) {
+        Ok(Ok(program)) => program,
+        Ok(Err(e)) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Error: failed to read {}: {}", arg, e);
+            process::exit(1);
+        }
+    };
+
+    let mut debugger = Debugger::new(Computer::new(&program));
+    if let Err(e) = debugger.repl(io::stdin().lock(), io::stdout()) {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
@@ -1,27 +1,48 @@
-use std::collections::HashMap;
-use std::str::FromStr;
-use std::fmt;
 use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
 
+use aoc_core::sparse::SparseGrid;
 
-#[derive(Debug)]
+use super::point::Point;
+
+/// A picture of characters that grows in every direction, unpainted cells are blank.
+#[derive(Debug, Clone, Default)]
 pub struct Canvas {
-    paint: HashMap<(isize, isize), char>
+    paint: SparseGrid<Point, char>,
 }
 
 impl Canvas {
     pub fn new() -> Canvas {
-        Canvas{
-            paint: HashMap::<(isize, isize), char>::new()
-        }
+        Canvas::default()
     }
 
     pub fn paint(&mut self, x: isize, y: isize, color: char) {
-        self.paint.insert((x, y), color);
+        self.paint_at(Point::new(x, y), color);
+    }
+
+    pub fn paint_at(&mut self, point: Point, color: char) {
+        self.paint.insert(point, color);
     }
 
     pub fn color_at(&self, x: isize, y: isize) -> char {
-        *self.paint.get(&(x, y)).unwrap_or(&' ')
+        self.color_at_point(Point::new(x, y))
+    }
+
+    pub fn color_at_point(&self, point: Point) -> char {
+        *self.paint.get(point).unwrap_or(&' ')
+    }
+
+    /// Every painted cell with its color.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, char)> + '_ {
+        self.paint.iter().map(|(point, color)| (point, *color))
+    }
+
+    /// The first painted cell with `color`.
+    pub fn find(&self, color: char) -> Option<Point> {
+        self.iter()
+            .find(|(_, c)| *c == color)
+            .map(|(point, _)| point)
     }
 }
 
@@ -29,10 +50,10 @@ impl FromStr for Canvas {
     type Err = Infallible;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut canvas = Canvas{paint: HashMap::new()};
+        let mut canvas = Canvas::new();
         let mut x = 0;
         let mut y = 0;
-        input.chars().for_each(|c|{
+        input.chars().for_each(|c| {
             if c == '\n' {
                 x = 0;
                 y += 1;
@@ -47,17 +68,6 @@ impl FromStr for Canvas {
 
 impl fmt::Display for Canvas {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let min_x = *self.paint.keys().map(|(x, _)| x).min().unwrap();
-        let max_x = *self.paint.keys().map(|(x, _)| x).max().unwrap();
-        let min_y = *self.paint.keys().map(|(_, y)| y).min().unwrap();
-        let max_y = *self.paint.keys().map(|(_, y)| y).max().unwrap();
-
-        let repr: String = (min_y..=max_y).map(|y| {
-            let line: String = (min_x..=max_x).map(|x| {
-                self.color_at(x, y)
-            }).collect();
-            format!("{}\n", line)
-        }).collect();
-        write!(f, "{}", repr)
-    }
-}
\ No newline at end of file
+        write!(f, "{}", self.paint.render(' '))
+    }
+}
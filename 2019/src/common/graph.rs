@@ -1,21 +1,24 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
-use std::cmp::min;
-use std::hash::Hash;
 use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::maze::PoiGraph;
 
 pub trait Neighbors<S>
-where S : Eq + Hash + Clone {
+where
+    S: Eq + Hash + Clone,
+{
     fn neighbors(&self, state: S) -> Vec<S>;
 }
 
-pub fn bfs<G, S>(
-    from: S, 
-    to: S, 
-    map: &G
-) -> Result<(usize, S), usize> 
-where S : Clone + Eq + Hash + Debug,
-G : Neighbors<S>
+pub fn bfs<G, S>(from: S, to: S, map: &G) -> Result<(usize, S), usize>
+where
+    S: Clone + Eq + Hash + Debug,
+    G: Neighbors<S>,
 {
     let mut visited: HashSet<S> = HashSet::new();
     let mut queue: VecDeque<(S, usize)> = VecDeque::new();
@@ -24,7 +27,7 @@ G : Neighbors<S>
 
     while !queue.is_empty() {
         let (point, distance) = queue.pop_front().unwrap();
-        
+
         for next_point in map.neighbors(point) {
             if next_point == to {
                 return Ok((distance + 1, next_point));
@@ -39,39 +42,295 @@ G : Neighbors<S>
     Err(0)
 }
 
-
-pub fn bfs_route<G, S>(
-    from: S, 
-    to: S, 
-    map: &G
-) -> Result<Vec<S>, usize> 
-where S : Clone + Eq + Hash + Debug,
-G : Neighbors<S>
+pub fn bfs_route<G, S>(from: S, to: S, map: &G) -> Result<Vec<S>, usize>
+where
+    S: Clone + Eq + Hash + Debug,
+    G: Neighbors<S>,
 {
     if from == to {
         return Ok(vec![from]);
     }
+    let mut predecessors: HashMap<S, S> = HashMap::new();
     let mut visited: HashSet<S> = HashSet::new();
-    let mut queue: VecDeque<Vec<S>> = VecDeque::new();
+    let mut queue: VecDeque<S> = VecDeque::new();
 
-    queue.push_back(vec![from]);
+    visited.insert(from.clone());
+    queue.push_back(from);
 
-    while !queue.is_empty() {
-        let partial_route = queue.pop_front().unwrap();
-        let point = partial_route.last().unwrap().to_owned();
-        
-        for next_point in map.neighbors(point) {
-            let mut next_route = partial_route.clone();
-            next_route.push(next_point.clone());
-            if next_point == to {
-                return Ok(next_route);
-            }
+    while let Some(point) = queue.pop_front() {
+        for next_point in map.neighbors(point.clone()) {
             if !visited.contains(&next_point) {
                 visited.insert(next_point.clone());
-                queue.push_back(next_route);
+                predecessors.insert(next_point.clone(), point.clone());
+                if next_point == to {
+                    return Ok(reconstruct(&predecessors, to));
+                }
+                queue.push_back(next_point);
             }
         }
     }
 
     Err(0)
 }
+
+pub trait WeightedNeighbors<S>
+where
+    S: Eq + Hash + Clone,
+{
+    /// The states reachable in one move, each with the cost of the move.
+    fn weighted_neighbors(&self, state: S) -> Vec<(S, usize)>;
+}
+
+/// A contracted maze is searched over its points of interest, every corridor is one move.
+impl<S> WeightedNeighbors<S> for PoiGraph<S>
+where
+    S: Copy + Eq + Hash,
+{
+    fn weighted_neighbors(&self, state: S) -> Vec<(S, usize)> {
+        self.edges(&state)
+    }
+}
+
+/// The distances found by a search from its sources, and the predecessor of every reached state on its
+/// shortest path, so paths are rebuilt on demand instead of being carried along during the search.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S>
+where
+    S: Eq + Hash,
+{
+    distances: HashMap<S, usize>,
+    predecessors: HashMap<S, S>,
+}
+
+impl<S> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash,
+{
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, usize> {
+        &self.distances
+    }
+
+    /// The shortest path from one of the sources to `to`, both included.
+    pub fn path(&self, to: &S) -> Option<Vec<S>> {
+        self.distances
+            .contains_key(to)
+            .then(|| reconstruct(&self.predecessors, to.clone()))
+    }
+}
+
+fn reconstruct<S>(predecessors: &HashMap<S, S>, to: S) -> Vec<S>
+where
+    S: Clone + Eq + Hash,
+{
+    let mut path = vec![to];
+    while let Some(previous) = predecessors.get(path.last().unwrap()) {
+        path.push(previous.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// A state in the queue, ordered so the `BinaryHeap` pops the lowest estimate first.
+struct Entry<S> {
+    estimate: usize,
+    cost: usize,
+    state: S,
+}
+
+impl<S> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<S> Eq for Entry<S> {}
+
+impl<S> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Entry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+/// A* from all `sources` at once, stopping once `to` is settled. Without a target every reachable state is settled.
+fn search<G, S, H>(sources: Vec<S>, to: Option<&S>, map: &G, heuristic: H) -> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash,
+    G: WeightedNeighbors<S>,
+    H: Fn(&S) -> usize,
+{
+    let mut distances: HashMap<S, usize> = HashMap::new();
+    let mut predecessors: HashMap<S, S> = HashMap::new();
+    let mut queue: BinaryHeap<Entry<S>> = BinaryHeap::new();
+
+    for source in sources {
+        distances.insert(source.clone(), 0);
+        queue.push(Entry {
+            estimate: heuristic(&source),
+            cost: 0,
+            state: source,
+        });
+    }
+
+    while let Some(Entry { cost, state, .. }) = queue.pop() {
+        if distances.get(&state).is_some_and(|best| cost > *best) {
+            continue;
+        }
+        if Some(&state) == to {
+            break;
+        }
+        for (next, step) in map.weighted_neighbors(state.clone()) {
+            let next_cost = cost + step;
+            if distances.get(&next).is_some_and(|best| next_cost >= *best) {
+                continue;
+            }
+            distances.insert(next.clone(), next_cost);
+            predecessors.insert(next.clone(), state.clone());
+            queue.push(Entry {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+
+    ShortestPaths {
+        distances,
+        predecessors,
+    }
+}
+
+/// Distances from the nearest of the `sources` to every reachable state.
+pub fn dijkstra<G, S, I>(sources: I, map: &G) -> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash,
+    G: WeightedNeighbors<S>,
+    I: IntoIterator<Item = S>,
+{
+    search(sources.into_iter().collect(), None, map, |_| 0)
+}
+
+/// The length and states of the shortest path from `from` to `to`.
+pub fn dijkstra_route<G, S>(from: S, to: S, map: &G) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    G: WeightedNeighbors<S>,
+{
+    astar(from, to, map, |_| 0)
+}
+
+/// Like [`dijkstra_route`], guided by a `heuristic` that estimates the remaining cost to `to`.
+/// The path is only guaranteed to be shortest if the heuristic never overestimates.
+pub fn astar<G, S, H>(from: S, to: S, map: &G, heuristic: H) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    G: WeightedNeighbors<S>,
+    H: Fn(&S) -> usize,
+{
+    let paths = search(vec![from], Some(&to), map, heuristic);
+    Some((paths.distance(&to)?, paths.path(&to)?))
+}
+
+/// Shortest distances between every ordered pair of the `points`, pairs without a path are left out.
+pub fn all_pairs<G, S>(points: &[S], map: &G) -> HashMap<(S, S), usize>
+where
+    S: Clone + Eq + Hash,
+    G: WeightedNeighbors<S>,
+{
+    let mut distances: HashMap<(S, S), usize> = HashMap::new();
+    for from in points {
+        let paths = dijkstra(vec![from.clone()], map);
+        for to in points {
+            if let Some(distance) = paths.distance(to) {
+                distances.insert((from.clone(), to.clone()), distance);
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A directed graph with numbered nodes.
+    struct Edges(Vec<(usize, usize, usize)>);
+
+    impl WeightedNeighbors<usize> for Edges {
+        fn weighted_neighbors(&self, state: usize) -> Vec<(usize, usize)> {
+            self.0
+                .iter()
+                .filter(|(from, _, _)| *from == state)
+                .map(|(_, to, cost)| (*to, *cost))
+                .collect()
+        }
+    }
+
+    impl Neighbors<usize> for Edges {
+        fn neighbors(&self, state: usize) -> Vec<usize> {
+            self.weighted_neighbors(state)
+                .into_iter()
+                .map(|(to, _)| to)
+                .collect()
+        }
+    }
+
+    fn edges() -> Edges {
+        // 0 -> 1 -> 2 -> 3 is cheaper than the direct 0 -> 3, 4 is only reachable from itself
+        Edges(vec![
+            (0, 1, 1),
+            (1, 2, 2),
+            (2, 3, 3),
+            (0, 3, 10),
+            (1, 3, 7),
+            (4, 0, 1),
+        ])
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let paths = dijkstra(vec![0], &edges());
+        assert_eq!(paths.distance(&3), Some(6));
+        assert_eq!(paths.path(&3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(paths.path(&0), Some(vec![0]));
+        assert_eq!(paths.distance(&4), None);
+        assert_eq!(paths.path(&4), None);
+
+        let paths = dijkstra(vec![0, 2], &edges());
+        assert_eq!(paths.path(&3), Some(vec![2, 3]));
+    }
+
+    #[test]
+    fn test_routes() {
+        assert_eq!(dijkstra_route(0, 3, &edges()), Some((6, vec![0, 1, 2, 3])));
+        assert_eq!(dijkstra_route(3, 0, &edges()), None);
+        // the heuristic prefers the expensive direct edge, but never overestimates
+        let heuristic = |state: &usize| if *state == 3 { 0 } else { 1 };
+        assert_eq!(
+            astar(0, 3, &edges(), heuristic),
+            Some((6, vec![0, 1, 2, 3]))
+        );
+        assert_eq!(bfs_route(0, 3, &edges()), Ok(vec![0, 3]));
+        assert_eq!(bfs_route(0, 0, &edges()), Ok(vec![0]));
+        assert_eq!(bfs_route(3, 0, &edges()), Err(0));
+    }
+
+    #[test]
+    fn test_all_pairs() {
+        let distances = all_pairs(&[0, 2, 4], &edges());
+        assert_eq!(distances.get(&(0, 2)), Some(&3));
+        assert_eq!(distances.get(&(4, 2)), Some(&4));
+        assert_eq!(distances.get(&(2, 2)), Some(&0));
+        assert_eq!(distances.get(&(2, 0)), None);
+        assert_eq!(distances.len(), 6);
+    }
+}
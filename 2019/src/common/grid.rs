@@ -1,15 +1,15 @@
 use std::char;
+use std::convert::Infallible;
 use std::fmt;
 use std::fmt::Display;
-use std::str::FromStr;
-use std::convert::Infallible;
 use std::hash::Hash;
+use std::str::FromStr;
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Grid {
     pub data: Vec<Vec<char>>,
     pub width: usize,
-    pub height: usize
+    pub height: usize,
 }
 
 impl Grid {
@@ -18,15 +18,20 @@ impl Grid {
     }
 
     pub fn get(&self, x: usize, y: usize) -> Option<char> {
-        self.data.get(y).map(|a| a.get(x).map(|c| *c)).flatten()
+        self.data.get(y).and_then(|a| a.get(x).copied())
     }
 
     pub fn get_unsafe(&self, x: usize, y: usize) -> char {
-        self.data.get(y).map(|a| a.get(x).map(|c| *c)).flatten().unwrap_or(' ')
+        self.data
+            .get(y)
+            .and_then(|a| a.get(x).copied())
+            .unwrap_or(' ')
     }
 
     pub fn coordinates(&self) -> Vec<(usize, usize)> {
-        (0..self.width).flat_map(|x| (0..self.height).map(move |y| (x, y))).collect()
+        (0..self.width)
+            .flat_map(|x| (0..self.height).map(move |y| (x, y)))
+            .collect()
     }
 }
 
@@ -40,20 +45,24 @@ impl FromStr for Grid {
             .collect();
         let width = data.iter().map(|a| a.len()).max().unwrap();
         let height = data.len();
-        Ok(Grid{
-            data: data,
-            width: width,
-            height: height
+        Ok(Grid {
+            data,
+            width,
+            height,
         })
     }
 }
 
 impl Display for Grid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let repr: String = self.data.iter().map(|line| {
-            let line_str: String = line.iter().collect();
-            format!("{}\n", line_str)
-        }).collect();
+        let repr: String = self
+            .data
+            .iter()
+            .map(|line| {
+                let line_str: String = line.iter().collect();
+                format!("{}\n", line_str)
+            })
+            .collect();
         write!(f, "{}", repr)
     }
 }
@@ -0,0 +1,417 @@
+//! A small assembly language for Intcode, assembled into a [`Program`] with [`Program::assemble`].
+//!
+//! ```text
+//! ; prints the numbers from 3 down to 1
+//!         add  #3, #0, counter
+//! loop:   out  counter
+//!         add  counter, #-1, counter
+//!         jt   counter, #loop
+//!         hlt
+//! counter: data 0
+//! ```
+//!
+//! Every line holds an optional label, an instruction or a `data` directive and an optional `;` comment.
+//! Operands use the sigils of the disassembler: `12` is position mode, `#12` immediate and `@12` relative mode.
+//! Instead of a number, an operand can name a label, optionally with an offset like `loop+1`. A line can also
+//! start with an address like `12:`, as printed by the disassembler, which is checked against the assembled
+//! address, so disassembled listings assemble back into the original program.
+use std::collections::HashMap;
+use std::fmt;
+
+use super::{OpCode, ParameterMode, Program};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssembleError {
+    UnknownMnemonic {
+        line: usize,
+        mnemonic: String,
+    },
+    WrongOperandCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    InvalidOperand {
+        line: usize,
+        operand: String,
+    },
+    InvalidLabel {
+        line: usize,
+        label: String,
+    },
+    DuplicateLabel {
+        line: usize,
+        label: String,
+    },
+    UnknownLabel {
+        line: usize,
+        label: String,
+    },
+    AddressMismatch {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for AssembleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AssembleError::UnknownMnemonic { line, mnemonic } => {
+                write!(f, "line {}: unknown mnemonic `{}`", line, mnemonic)
+            }
+            AssembleError::WrongOperandCount {
+                line,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "line {}: expected {} operand(s), found {}",
+                    line, expected, found
+                )
+            }
+            AssembleError::InvalidOperand { line, operand } => {
+                write!(f, "line {}: invalid operand `{}`", line, operand)
+            }
+            AssembleError::InvalidLabel { line, label } => {
+                write!(f, "line {}: invalid label `{}`", line, label)
+            }
+            AssembleError::DuplicateLabel { line, label } => {
+                write!(f, "line {}: label `{}` is already defined", line, label)
+            }
+            AssembleError::UnknownLabel { line, label } => {
+                write!(f, "line {}: unknown label `{}`", line, label)
+            }
+            AssembleError::AddressMismatch {
+                line,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "line {}: line is at address {}, not {}",
+                    line, found, expected
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AssembleError {}
+
+/// A number or a label with an offset, resolved once all labels are known.
+#[derive(Debug)]
+enum Value {
+    Number(i128),
+    Label(String, i128),
+}
+
+#[derive(Debug)]
+struct Operand {
+    mode: ParameterMode,
+    value: Value,
+}
+
+#[derive(Debug)]
+enum Statement {
+    Instruction(OpCode, Vec<Operand>),
+    Data(Vec<Value>),
+}
+
+impl Statement {
+    fn len(&self) -> usize {
+        match self {
+            Statement::Instruction(_, operands) => 1 + operands.len(),
+            Statement::Data(values) => values.len(),
+        }
+    }
+}
+
+impl Program {
+    pub fn assemble(source: &str) -> Result<Program, AssembleError> {
+        let mut labels: HashMap<String, usize> = HashMap::new();
+        let mut statements: Vec<(usize, Statement)> = Vec::new();
+        let mut address = 0;
+
+        for (index, text) in source.lines().enumerate() {
+            let line = index + 1;
+            let mut text = text.split(';').next().unwrap().trim();
+
+            while let Some((label, rest)) = split_label(text) {
+                if let Ok(expected) = label.parse::<usize>() {
+                    if expected != address {
+                        return Err(AssembleError::AddressMismatch {
+                            line,
+                            expected,
+                            found: address,
+                        });
+                    }
+                } else if !is_identifier(label) {
+                    return Err(AssembleError::InvalidLabel {
+                        line,
+                        label: label.to_string(),
+                    });
+                } else if labels.insert(label.to_string(), address).is_some() {
+                    return Err(AssembleError::DuplicateLabel {
+                        line,
+                        label: label.to_string(),
+                    });
+                }
+                text = rest;
+            }
+
+            if text.is_empty() {
+                continue;
+            }
+            let statement = parse_statement(line, text)?;
+            address += statement.len();
+            statements.push((line, statement));
+        }
+
+        let mut program = Vec::with_capacity(address);
+        for (line, statement) in statements {
+            match statement {
+                Statement::Instruction(operation, operands) => {
+                    let modes = operands
+                        .iter()
+                        .rev()
+                        .fold(0, |modes, o| modes * 10 + o.mode.code());
+                    program.push(modes * 100 + operation.code());
+                    for operand in operands {
+                        program.push(resolve(line, operand.value, &labels)?);
+                    }
+                }
+                Statement::Data(values) => {
+                    for value in values {
+                        program.push(resolve(line, value, &labels)?);
+                    }
+                }
+            }
+        }
+
+        Ok(Program { program })
+    }
+}
+
+/// Splits `label: rest` off a line, the colon has to come before any operand.
+fn split_label(text: &str) -> Option<(&str, &str)> {
+    let (label, rest) = text.split_once(':')?;
+    let label = label.trim();
+    (!label.is_empty() && !label.contains(char::is_whitespace)).then(|| (label, rest.trim()))
+}
+
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn parse_statement(line: usize, text: &str) -> Result<Statement, AssembleError> {
+    let (mnemonic, rest) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    let operands: Vec<&str> = rest
+        .split(',')
+        .map(str::trim)
+        .filter(|o| !o.is_empty())
+        .collect();
+
+    if mnemonic == "data" {
+        let values = operands
+            .iter()
+            .map(|o| {
+                parse_value(o).ok_or_else(|| AssembleError::InvalidOperand {
+                    line,
+                    operand: o.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        return Ok(Statement::Data(values));
+    }
+
+    let operation =
+        OpCode::from_mnemonic(mnemonic).ok_or_else(|| AssembleError::UnknownMnemonic {
+            line,
+            mnemonic: mnemonic.to_string(),
+        })?;
+    if operands.len() != operation.parameter_count() {
+        return Err(AssembleError::WrongOperandCount {
+            line,
+            expected: operation.parameter_count(),
+            found: operands.len(),
+        });
+    }
+    let operands = operands
+        .iter()
+        .map(|o| {
+            parse_operand(o).ok_or_else(|| AssembleError::InvalidOperand {
+                line,
+                operand: o.to_string(),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Statement::Instruction(operation, operands))
+}
+
+fn parse_operand(text: &str) -> Option<Operand> {
+    let (mode, value) = if let Some(value) = text.strip_prefix('#') {
+        (ParameterMode::Immediate, value)
+    } else if let Some(value) = text.strip_prefix('@') {
+        (ParameterMode::Relative, value)
+    } else {
+        (ParameterMode::Position, text)
+    };
+    Some(Operand {
+        mode,
+        value: parse_value(value.trim())?,
+    })
+}
+
+fn parse_value(text: &str) -> Option<Value> {
+    if let Ok(number) = text.parse() {
+        return Some(Value::Number(number));
+    }
+    let (label, offset) = match text.find(['+', '-']) {
+        Some(i) => {
+            let offset: i128 = text[i + 1..].trim().parse().ok()?;
+            (
+                text[..i].trim(),
+                if &text[i..=i] == "-" { -offset } else { offset },
+            )
+        }
+        None => (text, 0),
+    };
+    is_identifier(label).then(|| Value::Label(label.to_string(), offset))
+}
+
+fn resolve(
+    line: usize,
+    value: Value,
+    labels: &HashMap<String, usize>,
+) -> Result<i128, AssembleError> {
+    match value {
+        Value::Number(number) => Ok(number),
+        Value::Label(label, offset) => match labels.get(&label) {
+            Some(address) => (*address as i128).checked_add(offset).ok_or_else(|| {
+                AssembleError::InvalidOperand {
+                    line,
+                    operand: format!("{label}{offset:+}"),
+                }
+            }),
+            None => Err(AssembleError::UnknownLabel { line, label }),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::intcode::Computer;
+
+    #[test]
+    fn test_countdown() {
+        let program = Program::assemble(
+            "
+            ; prints the numbers from 3 down to 1
+                    add  #3, #0, counter
+            loop:   out  counter
+                    add  counter, #-1, counter
+                    jt   counter, #loop
+                    hlt
+            counter: data 0
+        ",
+        )
+        .unwrap();
+        assert_eq!(
+            program.program,
+            vec![1101, 3, 0, 14, 4, 14, 1001, 14, -1, 14, 1005, 14, 4, 99, 0]
+        );
+        assert_eq!(Computer::new(&program).run(vec![]).unwrap(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_label_offsets() {
+        // patches the immediate operand of the `out` instruction before running it
+        let program = Program::assemble(
+            "
+                    in   print+1
+            print:  out  #0
+                    hlt
+        ",
+        )
+        .unwrap();
+        assert_eq!(program.program, vec![3, 3, 104, 0, 99]);
+        assert_eq!(Computer::new(&program).run(vec![42]).unwrap(), vec![42]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            Program::assemble("add #1, #2"),
+            Err(AssembleError::WrongOperandCount {
+                line: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Program::assemble("hlt\nnop"),
+            Err(AssembleError::UnknownMnemonic {
+                line: 2,
+                mnemonic: "nop".into()
+            })
+        );
+        assert_eq!(
+            Program::assemble("jt #1, #nowhere"),
+            Err(AssembleError::UnknownLabel {
+                line: 1,
+                label: "nowhere".into()
+            })
+        );
+        assert_eq!(
+            Program::assemble("a: hlt\na: hlt"),
+            Err(AssembleError::DuplicateLabel {
+                line: 2,
+                label: "a".into()
+            })
+        );
+        assert_eq!(
+            Program::assemble("out $1"),
+            Err(AssembleError::InvalidOperand {
+                line: 1,
+                operand: "$1".into()
+            })
+        );
+        assert_eq!(
+            Program::assemble("hlt\ndata a+170141183460469231731687303715884105727\na: hlt"),
+            Err(AssembleError::InvalidOperand {
+                line: 2,
+                operand: "a+170141183460469231731687303715884105727".into()
+            })
+        );
+        assert_eq!(
+            Program::assemble("0: hlt\n2: hlt"),
+            Err(AssembleError::AddressMismatch {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_round_trip_puzzle_programs() {
+        for day in ["09", "13", "17", "21", "25"] {
+            let input = std::fs::read_to_string(format!("data/inputs/{}.txt", day)).unwrap();
+            let program = Program::parse(&input).unwrap();
+            let listing = program.disassemble().to_string();
+            assert_eq!(
+                Program::assemble(&listing).unwrap().program,
+                program.program,
+                "day {}",
+                day
+            );
+        }
+    }
+}
@@ -0,0 +1,569 @@
+//! Runs a [`Computer`] under control of a [`Debugger`]: breakpoints on addresses or opcodes, watchpoints on
+//! memory cells, single stepping, patching memory and tracing every executed instruction.
+//!
+//! The debugger can be driven from code, e.g. in tests, or interactively with [`Debugger::repl`]:
+//!
+//! ```text
+//! let mut debugger = Debugger::new(Computer::new(&program));
+//! debugger.repl(io::stdin().lock(), io::stdout())?;
+//! ```
+//!
+//! The `intcode_debug` binary opens the REPL on the input of a day, e.g. `cargo run --bin intcode_debug -- 9`.
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::path::Path;
+
+use super::{Computer, ComputerState, Instruction, IntcodeError, Line, OpCode, MEMORY_LIMIT};
+
+/// Why the debugger handed control back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stop {
+    /// A single step was executed.
+    Step,
+    /// The next instruction is at a breakpoint.
+    Breakpoint(usize),
+    /// The next instruction has an opcode with a breakpoint.
+    OpCode(OpCode),
+    /// The last instruction changed a watched memory cell.
+    Watchpoint {
+        address: usize,
+        old: i128,
+        new: i128,
+    },
+    /// The program waits for input.
+    Paused,
+    Halted,
+    /// The next instruction can not be executed.
+    Error(IntcodeError),
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stop::Step => write!(f, "step"),
+            Stop::Breakpoint(address) => write!(f, "breakpoint at {}", address),
+            Stop::OpCode(operation) => write!(f, "breakpoint on {}", operation.mnemonic()),
+            Stop::Watchpoint { address, old, new } => {
+                write!(f, "watchpoint {}: {} -> {}", address, old, new)
+            }
+            Stop::Paused => write!(f, "waiting for input"),
+            Stop::Halted => write!(f, "halted"),
+            Stop::Error(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+pub struct Debugger {
+    computer: Computer,
+    breakpoints: HashSet<usize>,
+    opcode_breakpoints: HashSet<OpCode>,
+    watchpoints: HashSet<usize>,
+    trace: Option<Box<dyn Write>>,
+}
+
+impl Debugger {
+    pub fn new(computer: Computer) -> Debugger {
+        Debugger {
+            computer,
+            breakpoints: HashSet::new(),
+            opcode_breakpoints: HashSet::new(),
+            watchpoints: HashSet::new(),
+            trace: None,
+        }
+    }
+
+    pub fn computer(&self) -> &Computer {
+        &self.computer
+    }
+
+    pub fn computer_mut(&mut self) -> &mut Computer {
+        &mut self.computer
+    }
+
+    pub fn into_computer(self) -> Computer {
+        self.computer
+    }
+
+    pub fn add_breakpoint(&mut self, address: usize) {
+        self.breakpoints.insert(address);
+    }
+
+    pub fn remove_breakpoint(&mut self, address: usize) {
+        self.breakpoints.remove(&address);
+    }
+
+    pub fn break_on(&mut self, operation: OpCode) {
+        self.opcode_breakpoints.insert(operation);
+    }
+
+    pub fn remove_break_on(&mut self, operation: OpCode) {
+        self.opcode_breakpoints.remove(&operation);
+    }
+
+    pub fn watch(&mut self, address: usize) {
+        self.watchpoints.insert(address);
+    }
+
+    pub fn unwatch(&mut self, address: usize) {
+        self.watchpoints.remove(&address);
+    }
+
+    /// Writes every executed instruction with the relative base to `writer`, `None` stops tracing.
+    pub fn trace_to(&mut self, writer: Option<Box<dyn Write>>) {
+        self.trace = writer;
+    }
+
+    pub fn trace_to_file<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let file = File::create(path)?;
+        self.trace_to(Some(Box::new(BufWriter::new(file))));
+        Ok(())
+    }
+
+    /// Writes `values` to memory starting at `address`, growing the memory if needed.
+    /// Like a write of the program, the patch may not reach past the memory limit.
+    pub fn patch(&mut self, address: usize, values: &[i128]) -> Result<(), IntcodeError> {
+        let end = address
+            .checked_add(values.len())
+            .filter(|end| *end <= MEMORY_LIMIT)
+            .ok_or(IntcodeError::AddressTooLarge {
+                pc: self.computer.pc(),
+                address: address.max(MEMORY_LIMIT) as i128,
+            })?;
+        let memory = &mut self.computer.memory;
+        if memory.len() < end {
+            memory.resize(end, 0);
+        }
+        memory[address..end].copy_from_slice(values);
+        Ok(())
+    }
+
+    /// The instruction at the program counter.
+    pub fn current(&self) -> Line {
+        Line::decode(&self.computer.memory, self.computer.pc())
+    }
+
+    /// Executes a single instruction, ignoring breakpoints.
+    pub fn step(&mut self) -> Stop {
+        if self.computer.is_halted() {
+            return Stop::Halted;
+        }
+        if let Some(trace) = self.trace.as_mut() {
+            let line = Line::decode(&self.computer.memory, self.computer.pc());
+            // a failing trace must not change how the program runs
+            let _ = writeln!(trace, "{}  ; rb={}", line, self.computer.relative_base());
+        }
+
+        let load = |computer: &Computer, address: usize| {
+            computer.memory.get(address).copied().unwrap_or(0)
+        };
+        let watched: HashMap<usize, i128> = self
+            .watchpoints
+            .iter()
+            .map(|address| (*address, load(&self.computer, *address)))
+            .collect();
+
+        match self.computer.step() {
+            Ok(ComputerState::Halted) => return Stop::Halted,
+            Ok(ComputerState::Paused) => return Stop::Paused,
+            Ok(ComputerState::Idle) => {}
+            Err(error) => return Stop::Error(error),
+        }
+
+        let mut changed: Vec<(usize, i128)> = watched
+            .into_iter()
+            .filter(|(address, old)| load(&self.computer, *address) != *old)
+            .collect();
+        changed.sort_unstable();
+        match changed.first() {
+            Some((address, old)) => Stop::Watchpoint {
+                address: *address,
+                old: *old,
+                new: load(&self.computer, *address),
+            },
+            None => Stop::Step,
+        }
+    }
+
+    /// Executes until a breakpoint or watchpoint is hit, the program needs input, halts or fails.
+    /// At least one instruction is executed, so resuming from a breakpoint moves past it.
+    pub fn resume(&mut self) -> Stop {
+        loop {
+            let stop = self.step();
+            if stop != Stop::Step {
+                return stop;
+            }
+            let pc = self.computer.pc();
+            if self.breakpoints.contains(&pc) {
+                return Stop::Breakpoint(pc);
+            }
+            let instruction = self
+                .computer
+                .memory
+                .get(pc)
+                .and_then(|x| Instruction::decode(*x));
+            if let Some(instruction) = instruction {
+                if self.opcode_breakpoints.contains(&instruction.operation) {
+                    return Stop::OpCode(instruction.operation);
+                }
+            }
+        }
+    }
+
+    /// Reads commands from `input` until it ends or `quit` is entered, see `help` for the commands.
+    pub fn repl<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        writeln!(output, "{}", self.current())?;
+        for line in input.lines() {
+            let line = line?;
+            let mut words = line.split_whitespace();
+            let command = match words.next() {
+                Some(command) => command,
+                None => continue,
+            };
+            let args: Vec<&str> = words.collect();
+            match self.command(command, &args, &mut output) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(message) => writeln!(output, "error: {}", message)?,
+            }
+            output.flush()?;
+        }
+        self.flush_trace()
+    }
+
+    fn flush_trace(&mut self) -> io::Result<()> {
+        match self.trace.as_mut() {
+            Some(trace) => trace.flush(),
+            None => Ok(()),
+        }
+    }
+
+    /// Runs a REPL command, `Ok(false)` ends the session.
+    fn command<W: Write>(
+        &mut self,
+        command: &str,
+        args: &[&str],
+        output: &mut W,
+    ) -> Result<bool, String> {
+        let number = |i: usize| -> Result<i128, String> {
+            let arg = args
+                .get(i)
+                .ok_or_else(|| format!("missing argument {}", i + 1))?;
+            arg.parse()
+                .map_err(|_| format!("`{}` is not a number", arg))
+        };
+        let address = |i: usize| -> Result<usize, String> {
+            let value = number(i)?;
+            if value < 0 {
+                return Err(format!("{} is not an address", value));
+            }
+            Ok(value as usize)
+        };
+        let io = |e: io::Error| e.to_string();
+
+        match command {
+            "s" | "step" => {
+                let count = if args.is_empty() { 1 } else { address(0)? };
+                let mut stop = Stop::Step;
+                for _ in 0..count {
+                    stop = self.step();
+                    if stop != Stop::Step {
+                        break;
+                    }
+                }
+                self.report(stop, output).map_err(io)?;
+            }
+            "c" | "continue" => {
+                let stop = self.resume();
+                self.report(stop, output).map_err(io)?;
+            }
+            "b" | "break" | "d" | "delete" => {
+                let is_adding = command.starts_with('b');
+                let target = args.first().ok_or("missing address or mnemonic")?;
+                match (target.parse::<usize>(), OpCode::from_mnemonic(target)) {
+                    (Ok(address), _) if is_adding => self.add_breakpoint(address),
+                    (Ok(address), _) => self.remove_breakpoint(address),
+                    (_, Some(operation)) if is_adding => self.break_on(operation),
+                    (_, Some(operation)) => self.remove_break_on(operation),
+                    _ => return Err(format!("`{}` is neither an address nor a mnemonic", target)),
+                }
+            }
+            "w" | "watch" => self.watch(address(0)?),
+            "uw" | "unwatch" => self.unwatch(address(0)?),
+            "r" | "regs" => {
+                writeln!(
+                    output,
+                    "pc={} rb={} state={:?}",
+                    self.computer.pc(),
+                    self.computer.relative_base(),
+                    self.computer.state
+                )
+                .map_err(io)?;
+            }
+            "x" | "examine" => {
+                let start = address(0)?;
+                // memory never grows past the limit, so neither does the range worth printing
+                let count = if args.len() > 1 { address(1)? } else { 1 }.min(MEMORY_LIMIT);
+                let values: Vec<String> = (start..start.saturating_add(count))
+                    .map(|a| {
+                        self.computer
+                            .memory
+                            .get(a)
+                            .copied()
+                            .unwrap_or(0)
+                            .to_string()
+                    })
+                    .collect();
+                writeln!(output, "{}: {}", start, values.join(", ")).map_err(io)?;
+            }
+            "l" | "list" => {
+                let mut at = if args.is_empty() {
+                    self.computer.pc()
+                } else {
+                    address(0)?
+                };
+                let count = if args.len() > 1 { address(1)? } else { 5 }.min(MEMORY_LIMIT);
+                for _ in 0..count {
+                    let line = Line::decode(&self.computer.memory, at);
+                    writeln!(output, "{}", line).map_err(io)?;
+                    match at.checked_add(line.len()) {
+                        Some(next) => at = next,
+                        None => break,
+                    }
+                }
+            }
+            "p" | "patch" => {
+                let start = address(0)?;
+                let values = (1..args.len().max(2))
+                    .map(number)
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.patch(start, &values).map_err(|e| e.to_string())?;
+            }
+            "i" | "input" => {
+                for i in 0..args.len() {
+                    self.computer.write_input(number(i)?);
+                }
+            }
+            "a" | "ascii" => {
+                let text = args.join(" ");
+                text.chars()
+                    .chain(Some('\n'))
+                    .for_each(|c| self.computer.write_input(c as i128));
+            }
+            "o" | "output" => {
+                let values = self.computer.drain_output();
+                let is_ascii =
+                    !values.is_empty() && values.iter().all(|v| *v == 10 || (32..127).contains(v));
+                if is_ascii {
+                    let text: String = values.iter().map(|v| *v as u8 as char).collect();
+                    write!(output, "{}", text).map_err(io)?;
+                } else {
+                    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                    writeln!(output, "{}", values.join(", ")).map_err(io)?;
+                }
+            }
+            "t" | "trace" => match args.first() {
+                Some(&"off") | None => {
+                    self.flush_trace().map_err(io)?;
+                    self.trace_to(None);
+                }
+                Some(path) => self.trace_to_file(path).map_err(io)?,
+            },
+            "q" | "quit" => return Ok(false),
+            "h" | "help" => {
+                write!(output, "{}", HELP).map_err(io)?;
+            }
+            _ => return Err(format!("unknown command `{}`, try `help`", command)),
+        }
+        Ok(true)
+    }
+
+    fn report<W: Write>(&self, stop: Stop, output: &mut W) -> io::Result<()> {
+        if stop != Stop::Step {
+            writeln!(output, "{}", stop)?;
+        }
+        writeln!(output, "{}", self.current())
+    }
+}
+
+const HELP: &str = "\
+s, step [n]              execute n instructions (1)
+c, continue              run until a breakpoint, a watchpoint, missing input or halt
+b, break <addr|mnemonic> break at an address or on every instruction with the opcode
+d, delete <addr|mnemonic> remove a breakpoint
+w, watch <addr>          stop when the memory cell changes
+uw, unwatch <addr>       remove a watchpoint
+r, regs                  show pc, relative base and state
+x, examine <addr> [n]    show n memory cells (1)
+l, list [addr] [n]       disassemble n instructions from addr (pc, 5)
+p, patch <addr> <values> write values to memory
+i, input <values>        queue input values
+a, ascii <text>          queue text and a newline as input
+o, output                show and clear the output
+t, trace <file|off>      write every executed instruction to a file
+q, quit
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::intcode::Program;
+
+    fn countdown() -> Computer {
+        Computer::new(
+            &Program::assemble(
+                "
+                    add  #3, #0, counter
+            loop:   out  counter
+                    add  counter, #-1, counter
+                    jt   counter, #loop
+                    hlt
+            counter: data 0
+        ",
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_breakpoint() {
+        let mut debugger = Debugger::new(countdown());
+        debugger.add_breakpoint(4);
+        assert_eq!(debugger.resume(), Stop::Breakpoint(4));
+        assert_eq!(debugger.resume(), Stop::Breakpoint(4));
+        assert_eq!(debugger.computer().memory[14], 2);
+        debugger.remove_breakpoint(4);
+        assert_eq!(debugger.resume(), Stop::Halted);
+        assert_eq!(debugger.computer_mut().drain_output(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_opcode_breakpoint() {
+        let mut debugger = Debugger::new(countdown());
+        debugger.break_on(OpCode::Output);
+        assert_eq!(debugger.resume(), Stop::OpCode(OpCode::Output));
+        assert_eq!(debugger.computer().pc(), 4);
+        assert_eq!(debugger.step(), Stop::Step);
+        assert_eq!(debugger.computer_mut().read_output(), Some(3));
+    }
+
+    #[test]
+    fn test_watchpoint() {
+        let mut debugger = Debugger::new(countdown());
+        debugger.watch(14);
+        assert_eq!(
+            debugger.resume(),
+            Stop::Watchpoint {
+                address: 14,
+                old: 0,
+                new: 3
+            }
+        );
+        assert_eq!(
+            debugger.resume(),
+            Stop::Watchpoint {
+                address: 14,
+                old: 3,
+                new: 2
+            }
+        );
+        assert_eq!(debugger.computer().pc(), 10);
+    }
+
+    #[test]
+    fn test_patch_before_resuming() {
+        let mut debugger = Debugger::new(countdown());
+        debugger.add_breakpoint(4);
+        debugger.resume();
+        debugger.patch(14, &[1]).unwrap();
+        assert_eq!(debugger.resume(), Stop::Halted);
+        assert_eq!(debugger.computer_mut().drain_output(), vec![1]);
+    }
+
+    #[test]
+    fn test_patch_past_memory_limit() {
+        let mut debugger = Debugger::new(countdown());
+        assert_eq!(
+            debugger.patch(MEMORY_LIMIT - 1, &[1, 2]),
+            Err(IntcodeError::AddressTooLarge {
+                pc: 0,
+                address: MEMORY_LIMIT as i128
+            })
+        );
+        assert_eq!(
+            debugger.patch(usize::MAX, &[1]),
+            Err(IntcodeError::AddressTooLarge {
+                pc: 0,
+                address: usize::MAX as i128
+            })
+        );
+        assert_eq!(debugger.computer().memory.len(), 15);
+        debugger.patch(MEMORY_LIMIT - 1, &[1]).unwrap();
+        assert_eq!(debugger.computer().memory.len(), MEMORY_LIMIT);
+    }
+
+    #[test]
+    fn test_paused_on_input() {
+        let program = Program::assemble("in 5\nout 5\nhlt").unwrap();
+        let mut debugger = Debugger::new(Computer::new(&program));
+        assert_eq!(debugger.resume(), Stop::Paused);
+        debugger.computer_mut().write_input(7);
+        assert_eq!(debugger.resume(), Stop::Halted);
+        assert_eq!(debugger.computer_mut().drain_output(), vec![7]);
+    }
+
+    #[test]
+    fn test_error() {
+        let program = Program::assemble("out #1\ndata 42").unwrap();
+        let mut debugger = Debugger::new(Computer::new(&program));
+        let stop = debugger.resume();
+        assert_eq!(
+            stop,
+            Stop::Error(IntcodeError::InvalidOpCode { pc: 2, value: 42 })
+        );
+        assert_eq!(stop.to_string(), "pc 2: invalid opcode in instruction 42");
+        assert_eq!(debugger.computer().pc(), 2);
+    }
+
+    #[test]
+    fn test_trace() {
+        let path = std::env::temp_dir().join(format!("intcode-trace-{}.txt", std::process::id()));
+        let mut debugger = Debugger::new(countdown());
+        debugger.trace_to_file(&path).unwrap();
+        debugger.resume();
+        debugger.trace_to(None);
+        let trace = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let lines: Vec<&str> = trace.lines().collect();
+        assert_eq!(lines.len(), 11);
+        assert_eq!(lines[0], "    0: add  #3, #0, 14  ; rb=0");
+        assert_eq!(lines[10], "   13: hlt  ; rb=0");
+    }
+
+    #[test]
+    fn test_repl() {
+        let mut debugger = Debugger::new(countdown());
+        let script =
+            "b 10\nc\nr\nx 14\np 14 1\nd 10\nc\no\nx 18446744073709551614 5\nl 18446744073709551614 5\nbogus\nq\ns\n";
+        let mut output = Vec::new();
+        debugger.repl(script.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output,
+            "    0: add  #3, #0, 14
+breakpoint at 10
+   10: jt   14, #4
+pc=10 rb=0 state=Idle
+14: 2
+halted
+   13: hlt
+3, 1
+18446744073709551614: 0
+18446744073709551614: data 0
+18446744073709551615: data 0
+error: unknown command `bogus`, try `help`
+"
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! The devices a [`Computer`](super::Computer) reads its input from and writes its output to.
+//!
+//! A computer executes against any [`IoDevice`] with [`Computer::run_device`](super::Computer::run_device),
+//! borrowing it only while it runs, so the caller keeps the device and can look at it in between runs.
+//! [`Computer::execute`](super::Computer::execute) uses the computer's own [`QueueDevice`].
+use std::collections::VecDeque;
+use std::mem;
+use std::sync::mpsc::{Receiver, Sender};
+
+use super::Stream;
+
+pub trait IoDevice {
+    /// The next input value, `None` if there is none yet, which pauses the computer until the next run.
+    fn read(&mut self) -> Option<i128>;
+
+    fn write(&mut self, value: i128);
+}
+
+impl<D: IoDevice + ?Sized> IoDevice for &mut D {
+    fn read(&mut self) -> Option<i128> {
+        (**self).read()
+    }
+
+    fn write(&mut self, value: i128) {
+        (**self).write(value)
+    }
+}
+
+/// Input is read in the order it was queued, output is collected until it is taken.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueueDevice {
+    pub input: Stream,
+    pub output: Stream,
+}
+
+impl IoDevice for QueueDevice {
+    fn read(&mut self) -> Option<i128> {
+        self.input.read()
+    }
+
+    fn write(&mut self, value: i128) {
+        self.output.write(value)
+    }
+}
+
+/// Calls `read` for every input and `write` for every output value, see [`from_fn`].
+pub struct FnDevice<R, W> {
+    read: R,
+    write: W,
+}
+
+/// A device that computes its input and handles its output with closures.
+pub fn from_fn<R: FnMut() -> Option<i128>, W: FnMut(i128)>(read: R, write: W) -> FnDevice<R, W> {
+    FnDevice { read, write }
+}
+
+impl<R: FnMut() -> Option<i128>, W: FnMut(i128)> IoDevice for FnDevice<R, W> {
+    fn read(&mut self) -> Option<i128> {
+        (self.read)()
+    }
+
+    fn write(&mut self, value: i128) {
+        (self.write)(value)
+    }
+}
+
+/// Connects a computer to channels, e.g. to run connected machines on their own threads.
+///
+/// Reading blocks until a value arrives, the computer only pauses once every sender is gone.
+/// Output sent after the receiving end is gone is dropped.
+#[derive(Debug)]
+pub struct ChannelDevice {
+    receiver: Receiver<i128>,
+    sender: Sender<i128>,
+}
+
+impl ChannelDevice {
+    pub fn new(receiver: Receiver<i128>, sender: Sender<i128>) -> ChannelDevice {
+        ChannelDevice { receiver, sender }
+    }
+}
+
+impl IoDevice for ChannelDevice {
+    fn read(&mut self) -> Option<i128> {
+        self.receiver.recv().ok()
+    }
+
+    fn write(&mut self, value: i128) {
+        let _ = self.sender.send(value);
+    }
+}
+
+/// Talks to programs that read and print ASCII text. Output outside of ASCII, like the large numbers
+/// some puzzle programs print as their answer, is collected separately from the text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AsciiConsole {
+    input: VecDeque<i128>,
+    text: String,
+    values: Vec<i128>,
+}
+
+impl AsciiConsole {
+    pub fn new() -> AsciiConsole {
+        AsciiConsole::default()
+    }
+
+    /// Queues a line of input, the newline is added.
+    pub fn write_line(&mut self, line: &str) {
+        self.input.extend(line.chars().map(|c| c as i128));
+        self.input.push_back('\n' as i128);
+    }
+
+    /// The text printed since the last call.
+    pub fn take_text(&mut self) -> String {
+        mem::take(&mut self.text)
+    }
+
+    /// Every value printed that is not ASCII.
+    pub fn values(&self) -> &[i128] {
+        &self.values
+    }
+}
+
+impl IoDevice for AsciiConsole {
+    fn read(&mut self) -> Option<i128> {
+        self.input.pop_front()
+    }
+
+    fn write(&mut self, value: i128) {
+        match value {
+            0..=127 => self.text.push(value as u8 as char),
+            _ => self.values.push(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::intcode::{Computer, ComputerState, Program};
+    use std::sync::mpsc;
+    use std::thread;
+
+    fn echo_plus_one() -> Program {
+        Program::assemble(
+            "
+            loop:   in   value
+                    add  value, #1, value
+                    out  value
+                    jt   #1, #loop
+            value:  data 0
+        ",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_fn_device() {
+        let mut inputs = vec![3, 2, 1];
+        let mut outputs = Vec::new();
+        let mut computer = Computer::new(&echo_plus_one());
+        let state = computer
+            .run_device(&mut from_fn(|| inputs.pop(), |value| outputs.push(value)))
+            .unwrap();
+        assert_eq!(state, ComputerState::Paused);
+        assert_eq!(outputs, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_ascii_console() {
+        let program = Program::assemble(
+            "
+            loop:   in   char
+                    out  char
+                    eq   char, #10, done
+                    jf   done, #loop
+                    out  #1000
+                    hlt
+            char:   data 0
+            done:   data 0
+        ",
+        )
+        .unwrap();
+        let mut console = AsciiConsole::new();
+        console.write_line("hi");
+        Computer::new(&program).run_device(&mut console).unwrap();
+        assert_eq!(console.take_text(), "hi\n");
+        assert_eq!(console.take_text(), "");
+        assert_eq!(console.values(), &[1000]);
+    }
+
+    #[test]
+    fn test_channels_between_threads() {
+        // two machines adding one each, connected in series
+        let (to_first, first_input) = mpsc::channel();
+        let (to_second, second_input) = mpsc::channel();
+        let (to_main, main_input) = mpsc::channel();
+        let workers: Vec<_> = vec![(first_input, to_second), (second_input, to_main)]
+            .into_iter()
+            .map(|(receiver, sender)| {
+                let mut computer = Computer::new(&echo_plus_one());
+                thread::spawn(move || {
+                    computer.run_device(&mut ChannelDevice::new(receiver, sender))
+                })
+            })
+            .collect();
+        to_first.send(1).unwrap();
+        to_first.send(10).unwrap();
+        assert_eq!(main_input.recv(), Ok(3));
+        assert_eq!(main_input.recv(), Ok(12));
+        drop(to_first);
+        for worker in workers {
+            assert_eq!(worker.join().unwrap(), Ok(ComputerState::Paused));
+        }
+    }
+}
@@ -0,0 +1,327 @@
+//! Turns a [`Program`] into a listing with addresses, mnemonics and decoded parameters, see [`Disassembly`].
+//!
+//! Parameters are written with a sigil for their mode: `12` reads address 12, `#12` is the value 12 itself
+//! and `@12` reads address 12 relative to the relative base.
+//!
+//! Which cells hold code is inferred by following the control flow from address 0. Immediate jump targets
+//! are followed, unconditional jumps (`jt #1, ..` and `jf #0, ..`) and `hlt` do not fall through. Jumps to
+//! computed addresses can not be followed, but puzzle programs call their subroutines by pushing the return
+//! address to the top of the stack (`add #ret, #0, @0`) before jumping, so those constants are followed as well.
+//! Arguments are pushed below it (`@1`, `@2`, ...) and often point at data, they are not followed. Everything
+//! else is data.
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fmt;
+
+use super::{Instruction, OpCode, ParameterMode, Program};
+
+/// Data cells are listed in lines of at most this many values.
+const DATA_PER_LINE: usize = 8;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Parameter {
+    pub mode: ParameterMode,
+    pub value: i128,
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.mode {
+            ParameterMode::Position => write!(f, "{}", self.value),
+            ParameterMode::Immediate => write!(f, "#{}", self.value),
+            ParameterMode::Relative => write!(f, "@{}", self.value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Line {
+    Instruction {
+        address: usize,
+        operation: OpCode,
+        parameters: Vec<Parameter>,
+    },
+    Data {
+        address: usize,
+        values: Vec<i128>,
+    },
+}
+
+impl Line {
+    /// Decodes the instruction at the given address, a single data cell if it is not a valid instruction.
+    /// Memory past the end reads as zero, like in the [`Computer`](super::Computer).
+    pub fn decode(memory: &[i128], address: usize) -> Line {
+        let load = |a: usize| memory.get(a).copied().unwrap_or(0);
+        match Instruction::decode(load(address)) {
+            Some(instruction) => Line::Instruction {
+                address,
+                operation: instruction.operation,
+                parameters: (0..instruction.operation.parameter_count())
+                    .map(|i| Parameter {
+                        mode: instruction.mode[i],
+                        value: load(address + 1 + i),
+                    })
+                    .collect(),
+            },
+            None => Line::Data {
+                address,
+                values: vec![load(address)],
+            },
+        }
+    }
+
+    pub fn address(&self) -> usize {
+        match self {
+            Line::Instruction { address, .. } | Line::Data { address, .. } => *address,
+        }
+    }
+
+    /// Number of memory cells the line covers.
+    pub fn len(&self) -> usize {
+        match self {
+            Line::Instruction { parameters, .. } => 1 + parameters.len(),
+            Line::Data { values, .. } => values.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (address, mnemonic, operands) = match self {
+            Line::Instruction {
+                address,
+                operation,
+                parameters,
+            } => {
+                let operands: Vec<String> = parameters.iter().map(|p| p.to_string()).collect();
+                (address, operation.mnemonic(), operands)
+            }
+            Line::Data { address, values } => {
+                let operands: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                (address, "data", operands)
+            }
+        };
+        if operands.is_empty() {
+            write!(f, "{:>5}: {}", address, mnemonic)
+        } else {
+            write!(f, "{:>5}: {:<4} {}", address, mnemonic, operands.join(", "))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Disassembly {
+    pub lines: Vec<Line>,
+}
+
+impl Disassembly {
+    pub fn new(program: &Program) -> Disassembly {
+        let memory = &program.program;
+        let starts = find_code(memory);
+
+        let mut lines = Vec::new();
+        let mut address = 0;
+        while address < memory.len() {
+            if starts[address] {
+                lines.push(Line::decode(memory, address));
+            } else {
+                let end = (address + 1..memory.len())
+                    .take(DATA_PER_LINE - 1)
+                    .find(|a| starts[*a])
+                    .unwrap_or_else(|| memory.len().min(address + DATA_PER_LINE));
+                lines.push(Line::Data {
+                    address,
+                    values: memory[address..end].to_vec(),
+                });
+            }
+            address += lines.last().unwrap().len();
+        }
+
+        Disassembly { lines }
+    }
+
+    /// The line covering the given address.
+    pub fn line_at(&self, address: usize) -> Option<&Line> {
+        let index = self
+            .lines
+            .partition_point(|line| line.address() <= address)
+            .checked_sub(1)?;
+        let line = &self.lines[index];
+        (address < line.address() + line.len()).then_some(line)
+    }
+}
+
+impl fmt::Display for Disassembly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+impl Program {
+    pub fn disassemble(&self) -> Disassembly {
+        Disassembly::new(self)
+    }
+}
+
+/// Marks the addresses where reachable instructions start.
+fn find_code(memory: &[i128]) -> Vec<bool> {
+    let mut starts = vec![false; memory.len()];
+    let mut covered = vec![false; memory.len()];
+    let in_program = |value: i128| usize::try_from(value).ok().filter(|a| *a < memory.len());
+    let mut queue: VecDeque<usize> = in_program(0).into_iter().collect();
+
+    while let Some(address) = queue.pop_front() {
+        if covered[address] {
+            continue;
+        }
+        let instruction = match Instruction::decode(memory[address]) {
+            Some(instruction) => instruction,
+            None => continue,
+        };
+        let length = 1 + instruction.operation.parameter_count();
+        if address + length > memory.len() || covered[address..address + length].iter().any(|c| *c)
+        {
+            continue;
+        }
+        starts[address] = true;
+        covered[address..address + length]
+            .iter_mut()
+            .for_each(|c| *c = true);
+
+        let parameter = |i: usize| (instruction.mode[i], memory[address + 1 + i]);
+        let falls_through = match instruction.operation {
+            OpCode::Exit => false,
+            OpCode::JumpIfTrue | OpCode::JumpIfFalse => {
+                if let (ParameterMode::Immediate, target) = parameter(1) {
+                    queue.extend(in_program(target));
+                }
+                match (parameter(0), instruction.operation) {
+                    ((ParameterMode::Immediate, 0), OpCode::JumpIfFalse) => false,
+                    ((ParameterMode::Immediate, x), OpCode::JumpIfTrue) => x == 0,
+                    _ => true,
+                }
+            }
+            OpCode::Add | OpCode::Multiply => {
+                if let (
+                    (ParameterMode::Immediate, a),
+                    (ParameterMode::Immediate, b),
+                    (ParameterMode::Relative, 0),
+                ) = (parameter(0), parameter(1), parameter(2))
+                {
+                    // an overflowing result fails at runtime, so it leads nowhere
+                    let value = if instruction.operation == OpCode::Add {
+                        a.checked_add(b)
+                    } else {
+                        a.checked_mul(b)
+                    };
+                    queue.extend(value.and_then(in_program));
+                }
+                true
+            }
+            _ => true,
+        };
+        if falls_through {
+            queue.extend(in_program((address + length) as i128));
+        }
+    }
+
+    starts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing(program: &str) -> String {
+        Program::parse(program).unwrap().disassemble().to_string()
+    }
+
+    #[test]
+    fn test_parameter_modes() {
+        assert_eq!(
+            listing("21101,1,2,5,99"),
+            "    0: add  #1, #2, @5\n    4: hlt\n"
+        );
+    }
+
+    #[test]
+    fn test_data_after_halt() {
+        assert_eq!(
+            listing("104,7,99,1,2,3"),
+            "    0: out  #7\n    2: hlt\n    3: data 1, 2, 3\n"
+        );
+    }
+
+    #[test]
+    fn test_unconditional_jump_skips_data() {
+        let disassembly = Program::parse("1106,0,5,42,42,204,-1,99")
+            .unwrap()
+            .disassemble();
+        assert_eq!(
+            disassembly.to_string(),
+            "    0: jf   #0, #5\n    3: data 42, 42\n    5: out  @-1\n    7: hlt\n"
+        );
+        assert_eq!(
+            disassembly.line_at(4),
+            Some(&Line::Data {
+                address: 3,
+                values: vec![42, 42]
+            })
+        );
+        assert_eq!(disassembly.line_at(8), None);
+    }
+
+    #[test]
+    fn test_follows_return_addresses() {
+        // calls the subroutine at 13 with a pointer to the data at 11, it returns through the address pushed onto the stack
+        let disassembly = Program::parse("21101,12,0,0,21101,11,0,1,1105,1,13,104,99,2106,0,0")
+            .unwrap()
+            .disassemble();
+        let addresses: Vec<usize> = disassembly.lines.iter().map(|l| l.address()).collect();
+        assert_eq!(addresses, vec![0, 4, 8, 11, 12, 13]);
+        assert_eq!(
+            disassembly.line_at(11),
+            Some(&Line::Data {
+                address: 11,
+                values: vec![104]
+            })
+        );
+        assert!(matches!(
+            disassembly.line_at(12),
+            Some(Line::Instruction {
+                operation: OpCode::Exit,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_overflowing_pointer_leads_nowhere() {
+        let program = format!("21101,{},1,0,99", i128::MAX);
+        let disassembly = Program::parse(&program).unwrap().disassemble();
+        let addresses: Vec<usize> = disassembly.lines.iter().map(|l| l.address()).collect();
+        assert_eq!(addresses, vec![0, 4]);
+    }
+
+    #[test]
+    fn test_data_lines_are_split() {
+        let disassembly = Program::parse("99,1,2,3,4,5,6,7,8,9,10")
+            .unwrap()
+            .disassemble();
+        assert_eq!(disassembly.lines.len(), 3);
+        assert_eq!(
+            disassembly.lines[2],
+            Line::Data {
+                address: 9,
+                values: vec![9, 10]
+            }
+        );
+    }
+}
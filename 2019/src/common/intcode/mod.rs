@@ -0,0 +1,752 @@
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fmt;
+use std::mem;
+//use num_i128::{i128, Toi128, Fromi128};
+
+mod assembler;
+mod debugger;
+mod device;
+mod disassembler;
+mod network;
+mod pipeline;
+mod profiler;
+mod snapshot;
+
+pub use assembler::*;
+pub use debugger::*;
+pub use device::*;
+pub use disassembler::*;
+pub use network::*;
+pub use pipeline::*;
+pub use profiler::*;
+pub use snapshot::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Program {
+    pub program: Vec<i128>,
+}
+
+/// Memory cells a program may write to, so a wild write fails instead of exhausting the host's memory.
+const MEMORY_LIMIT: usize = 1 << 24;
+
+/// Why a program could not be parsed or executed. Every error carries the location it happened at,
+/// `pc` is the address of the failing instruction, which is left unexecuted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntcodeError {
+    InvalidOpCode {
+        pc: usize,
+        value: i128,
+    },
+    InvalidMode {
+        pc: usize,
+        value: i128,
+    },
+    NegativeAddress {
+        pc: usize,
+        address: i128,
+    },
+    AddressTooLarge {
+        pc: usize,
+        address: i128,
+    },
+    WriteToImmediate {
+        pc: usize,
+    },
+    /// An addition, multiplication or relative base adjustment does not fit in an `i128`.
+    Overflow {
+        pc: usize,
+    },
+    Parse {
+        index: usize,
+        value: String,
+    },
+}
+
+impl fmt::Display for IntcodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IntcodeError::InvalidOpCode { pc, value } => {
+                write!(f, "pc {}: invalid opcode in instruction {}", pc, value)
+            }
+            IntcodeError::InvalidMode { pc, value } => {
+                write!(
+                    f,
+                    "pc {}: invalid parameter mode in instruction {}",
+                    pc, value
+                )
+            }
+            IntcodeError::NegativeAddress { pc, address } => {
+                write!(f, "pc {}: negative address {}", pc, address)
+            }
+            IntcodeError::AddressTooLarge { pc, address } => {
+                write!(
+                    f,
+                    "pc {}: address {} is past the memory limit of {}",
+                    pc, address, MEMORY_LIMIT
+                )
+            }
+            IntcodeError::WriteToImmediate { pc } => {
+                write!(f, "pc {}: write to an immediate parameter", pc)
+            }
+            IntcodeError::Overflow { pc } => write!(f, "pc {}: arithmetic overflow", pc),
+            IntcodeError::Parse { index, value } => {
+                write!(f, "value {}: `{}` is not a number", index, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IntcodeError {}
+
+impl Program {
+    pub fn parse(contents: &str) -> Result<Program, IntcodeError> {
+        let program = contents
+            .trim()
+            .split(',')
+            .enumerate()
+            .map(|(index, x)| {
+                x.trim().parse::<i128>().map_err(|_| IntcodeError::Parse {
+                    index,
+                    value: x.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Program { program })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum OpCode {
+    Add,
+    Multiply,
+    Read,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    SetRelative,
+    Exit,
+}
+
+impl OpCode {
+    pub fn decode(x: i128) -> Option<OpCode> {
+        match x {
+            1 => Some(OpCode::Add),
+            2 => Some(OpCode::Multiply),
+            3 => Some(OpCode::Read),
+            4 => Some(OpCode::Output),
+            5 => Some(OpCode::JumpIfTrue),
+            6 => Some(OpCode::JumpIfFalse),
+            7 => Some(OpCode::LessThan),
+            8 => Some(OpCode::Equals),
+            9 => Some(OpCode::SetRelative),
+            99 => Some(OpCode::Exit),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> i128 {
+        match self {
+            OpCode::Add => 1,
+            OpCode::Multiply => 2,
+            OpCode::Read => 3,
+            OpCode::Output => 4,
+            OpCode::JumpIfTrue => 5,
+            OpCode::JumpIfFalse => 6,
+            OpCode::LessThan => 7,
+            OpCode::Equals => 8,
+            OpCode::SetRelative => 9,
+            OpCode::Exit => 99,
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<OpCode> {
+        match mnemonic {
+            "add" => Some(OpCode::Add),
+            "mul" => Some(OpCode::Multiply),
+            "in" => Some(OpCode::Read),
+            "out" => Some(OpCode::Output),
+            "jt" => Some(OpCode::JumpIfTrue),
+            "jf" => Some(OpCode::JumpIfFalse),
+            "lt" => Some(OpCode::LessThan),
+            "eq" => Some(OpCode::Equals),
+            "arb" => Some(OpCode::SetRelative),
+            "hlt" => Some(OpCode::Exit),
+            _ => None,
+        }
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            OpCode::Add => "add",
+            OpCode::Multiply => "mul",
+            OpCode::Read => "in",
+            OpCode::Output => "out",
+            OpCode::JumpIfTrue => "jt",
+            OpCode::JumpIfFalse => "jf",
+            OpCode::LessThan => "lt",
+            OpCode::Equals => "eq",
+            OpCode::SetRelative => "arb",
+            OpCode::Exit => "hlt",
+        }
+    }
+
+    pub fn parameter_count(&self) -> usize {
+        match self {
+            OpCode::Add | OpCode::Multiply | OpCode::LessThan | OpCode::Equals => 3,
+            OpCode::JumpIfTrue | OpCode::JumpIfFalse => 2,
+            OpCode::Read | OpCode::Output | OpCode::SetRelative => 1,
+            OpCode::Exit => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    pub operation: OpCode,
+    pub mode: [ParameterMode; 3],
+}
+
+impl Instruction {
+    /// Decodes an instruction, `None` if the opcode or one of the used parameter modes is unknown.
+    pub fn decode(x: i128) -> Option<Instruction> {
+        if x < 0 {
+            return None;
+        }
+        let operation = OpCode::decode(x % 100)?;
+        let mut mode = [ParameterMode::Position; 3];
+        let mut i = x / 100;
+        for m in mode.iter_mut().take(operation.parameter_count()) {
+            *m = ParameterMode::decode(i % 10)?;
+            i /= 10;
+        }
+        Some(Instruction { operation, mode })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ParameterMode {
+    Position,
+    Immediate,
+    Relative,
+}
+
+impl ParameterMode {
+    pub fn decode(mode: i128) -> Option<ParameterMode> {
+        match mode {
+            0 => Some(ParameterMode::Position),
+            1 => Some(ParameterMode::Immediate),
+            2 => Some(ParameterMode::Relative),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> i128 {
+        match self {
+            ParameterMode::Position => 0,
+            ParameterMode::Immediate => 1,
+            ParameterMode::Relative => 2,
+        }
+    }
+}
+
+/// An Intcode machine, shared by every 2019 day.
+///
+/// The computer reads from and writes to its own input and output queues, or to any [`IoDevice`] it is
+/// run with. Execution continues until the program halts or blocks on an input that is not there yet,
+/// then it can be resumed once more input is available.
+#[derive(Debug)]
+pub struct Computer {
+    pub memory: Vec<i128>,
+    pc: usize,
+    pub state: ComputerState,
+    io: QueueDevice,
+    relative_base: i128,
+    profile: Option<Box<Profile>>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ComputerState {
+    /// Ready to execute the next instruction.
+    Idle,
+    /// Reached the exit instruction, executing again does nothing.
+    Halted,
+    /// Blocked on a read without input.
+    Paused,
+}
+
+impl Computer {
+    pub fn new(program: &Program) -> Computer {
+        Computer {
+            memory: program.program.clone(),
+            pc: 0,
+            state: ComputerState::Idle,
+            io: QueueDevice::default(),
+            relative_base: 0,
+            profile: None,
+        }
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn relative_base(&self) -> i128 {
+        self.relative_base
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.state == ComputerState::Halted
+    }
+
+    /// Writes a value to the input queue.
+    pub fn write_input(&mut self, value: i128) {
+        self.io.input.write(value);
+    }
+
+    /// Reads the oldest value from the output queue.
+    pub fn read_output(&mut self) -> Option<i128> {
+        self.io.output.read()
+    }
+
+    /// Reads all the values waiting in the output queue.
+    pub fn drain_output(&mut self) -> Vec<i128> {
+        self.io.output.drain()
+    }
+
+    /// Writes `input` to the input queue, executes until the program halts or needs more input
+    /// and returns everything waiting in the output queue.
+    pub fn run(&mut self, input: Vec<i128>) -> Result<Vec<i128>, IntcodeError> {
+        input.into_iter().for_each(|x| self.write_input(x));
+        self.execute()?;
+        Ok(self.drain_output())
+    }
+
+    /// Executes until the program halts or needs more input, using the computer's own queues.
+    pub fn execute(&mut self) -> Result<ComputerState, IntcodeError> {
+        let mut io = mem::take(&mut self.io);
+        let result = self.run_device(&mut io);
+        self.io = io;
+        result
+    }
+
+    /// Executes a single instruction using the computer's own queues,
+    /// a read from an empty input queue leaves the computer [`ComputerState::Paused`].
+    pub fn step(&mut self) -> Result<ComputerState, IntcodeError> {
+        let mut io = mem::take(&mut self.io);
+        let result = self.step_device(&mut io);
+        self.io = io;
+        result
+    }
+
+    /// Executes until the program halts or needs more input, reading from and writing to `device`.
+    pub fn run_device<D: IoDevice + ?Sized>(
+        &mut self,
+        device: &mut D,
+    ) -> Result<ComputerState, IntcodeError> {
+        self.state = loop {
+            match self.step_io(device)? {
+                ComputerState::Idle => {}
+                state => break state,
+            }
+        };
+        Ok(self.state)
+    }
+
+    /// Executes a single instruction, reading from and writing to `device`.
+    pub fn step_device<D: IoDevice + ?Sized>(
+        &mut self,
+        device: &mut D,
+    ) -> Result<ComputerState, IntcodeError> {
+        self.state = self.step_io(device)?;
+        Ok(self.state)
+    }
+
+    /// Executes the instruction at the program counter. On an error nothing has been changed, the program
+    /// counter still points at the failing instruction.
+    fn step_io<D: IoDevice + ?Sized>(
+        &mut self,
+        device: &mut D,
+    ) -> Result<ComputerState, IntcodeError> {
+        let pc = self.pc;
+        let instruction = self.decode()?;
+        let state = self.execute_instruction(instruction, device)?;
+        if let Some(profile) = self.profile.as_mut() {
+            if state != ComputerState::Paused {
+                profile.record(pc, instruction.operation, self.pc, self.memory.len());
+            }
+        }
+        Ok(state)
+    }
+
+    fn execute_instruction<D: IoDevice + ?Sized>(
+        &mut self,
+        instruction: Instruction,
+        device: &mut D,
+    ) -> Result<ComputerState, IntcodeError> {
+        match instruction.operation {
+            OpCode::Add => {
+                let result = self
+                    .read(1, &instruction)?
+                    .checked_add(self.read(2, &instruction)?)
+                    .ok_or(IntcodeError::Overflow { pc: self.pc })?;
+                self.write(3, &instruction, result)?;
+                self.pc += 4;
+            }
+            OpCode::Multiply => {
+                let result = self
+                    .read(1, &instruction)?
+                    .checked_mul(self.read(2, &instruction)?)
+                    .ok_or(IntcodeError::Overflow { pc: self.pc })?;
+                self.write(3, &instruction, result)?;
+                self.pc += 4;
+            }
+            OpCode::Read => {
+                // the address is checked before the input is consumed, so a failing read loses no input
+                self.get_write_address(1, &instruction)?;
+                match device.read() {
+                    Some(result) => {
+                        self.write(1, &instruction, result)?;
+                        self.pc += 2;
+                    }
+                    None => return Ok(ComputerState::Paused),
+                }
+            }
+            OpCode::Output => {
+                let result = self.read(1, &instruction)?;
+                device.write(result);
+                self.pc += 2;
+            }
+            OpCode::JumpIfTrue => {
+                let value = self.read(1, &instruction)?;
+                if value != 0 {
+                    self.pc = self.jump_target(&instruction)?;
+                } else {
+                    self.pc += 3;
+                }
+            }
+            OpCode::JumpIfFalse => {
+                let value = self.read(1, &instruction)?;
+                if value == 0 {
+                    self.pc = self.jump_target(&instruction)?;
+                } else {
+                    self.pc += 3;
+                }
+            }
+            OpCode::LessThan => {
+                let result = self.read(1, &instruction)? < self.read(2, &instruction)?;
+                self.write(3, &instruction, result as i128)?;
+                self.pc += 4;
+            }
+            OpCode::Equals => {
+                let result = self.read(1, &instruction)? == self.read(2, &instruction)?;
+                self.write(3, &instruction, result as i128)?;
+                self.pc += 4;
+            }
+            OpCode::SetRelative => {
+                self.relative_base = self
+                    .relative_base
+                    .checked_add(self.read(1, &instruction)?)
+                    .ok_or(IntcodeError::Overflow { pc: self.pc })?;
+                self.pc += 2;
+            }
+            OpCode::Exit => return Ok(ComputerState::Halted),
+        }
+        Ok(ComputerState::Idle)
+    }
+
+    fn decode(&self) -> Result<Instruction, IntcodeError> {
+        let value = self.load(self.pc);
+        Instruction::decode(value).ok_or_else(|| {
+            if value >= 0 && OpCode::decode(value % 100).is_some() {
+                IntcodeError::InvalidMode { pc: self.pc, value }
+            } else {
+                IntcodeError::InvalidOpCode { pc: self.pc, value }
+            }
+        })
+    }
+
+    fn read(&self, offset: usize, instruction: &Instruction) -> Result<i128, IntcodeError> {
+        let address = self.get_address(offset, instruction)?;
+        Ok(self.load(address))
+    }
+
+    fn write(
+        &mut self,
+        offset: usize,
+        instruction: &Instruction,
+        value: i128,
+    ) -> Result<(), IntcodeError> {
+        let address = self.get_write_address(offset, instruction)?;
+        if address >= MEMORY_LIMIT {
+            return Err(IntcodeError::AddressTooLarge {
+                pc: self.pc,
+                address: address as i128,
+            });
+        }
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, 0);
+        }
+        self.memory[address] = value;
+        Ok(())
+    }
+
+    /// Memory past the end of the program reads as zero, it only grows when written to.
+    fn load(&self, position: usize) -> i128 {
+        self.memory.get(position).copied().unwrap_or(0)
+    }
+
+    fn get_address(&self, offset: usize, instruction: &Instruction) -> Result<usize, IntcodeError> {
+        let address = match instruction.mode[offset - 1] {
+            ParameterMode::Position => self.load(self.pc + offset),
+            ParameterMode::Immediate => return Ok(self.pc + offset),
+            ParameterMode::Relative => self
+                .load(self.pc + offset)
+                .checked_add(self.relative_base)
+                .ok_or(IntcodeError::Overflow { pc: self.pc })?,
+        };
+        self.check_address(address)
+    }
+
+    fn get_write_address(
+        &self,
+        offset: usize,
+        instruction: &Instruction,
+    ) -> Result<usize, IntcodeError> {
+        if instruction.mode[offset - 1] == ParameterMode::Immediate {
+            return Err(IntcodeError::WriteToImmediate { pc: self.pc });
+        }
+        self.get_address(offset, instruction)
+    }
+
+    fn jump_target(&self, instruction: &Instruction) -> Result<usize, IntcodeError> {
+        let target = self.read(2, instruction)?;
+        self.check_address(target)
+    }
+
+    fn check_address(&self, address: i128) -> Result<usize, IntcodeError> {
+        match usize::try_from(address) {
+            Ok(valid) => Ok(valid),
+            Err(_) if address < 0 => Err(IntcodeError::NegativeAddress {
+                pc: self.pc,
+                address,
+            }),
+            Err(_) => Err(IntcodeError::AddressTooLarge {
+                pc: self.pc,
+                address,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stream {
+    data: VecDeque<i128>,
+}
+
+impl Default for Stream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Stream {
+    pub fn new() -> Stream {
+        Stream {
+            data: VecDeque::new(),
+        }
+    }
+
+    pub fn read(&mut self) -> Option<i128> {
+        self.data.pop_front()
+    }
+
+    pub fn write(&mut self, value: i128) {
+        self.data.push_back(value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn drain(&mut self) -> Vec<i128> {
+        self.data.drain(..).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_instruction() {
+        let program = Program::parse("109,19,204,-34,99").unwrap();
+        let mut computer = Computer::new(&program);
+        computer.relative_base = 2000;
+        let output = computer.run(vec![]).unwrap();
+        assert_eq!(computer.relative_base, 2019);
+        assert_eq!(output[0], 0);
+    }
+
+    #[test]
+    fn test_copy() {
+        let program =
+            Program::parse("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99").unwrap();
+        let mut computer = Computer::new(&program);
+        let output = computer.run(vec![]).unwrap();
+        println!("{:?}", output);
+    }
+
+    #[test]
+    fn test_pause_on_missing_input() {
+        let program = Program::assemble(
+            "
+            in   value
+            out  value
+            hlt
+            value: data 0
+        ",
+        )
+        .unwrap();
+        let mut computer = Computer::new(&program);
+        assert_eq!(computer.execute().unwrap(), ComputerState::Paused);
+        assert_eq!(computer.pc(), 0);
+        computer.write_input(7);
+        assert_eq!(computer.execute().unwrap(), ComputerState::Halted);
+        assert_eq!(computer.drain_output(), vec![7]);
+    }
+
+    #[test]
+    fn test_step() {
+        let program = Program::assemble(
+            "
+            add  #1, #2, sum
+            hlt
+            sum: data 0
+        ",
+        )
+        .unwrap();
+        let mut computer = Computer::new(&program);
+        assert_eq!(computer.step().unwrap(), ComputerState::Idle);
+        assert_eq!(computer.pc(), 4);
+        assert_eq!(computer.memory[5], 3);
+        assert_eq!(computer.step().unwrap(), ComputerState::Halted);
+        assert!(computer.is_halted());
+    }
+
+    #[test]
+    fn test_memory_grows_only_on_write() {
+        let program = Program::parse("1,100,0,0,1101,0,0,200,99").unwrap();
+        let mut computer = Computer::new(&program);
+        computer.execute().unwrap();
+        assert_eq!(computer.memory[0], 1);
+        assert_eq!(computer.memory.len(), 201);
+    }
+
+    #[test]
+    fn test_large_number() {
+        let program = Program::parse("1102,34915192,34915192,7,4,7,99,0").unwrap();
+        let mut computer = Computer::new(&program);
+        let output = computer.run(vec![]).unwrap();
+        assert_eq!(format!("{}", output[0]).len(), 16);
+    }
+
+    #[test]
+    fn test_large_number2() {
+        let program = Program::parse("104,1125899906842624,99").unwrap();
+        let mut computer = Computer::new(&program);
+        let output = computer.run(vec![]).unwrap();
+        assert_eq!(format!("{}", output[0]).len(), 16);
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |program: &str| {
+            Computer::new(&Program::parse(program).unwrap())
+                .run(vec![1])
+                .unwrap_err()
+        };
+        assert_eq!(
+            error("1101,1,1,5,42"),
+            IntcodeError::InvalidOpCode { pc: 4, value: 42 }
+        );
+        assert_eq!(
+            error("-1"),
+            IntcodeError::InvalidOpCode { pc: 0, value: -1 }
+        );
+        assert_eq!(
+            error("304,0,99"),
+            IntcodeError::InvalidMode { pc: 0, value: 304 }
+        );
+        assert_eq!(
+            error("4,-3,99"),
+            IntcodeError::NegativeAddress { pc: 0, address: -3 }
+        );
+        assert_eq!(
+            error("109,-5,204,2,99"),
+            IntcodeError::NegativeAddress { pc: 2, address: -3 }
+        );
+        assert_eq!(
+            error("1105,1,-7"),
+            IntcodeError::NegativeAddress { pc: 0, address: -7 }
+        );
+        assert_eq!(
+            error("11101,1,1,0,99"),
+            IntcodeError::WriteToImmediate { pc: 0 }
+        );
+        assert_eq!(
+            error("3,1000000000000,99"),
+            IntcodeError::AddressTooLarge {
+                pc: 0,
+                address: 1000000000000
+            }
+        );
+        let max = i128::MAX.to_string();
+        assert_eq!(
+            error(&format!("1101,{},1,0,99", max)),
+            IntcodeError::Overflow { pc: 0 }
+        );
+        assert_eq!(
+            error(&format!("1102,{},2,0,99", max)),
+            IntcodeError::Overflow { pc: 0 }
+        );
+        assert_eq!(
+            error(&format!("109,{},109,1,99", max)),
+            IntcodeError::Overflow { pc: 2 }
+        );
+        assert_eq!(
+            error(&format!("109,{},204,1,99", max)),
+            IntcodeError::Overflow { pc: 2 }
+        );
+        assert_eq!(
+            Program::parse("1,2,x,99"),
+            Err(IntcodeError::Parse {
+                index: 2,
+                value: "x".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_error_keeps_state() {
+        let program = Program::assemble(
+            "
+            out  #1
+            in   #0
+        ",
+        )
+        .unwrap();
+        let mut computer = Computer::new(&program);
+        computer.write_input(5);
+        assert_eq!(
+            computer.execute(),
+            Err(IntcodeError::WriteToImmediate { pc: 2 })
+        );
+        assert_eq!(computer.pc(), 2);
+        assert_eq!(computer.read_output(), Some(1));
+        assert_eq!(computer.io.input.read(), Some(5));
+    }
+}
@@ -0,0 +1,228 @@
+//! A network of computers that talk in packets, as in day 23.
+//!
+//! Every computer first reads its own address, then sends a packet by writing the destination, `x` and `y`.
+//! Reading without a packet waiting gives -1. The [`Network`] schedules its computers cooperatively on the
+//! calling thread, each runs until it waits for packets, then the next one gets a turn. Packets to addresses
+//! without a computer, like the NAT at 255, are handed to the caller as [`Event`]s, as is the network going idle.
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+
+use super::{Computer, IntcodeError, IoDevice, Program};
+
+/// The address of the NAT.
+pub const NAT: usize = 255;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packet {
+    pub address: usize,
+    pub x: i128,
+    pub y: i128,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// A packet was sent to an address without a computer.
+    Packet(Packet),
+    /// Every computer waits for packets and none are left to deliver.
+    Idle,
+}
+
+/// The network interface of a computer. When there is nothing to read it reads -1 once,
+/// the next read pauses the computer so the others get a turn.
+#[derive(Debug, Default)]
+struct Nic {
+    inbox: VecDeque<i128>,
+    partial: Vec<i128>,
+    outbox: Vec<Packet>,
+    waiting: bool,
+}
+
+impl IoDevice for Nic {
+    fn read(&mut self) -> Option<i128> {
+        match self.inbox.pop_front() {
+            Some(value) => {
+                self.waiting = false;
+                Some(value)
+            }
+            None if self.waiting => None,
+            None => {
+                self.waiting = true;
+                Some(-1)
+            }
+        }
+    }
+
+    fn write(&mut self, value: i128) {
+        self.partial.push(value);
+        if let [address, x, y] = self.partial[..] {
+            self.partial.clear();
+            // negative addresses can not have a computer, they end up as an event
+            let address = usize::try_from(address).unwrap_or(usize::MAX);
+            self.outbox.push(Packet { address, x, y });
+        }
+    }
+}
+
+pub struct Network {
+    nodes: Vec<(Computer, Nic)>,
+    events: VecDeque<Event>,
+}
+
+impl Network {
+    /// Boots `size` computers running `program` at the addresses `0..size`.
+    pub fn new(program: &Program, size: usize) -> Network {
+        let nodes = (0..size)
+            .map(|address| {
+                let mut nic = Nic::default();
+                nic.inbox.push_back(address as i128);
+                (Computer::new(program), nic)
+            })
+            .collect();
+
+        Network {
+            nodes,
+            events: VecDeque::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Delivers a packet from outside the network, e.g. from the NAT.
+    pub fn send(&mut self, packet: Packet) {
+        match self.nodes.get_mut(packet.address) {
+            Some((_, nic)) => nic.inbox.extend([packet.x, packet.y]),
+            None => self.events.push_back(Event::Packet(packet)),
+        }
+    }
+
+    /// Runs the computers until a packet is sent to an address without a computer or the network is idle.
+    pub fn next_event(&mut self) -> Result<Event, IntcodeError> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Ok(event);
+            }
+            if !self.round()? {
+                return Ok(Event::Idle);
+            }
+        }
+    }
+
+    /// Gives every computer a turn, returns whether any packet was sent.
+    fn round(&mut self) -> Result<bool, IntcodeError> {
+        let mut active = false;
+        for index in 0..self.nodes.len() {
+            let (computer, nic) = &mut self.nodes[index];
+            computer.run_device(nic)?;
+            let outbox = std::mem::take(&mut nic.outbox);
+            active |= !outbox.is_empty();
+            outbox.into_iter().for_each(|packet| self.send(packet));
+        }
+        Ok(active)
+    }
+}
+
+/// Remembers the last packet sent to it, and sends it to address 0 when the network is idle.
+#[derive(Debug, Clone, Default)]
+pub struct Nat {
+    last: Option<Packet>,
+}
+
+impl Nat {
+    pub fn new() -> Nat {
+        Nat::default()
+    }
+
+    pub fn receive(&mut self, packet: Packet) {
+        self.last = Some(packet);
+    }
+
+    /// The packet to send to wake up an idle network, if the NAT received one.
+    pub fn wake(&self) -> Option<Packet> {
+        self.last.map(|packet| Packet {
+            address: 0,
+            ..packet
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every computer passes packets on to the next address with `x` incremented.
+    fn relay() -> Program {
+        Program::assemble(
+            "
+                    in   address
+            loop:   in   x
+                    eq   x, #-1, empty
+                    jt   empty, #loop
+                    in   y
+                    add  address, #1, next
+                    add  x, #1, x
+                    out  next
+                    out  x
+                    out  y
+                    jt   #1, #loop
+            address: data 0
+            x:       data 0
+            y:       data 0
+            empty:   data 0
+            next:    data 0
+        ",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_routing() {
+        let mut network = Network::new(&relay(), 3);
+        assert_eq!(network.next_event(), Ok(Event::Idle));
+        network.send(Packet {
+            address: 0,
+            x: 0,
+            y: 7,
+        });
+        assert_eq!(
+            network.next_event(),
+            Ok(Event::Packet(Packet {
+                address: 3,
+                x: 3,
+                y: 7
+            }))
+        );
+        assert_eq!(network.next_event(), Ok(Event::Idle));
+    }
+
+    #[test]
+    fn test_nat_wakes_up_the_network() {
+        let mut network = Network::new(&relay(), 2);
+        let mut nat = Nat::new();
+        assert_eq!(nat.wake(), None);
+        network.send(Packet {
+            address: 1,
+            x: 0,
+            y: 1,
+        });
+        match network.next_event() {
+            Ok(Event::Packet(packet)) => nat.receive(packet),
+            event => panic!("{:?}", event),
+        }
+        assert_eq!(network.next_event(), Ok(Event::Idle));
+        network.send(nat.wake().unwrap());
+        assert_eq!(
+            network.next_event(),
+            Ok(Event::Packet(Packet {
+                address: 2,
+                x: 3,
+                y: 1
+            }))
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! Computers connected in series, every machine reading what the one before it printed, as the amplifiers of
+//! day 7. With [`Pipeline::feedback`] the last machine's output goes back into the first, forming a ring.
+use super::{Computer, IntcodeError, Program};
+
+pub struct Pipeline {
+    computers: Vec<Computer>,
+    feedback: bool,
+}
+
+impl Pipeline {
+    /// `count` machines running `program`.
+    pub fn new(program: &Program, count: usize) -> Pipeline {
+        Pipeline::from_computers((0..count).map(|_| Computer::new(program)).collect())
+    }
+
+    pub fn from_computers(computers: Vec<Computer>) -> Pipeline {
+        Pipeline {
+            computers,
+            feedback: false,
+        }
+    }
+
+    /// Writes one value to the input of each machine in order, like the phase settings of the amplifiers.
+    pub fn seed(mut self, values: &[i128]) -> Pipeline {
+        self.computers
+            .iter_mut()
+            .zip(values)
+            .for_each(|(computer, value)| computer.write_input(*value));
+        self
+    }
+
+    /// Connects the output of the last machine to the input of the first.
+    pub fn feedback(mut self) -> Pipeline {
+        self.feedback = true;
+        self
+    }
+
+    pub fn computers(&self) -> &[Computer] {
+        &self.computers
+    }
+
+    /// Writes `input` to the first machine and runs the machines in turn until they are all halted or waiting
+    /// for input that no other machine is going to print. Returns the last value printed by the last machine.
+    pub fn run(&mut self, input: Vec<i128>) -> Result<Option<i128>, IntcodeError> {
+        let mut signals = input;
+        let mut last = None;
+        loop {
+            let mut progress = false;
+            for computer in self.computers.iter_mut() {
+                signals
+                    .drain(..)
+                    .for_each(|signal| computer.write_input(signal));
+                computer.execute()?;
+                signals = computer.drain_output();
+                progress |= !signals.is_empty();
+            }
+            last = signals.last().copied().or(last);
+            if !self.feedback || !progress {
+                return Ok(last);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Prints every input multiplied by the first input, halts after the second value.
+    fn multiplier() -> Program {
+        Program::assemble(
+            "
+                    in   factor
+                    in   value
+                    mul  value, factor, value
+                    out  value
+                    in   value
+                    mul  value, factor, value
+                    out  value
+                    hlt
+            factor: data 0
+            value:  data 0
+        ",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_series() {
+        let mut pipeline = Pipeline::new(&multiplier(), 3).seed(&[2, 3, 4]);
+        assert_eq!(pipeline.run(vec![1]), Ok(Some(24)));
+        assert_eq!(pipeline.run(vec![]), Ok(None));
+        assert_eq!(pipeline.run(vec![10]), Ok(Some(240)));
+        assert!(pipeline.computers().iter().all(|c| c.is_halted()));
+    }
+
+    #[test]
+    fn test_feedback() {
+        // 1 goes around the ring twice, the second time every machine halts
+        let mut pipeline = Pipeline::new(&multiplier(), 2).seed(&[2, 3]).feedback();
+        assert_eq!(pipeline.run(vec![1]), Ok(Some(36)));
+        assert!(pipeline.computers().iter().all(|c| c.is_halted()));
+    }
+
+    #[test]
+    fn test_feedback_stops_when_starved() {
+        let mut pipeline = Pipeline::new(&multiplier(), 2).feedback();
+        assert_eq!(pipeline.run(vec![]), Ok(None));
+        assert!(!pipeline.computers()[0].is_halted());
+    }
+}
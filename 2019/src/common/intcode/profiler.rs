@@ -0,0 +1,282 @@
+//! Counts what a [`Computer`] spends its time on: executed instructions per address and per opcode,
+//! how its memory grows and which loops run most often, to find out why a day is slow.
+//!
+//! ```text
+//! computer.enable_profiling();
+//! computer.execute()?;
+//! println!("{}", computer.profile().unwrap());
+//! ```
+//!
+//! Days that run many short-lived machines can [`merge`](Profile::merge) their profiles.
+use std::collections::HashMap;
+use std::fmt;
+
+use super::{Computer, OpCode};
+
+/// How many addresses and loops the report lists.
+const REPORT_LENGTH: usize = 10;
+
+/// A loop closed by a backward jump from `end` to `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Loop {
+    pub start: usize,
+    pub end: usize,
+    pub iterations: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    instructions: u64,
+    by_address: Vec<u64>,
+    by_opcode: HashMap<OpCode, u64>,
+    loops: HashMap<(usize, usize), u64>,
+    initial_memory: usize,
+    peak_memory: usize,
+    growth: Vec<(u64, usize)>,
+}
+
+impl Profile {
+    fn new(memory: usize) -> Profile {
+        Profile {
+            initial_memory: memory,
+            peak_memory: memory,
+            ..Profile::default()
+        }
+    }
+
+    /// Counts an executed instruction, `next_pc` and `memory` are the program counter and memory size after it.
+    pub(super) fn record(&mut self, pc: usize, operation: OpCode, next_pc: usize, memory: usize) {
+        self.instructions += 1;
+        if pc >= self.by_address.len() {
+            self.by_address.resize(pc + 1, 0);
+        }
+        self.by_address[pc] += 1;
+        *self.by_opcode.entry(operation).or_insert(0) += 1;
+        if matches!(operation, OpCode::JumpIfTrue | OpCode::JumpIfFalse) && next_pc <= pc {
+            *self.loops.entry((next_pc, pc)).or_insert(0) += 1;
+        }
+        if memory > self.peak_memory {
+            self.peak_memory = memory;
+            self.growth.push((self.instructions, memory));
+        }
+    }
+
+    pub fn instructions(&self) -> u64 {
+        self.instructions
+    }
+
+    pub fn at_address(&self, address: usize) -> u64 {
+        self.by_address.get(address).copied().unwrap_or(0)
+    }
+
+    pub fn of_opcode(&self, operation: OpCode) -> u64 {
+        self.by_opcode.get(&operation).copied().unwrap_or(0)
+    }
+
+    /// The `count` most executed addresses with their counts, most executed first.
+    pub fn hot_addresses(&self, count: usize) -> Vec<(usize, u64)> {
+        let mut addresses: Vec<(usize, u64)> = self
+            .by_address
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, executed)| *executed > 0)
+            .collect();
+        addresses
+            .sort_unstable_by_key(|(address, executed)| (std::cmp::Reverse(*executed), *address));
+        addresses.truncate(count);
+        addresses
+    }
+
+    /// The `count` loops with the most iterations, most iterations first.
+    pub fn hot_loops(&self, count: usize) -> Vec<Loop> {
+        let mut loops: Vec<Loop> = self
+            .loops
+            .iter()
+            .map(|((start, end), iterations)| Loop {
+                start: *start,
+                end: *end,
+                iterations: *iterations,
+            })
+            .collect();
+        loops.sort_unstable_by_key(|l| (std::cmp::Reverse(l.iterations), l.start, l.end));
+        loops.truncate(count);
+        loops
+    }
+
+    pub fn initial_memory(&self) -> usize {
+        self.initial_memory
+    }
+
+    pub fn peak_memory(&self) -> usize {
+        self.peak_memory
+    }
+
+    /// Every time the memory grew past its previous size: the instruction count and the new size.
+    pub fn memory_growth(&self) -> &[(u64, usize)] {
+        &self.growth
+    }
+
+    /// Adds the counts of another profile, e.g. of another machine running the same program.
+    /// The memory growth is kept from the profile that grew the most.
+    pub fn merge(&mut self, other: &Profile) {
+        self.instructions += other.instructions;
+        if other.by_address.len() > self.by_address.len() {
+            self.by_address.resize(other.by_address.len(), 0);
+        }
+        self.by_address
+            .iter_mut()
+            .zip(&other.by_address)
+            .for_each(|(count, other)| *count += other);
+        other
+            .by_opcode
+            .iter()
+            .for_each(|(operation, count)| *self.by_opcode.entry(*operation).or_insert(0) += count);
+        other
+            .loops
+            .iter()
+            .for_each(|(edge, count)| *self.loops.entry(*edge).or_insert(0) += count);
+        if other.peak_memory > self.peak_memory {
+            self.initial_memory = other.initial_memory;
+            self.peak_memory = other.peak_memory;
+            self.growth.clone_from(&other.growth);
+        }
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let share = |count: u64| 100.0 * count as f64 / self.instructions.max(1) as f64;
+        writeln!(f, "instructions: {}", self.instructions)?;
+        writeln!(
+            f,
+            "memory: {} -> {} cells, grew {} times",
+            self.initial_memory,
+            self.peak_memory,
+            self.growth.len()
+        )?;
+
+        writeln!(f, "opcodes:")?;
+        let mut opcodes: Vec<(&OpCode, &u64)> = self.by_opcode.iter().collect();
+        opcodes.sort_unstable_by_key(|(operation, count)| {
+            (std::cmp::Reverse(**count), operation.code())
+        });
+        for (operation, count) in opcodes {
+            writeln!(
+                f,
+                "  {:<4} {:>12} {:>5.1}%",
+                operation.mnemonic(),
+                count,
+                share(*count)
+            )?;
+        }
+
+        writeln!(f, "hot addresses:")?;
+        for (address, count) in self.hot_addresses(REPORT_LENGTH) {
+            writeln!(f, "  {:>5} {:>12} {:>5.1}%", address, count, share(count))?;
+        }
+
+        writeln!(f, "hot loops:")?;
+        for l in self.hot_loops(REPORT_LENGTH) {
+            writeln!(
+                f,
+                "  {:>5}..{:<5} {:>12} iterations",
+                l.start, l.end, l.iterations
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Computer {
+    /// Starts counting executed instructions into a new profile, replacing the current one.
+    pub fn enable_profiling(&mut self) {
+        self.profile = Some(Box::new(Profile::new(self.memory.len())));
+    }
+
+    pub fn profile(&self) -> Option<&Profile> {
+        self.profile.as_deref()
+    }
+
+    /// Stops profiling and returns the profile.
+    pub fn take_profile(&mut self) -> Option<Profile> {
+        self.profile.take().map(|profile| *profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::intcode::Program;
+
+    fn countdown() -> Computer {
+        Computer::new(
+            &Program::assemble(
+                "
+                    add  #3, #0, counter
+            loop:   out  counter
+                    add  counter, #-1, counter
+                    jt   counter, #loop
+                    add  #1, #0, 20
+                    hlt
+            counter: data 0
+        ",
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_counts() {
+        let mut computer = countdown();
+        assert!(computer.profile().is_none());
+        computer.enable_profiling();
+        computer.execute().unwrap();
+        let profile = computer.profile().unwrap();
+        assert_eq!(profile.instructions(), 1 + 3 * 3 + 2);
+        assert_eq!(profile.of_opcode(OpCode::Add), 1 + 3 + 1);
+        assert_eq!(profile.of_opcode(OpCode::Output), 3);
+        assert_eq!(profile.at_address(4), 3);
+        assert_eq!(profile.at_address(5), 0);
+        assert_eq!(profile.hot_addresses(1), vec![(4, 3)]);
+        assert_eq!(
+            profile.hot_loops(5),
+            vec![Loop {
+                start: 4,
+                end: 10,
+                iterations: 2
+            }]
+        );
+        assert_eq!((profile.initial_memory(), profile.peak_memory()), (19, 21));
+        assert_eq!(profile.memory_growth(), &[(11, 21)]);
+    }
+
+    #[test]
+    fn test_paused_reads_are_not_counted() {
+        let mut computer = Computer::new(&Program::assemble("in 3\nhlt").unwrap());
+        computer.enable_profiling();
+        computer.execute().unwrap();
+        computer.execute().unwrap();
+        assert_eq!(computer.profile().unwrap().instructions(), 0);
+        computer.write_input(1);
+        computer.execute().unwrap();
+        assert_eq!(computer.take_profile().unwrap().instructions(), 2);
+        assert!(computer.profile().is_none());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut first = countdown();
+        first.enable_profiling();
+        first.execute().unwrap();
+        let mut merged = first.take_profile().unwrap();
+        let single = merged.clone();
+        merged.merge(&single);
+        assert_eq!(merged.instructions(), 2 * single.instructions());
+        assert_eq!(merged.hot_loops(1)[0].iterations, 4);
+        assert_eq!(merged.memory_growth(), single.memory_growth());
+        assert!(merged.to_string().starts_with(
+            "instructions: 24\nmemory: 19 -> 21 cells, grew 1 times\nopcodes:\n  add"
+        ));
+    }
+}
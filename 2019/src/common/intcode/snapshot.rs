@@ -0,0 +1,187 @@
+//! Saving and restoring the complete state of a [`Computer`], so exploration code can fork a machine
+//! instead of walking it back, and a stable hash of that state to recognize machines that are in the same state.
+use super::{Computer, ComputerState, QueueDevice, Stream};
+
+/// Everything a [`Computer`] needs to continue running: memory, registers and the values waiting in its queues.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    memory: Vec<i128>,
+    pc: usize,
+    relative_base: i128,
+    state: ComputerState,
+    input: Stream,
+    output: Stream,
+}
+
+impl Snapshot {
+    /// See [`Computer::state_hash`].
+    pub fn state_hash(&self) -> u64 {
+        state_hash(
+            &self.memory,
+            self.pc,
+            self.relative_base,
+            &self.input,
+            &self.output,
+        )
+    }
+}
+
+impl Computer {
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            memory: self.memory.clone(),
+            pc: self.pc,
+            relative_base: self.relative_base,
+            state: self.state,
+            input: self.io.input.clone(),
+            output: self.io.output.clone(),
+        }
+    }
+
+    /// Returns to the state of the snapshot, including the values waiting in the input and output queues.
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.memory.clone_from(&snapshot.memory);
+        self.pc = snapshot.pc;
+        self.relative_base = snapshot.relative_base;
+        self.state = snapshot.state;
+        self.io.input.clone_from(&snapshot.input);
+        self.io.output.clone_from(&snapshot.output);
+    }
+
+    /// A machine that continues from the snapshot, without a profile.
+    pub fn from_snapshot(snapshot: &Snapshot) -> Computer {
+        Computer {
+            memory: snapshot.memory.clone(),
+            pc: snapshot.pc,
+            state: snapshot.state,
+            io: QueueDevice {
+                input: snapshot.input.clone(),
+                output: snapshot.output.clone(),
+            },
+            relative_base: snapshot.relative_base,
+            profile: None,
+        }
+    }
+
+    /// An independent copy of this machine, including the values waiting in its queues.
+    pub fn fork(&self) -> Computer {
+        Computer::from_snapshot(&self.snapshot())
+    }
+
+    /// A hash of memory, registers and waiting input and output, which is the same across runs and platforms.
+    /// Machines whose memory only differs in trailing zeros hash the same, as memory past the end reads as zero.
+    pub fn state_hash(&self) -> u64 {
+        state_hash(
+            &self.memory,
+            self.pc,
+            self.relative_base,
+            &self.io.input,
+            &self.io.output,
+        )
+    }
+}
+
+/// 64 bit FNV-1a, unlike the std hashers it is guaranteed not to change between Rust versions.
+struct Fnv(u64);
+
+impl Fnv {
+    fn write(&mut self, value: i128) {
+        for byte in value.to_le_bytes().iter() {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x100_0000_01b3);
+        }
+    }
+
+    fn write_all<'a, I: IntoIterator<Item = &'a i128>>(&mut self, values: I) {
+        let mut count = 0;
+        for value in values {
+            self.write(*value);
+            count += 1;
+        }
+        // the length keeps e.g. the end of the memory and the start of the input apart
+        self.write(count);
+    }
+}
+
+fn state_hash(
+    memory: &[i128],
+    pc: usize,
+    relative_base: i128,
+    input: &Stream,
+    output: &Stream,
+) -> u64 {
+    let length = memory.iter().rposition(|x| *x != 0).map_or(0, |i| i + 1);
+    let mut hasher = Fnv(0xcbf2_9ce4_8422_2325);
+    hasher.write_all(&memory[..length]);
+    hasher.write(pc as i128);
+    hasher.write(relative_base);
+    hasher.write_all(input.data.iter());
+    hasher.write_all(output.data.iter());
+    hasher.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::intcode::Program;
+
+    fn counter() -> Computer {
+        // echoes every input plus one
+        Computer::new(
+            &Program::assemble(
+                "
+            loop:   in   value
+                    add  value, #1, value
+                    out  value
+                    jt   #1, #loop
+            value:  data 0
+        ",
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_restore() {
+        let mut computer = counter();
+        computer.run(vec![1]).unwrap();
+        let snapshot = computer.snapshot();
+        assert_eq!(computer.run(vec![10]).unwrap(), vec![11]);
+        computer.write_input(5);
+        computer.restore(&snapshot);
+        assert_eq!(computer.run(vec![20]).unwrap(), vec![21]);
+        assert_eq!(
+            computer.snapshot(),
+            Computer::from_snapshot(&computer.snapshot()).snapshot()
+        );
+    }
+
+    #[test]
+    fn test_fork_is_independent() {
+        let mut computer = counter();
+        computer.write_input(1);
+        let mut fork = computer.fork();
+        assert_eq!(fork.run(vec![2]).unwrap(), vec![2, 3]);
+        assert_eq!(computer.run(vec![]).unwrap(), vec![2]);
+        assert_eq!(fork.memory[11], 3);
+        assert_eq!(computer.memory[11], 2);
+    }
+
+    #[test]
+    fn test_state_hash() {
+        let mut computer = counter();
+        let start = computer.state_hash();
+        assert_eq!(start, counter().state_hash());
+        assert_eq!(start, computer.snapshot().state_hash());
+
+        computer.write_input(1);
+        let with_input = computer.state_hash();
+        assert_ne!(start, with_input);
+
+        computer.memory.resize(100, 0);
+        assert_eq!(with_input, computer.state_hash());
+
+        computer.run(vec![]).unwrap();
+        assert_ne!(with_input, computer.state_hash());
+    }
+}
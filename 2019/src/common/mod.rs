@@ -1,5 +1,5 @@
+pub mod canvas;
 pub mod graph;
-pub mod point;
 pub mod grid;
 pub mod intcode;
-pub mod canvas;
+pub mod point;
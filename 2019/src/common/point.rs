@@ -1,10 +1,13 @@
-use std::ops::{Add, AddAssign};
 use std::ops::Sub;
+use std::ops::{Add, AddAssign};
+
+use aoc_core::direction::Direction;
+use aoc_core::sparse::GridPoint;
 
 #[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
-pub struct Point{
+pub struct Point {
     pub x: isize,
-    pub y: isize
+    pub y: isize,
 }
 
 impl Add for Point {
@@ -38,15 +41,34 @@ impl Sub for Point {
     }
 }
 
-impl Point{
+impl Point {
     pub fn new(x: isize, y: isize) -> Point {
-        Point{
-            x: x,
-            y: y
-        }
+        Point { x, y }
     }
 
     pub fn abs(&self) -> isize {
         self.x.abs() + self.y.abs()
     }
 }
+
+/// The unit step of a direction, see [`Direction::offset`].
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        let (x, y) = direction.offset();
+        Point::new(x, y)
+    }
+}
+
+impl GridPoint for Point {
+    fn x(&self) -> i64 {
+        self.x as i64
+    }
+
+    fn y(&self) -> i64 {
+        self.y as i64
+    }
+
+    fn from_xy(x: i64, y: i64) -> Self {
+        Point::new(x as isize, y as isize)
+    }
+}
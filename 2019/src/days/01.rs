@@ -1,5 +1,6 @@
+advent_of_code::solution!(1);
+
 use log::{debug, info};
-use std::fs;
 
 fn fuel_required(mass: &i32) -> i32 {
     mass / 3 - 2
@@ -7,43 +8,37 @@ fn fuel_required(mass: &i32) -> i32 {
 
 fn fuel_required_recursive(mass: &i32) -> i32 {
     let fuel = fuel_required(mass);
-    if fuel < 9 { //rest is handled by wishing really hard
+    if fuel < 9 {
+        //rest is handled by wishing really hard
         fuel
     } else {
         fuel + fuel_required_recursive(&fuel)
     }
 }
 
-pub fn solve(input_file: &str){
-    let masses = parse(&input_file);
-
-    debug!("{:?}", masses);
-
-    part1(&masses);
-    part2(&masses);
-}
-
-fn parse(input_file: &str) -> Vec<i32> {
-    let contents = fs::read_to_string(input_file)
-        .expect("Something went wrong reading the file");
-    
-    contents.lines().map(|x| x.parse::<i32>().unwrap()).collect()
+fn parse(input: &str) -> Vec<i32> {
+    input.lines().map(|x| x.parse::<i32>().unwrap()).collect()
 }
 
-fn part1(masses: &Vec<i32>) {
+pub fn part_one(input: &str) -> Option<i32> {
+    let masses = parse(input);
+    debug!("{:?}", masses);
     let total: i32 = masses.iter().map(fuel_required).sum();
     info!("Total fuel required {}", total);
+    Some(total)
 }
 
-fn part2(masses: &Vec<i32>) {
+pub fn part_two(input: &str) -> Option<i32> {
+    let masses = parse(input);
     let total: i32 = masses.iter().map(fuel_required_recursive).sum();
     info!("Total fuel required {}", total);
+    Some(total)
 }
 
 #[cfg(test)]
-mod tests{
+mod tests {
     use super::*;
-    
+
     #[test]
     fn test_fuel_required() {
         assert_eq!(fuel_required(&12), 2);
@@ -0,0 +1,33 @@
+advent_of_code::solution!(2);
+
+use log::{debug, info};
+
+use advent_of_code::common::intcode::*;
+
+fn run(program: &Program, noun: i128, verb: i128) -> Result<Vec<i128>, IntcodeError> {
+    let mut computer = Computer::new(program);
+    computer.memory[1] = noun;
+    computer.memory[2] = verb;
+    computer.execute()?;
+    debug!("{:?}", computer.memory);
+    Ok(computer.memory)
+}
+
+pub fn part_one(input: &str) -> Option<i128> {
+    let program = Program::parse(input).ok()?;
+    let result = run(&program, 12, 2).ok()?;
+    info!("Value at position 0: {}", result[0]);
+    Some(result[0])
+}
+
+pub fn part_two(input: &str) -> Option<i128> {
+    let program = Program::parse(input).ok()?;
+    let solution = (0i128..=99)
+        .flat_map(|x| (0i128..=99).map(move |y| (x, y)))
+        // most combinations compute garbage addresses, those simply are not the solution
+        .find(|(noun, verb)| {
+            run(&program, *noun, *verb).is_ok_and(|result| result[0] == 19690720)
+        })?;
+    info!("The solution is {}", 100 * solution.0 + solution.1);
+    Some(100 * solution.0 + solution.1)
+}
@@ -0,0 +1,101 @@
+advent_of_code::solution!(3);
+
+use log::{debug, info, trace};
+use std::collections::HashSet;
+use std::iter;
+use std::iter::FromIterator;
+use std::ops::AddAssign;
+
+#[derive(Clone, Hash, Eq, PartialEq, Debug)]
+struct Point {
+    x: isize,
+    y: isize,
+}
+
+impl Point {
+    fn manhattan(&self, other: &Point) -> isize {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        };
+    }
+}
+
+#[derive(Debug)]
+struct Wire {
+    points: Vec<Point>,
+}
+
+impl Wire {
+    fn from_str(def: &str) -> Wire {
+        let points = def
+            .split(",")
+            .flat_map(|step| {
+                let direction = step.chars().next().unwrap();
+                let amount = step[1..].parse::<usize>().unwrap();
+                match direction {
+                    'U' => iter::repeat(Point { x: 0, y: 1 }).take(amount),
+                    'D' => iter::repeat(Point { x: 0, y: -1 }).take(amount),
+                    'R' => iter::repeat(Point { x: 1, y: 0 }).take(amount),
+                    'L' => iter::repeat(Point { x: -1, y: 0 }).take(amount),
+                    _ => panic!("Unknown direction encountered {}", direction),
+                }
+            })
+            .scan(Point { x: 0, y: 0 }, |pos, step| {
+                pos.add_assign(step);
+                Some(pos.clone())
+            })
+            .collect::<Vec<Point>>();
+
+        Wire { points }
+    }
+
+    fn parse(input: &str) -> Vec<Wire> {
+        input.lines().map(Wire::from_str).collect()
+    }
+}
+
+pub fn part_one(input: &str) -> Option<isize> {
+    let wires = Wire::parse(input);
+    let (wire1, wire2) = (&wires[0], &wires[1]);
+    let first: HashSet<Point> = HashSet::from_iter(wire1.points.iter().cloned());
+    let second: HashSet<Point> = HashSet::from_iter(wire2.points.iter().cloned());
+    let intersections = first.intersection(&second);
+    debug!("{:?}", &intersections);
+    let origin = Point { x: 0, y: 0 };
+    let closest = intersections.min_by_key(|x| x.manhattan(&origin)).unwrap();
+    let distance = origin.manhattan(closest);
+    trace!("{:?}", wire1);
+    trace!("{:?}", wire2);
+    info!("distance {} {:?}", distance, closest);
+    Some(distance)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let wires = Wire::parse(input);
+    let (wire1, wire2) = (&wires[0], &wires[1]);
+    let first: HashSet<Point> = HashSet::from_iter(wire1.points.iter().cloned());
+    let second: HashSet<Point> = HashSet::from_iter(wire2.points.iter().cloned());
+    let intersections = first.intersection(&second);
+
+    let distance_to_best = intersections
+        .map(|intersection| {
+            let distance1 = wire1.points.iter().position(|x| x == intersection).unwrap() + 1;
+            let distance2 = wire2.points.iter().position(|x| x == intersection).unwrap() + 1;
+            debug!(
+                "Distance to intersection {:?}: {}+{}",
+                intersection, distance1, distance2
+            );
+            distance1 + distance2
+        })
+        .min()
+        .unwrap();
+    info!("distance {}", distance_to_best);
+    Some(distance_to_best)
+}
@@ -1,5 +1,6 @@
+advent_of_code::solution!(4);
+
 use log::{debug, info};
-use std::fs;
 
 #[derive(Copy, Clone, Debug)]
 struct NumberUtils(usize);
@@ -27,63 +28,60 @@ impl NumberUtils {
 
     fn repeated_digit_group(&self) -> bool {
         let digits = self.digits();
-        let middle = digits.windows(4).any(|w| {
-            w[0] != w[1] && w[1] == w[2] && w[2] != w[3]
-        });
+        let middle = digits
+            .windows(4)
+            .any(|w| w[0] != w[1] && w[1] == w[2] && w[2] != w[3]);
         let left_edge = digits[0] == digits[1] && digits[1] != digits[2];
         let l = digits.len();
-        let right_edge = digits[l-1] == digits[l-2] && digits[l-2] != digits[l-3];
+        let right_edge = digits[l - 1] == digits[l - 2] && digits[l - 2] != digits[l - 3];
         middle || left_edge || right_edge
     }
-
-}
-
-pub fn solve(input_file: &str){
-    let numbers = parse(input_file);
-
-    part1(numbers[0], numbers[1]);
-    part2(numbers[0], numbers[1]);
 }
 
-fn parse(input_file: &str) -> Vec<usize> {
-    let contents = fs::read_to_string(input_file)
-        .expect("Something went wrong reading the file");
-    
-    contents.trim().split("-").map(|x| x.parse::<usize>().unwrap()).collect()
+fn parse(input: &str) -> Vec<usize> {
+    input
+        .trim()
+        .split("-")
+        .map(|x| x.parse::<usize>().unwrap())
+        .collect()
 }
 
-fn part1(number1: usize, number2: usize) {
+pub fn part_one(input: &str) -> Option<usize> {
+    let numbers = parse(input);
     let mut count = 0usize;
-    for i in number1..=number2 {
+    for i in numbers[0]..=numbers[1] {
         let number = NumberUtils(i);
         if number.non_decreasing() && number.repeated_digit() {
             debug!("{:?}", number);
             count += 1;
         }
     }
-    info!("P1: Number of valid passwords is {}", count)
+    info!("P1: Number of valid passwords is {}", count);
+    Some(count)
 }
 
-fn part2(number1: usize, number2: usize) {
+pub fn part_two(input: &str) -> Option<usize> {
+    let numbers = parse(input);
     let mut count = 0usize;
-    for i in number1..=number2 {
+    for i in numbers[0]..=numbers[1] {
         let number = NumberUtils(i);
         if number.non_decreasing() && number.repeated_digit_group() {
             debug!("{:?}", number);
             count += 1;
         }
     }
-    info!("P2: Number of valid passwords is {}", count)
+    info!("P2: Number of valid passwords is {}", count);
+    Some(count)
 }
 
 #[cfg(test)]
-mod tests{
+mod tests {
     use super::*;
-    
+
     #[test]
     fn test_digit_groups() {
-        assert_eq!(NumberUtils(112233).repeated_digit_group(), true);
-        assert_eq!(NumberUtils(123444).repeated_digit_group(), false);
-        assert_eq!(NumberUtils(111122).repeated_digit_group(), true);
+        assert!(NumberUtils(112233).repeated_digit_group());
+        assert!(!NumberUtils(123444).repeated_digit_group());
+        assert!(NumberUtils(111122).repeated_digit_group());
     }
 }
@@ -0,0 +1,134 @@
+advent_of_code::solution!(5);
+
+use log::{debug, info};
+
+use advent_of_code::common::intcode::*;
+
+pub fn part_one(input: &str) -> Option<i128> {
+    let mut computer = Computer::new(&Program::parse(input).ok()?);
+    let output = computer.run(vec![1]).ok()?;
+    debug!("{:?}", output);
+    info!("{:?}", output[output.len() - 1]);
+    output.last().copied()
+}
+
+pub fn part_two(input: &str) -> Option<i128> {
+    let mut computer = Computer::new(&Program::parse(input).ok()?);
+    let output = computer.run(vec![5]).ok()?;
+    debug!("{:?}", output);
+    info!("{:?}", output);
+    output.last().copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_input_output_program() {
+        let program = Program {
+            program: vec![3, 0, 4, 0, 99],
+        };
+        let input = vec![1];
+        let output = Computer::new(&program).run(input).unwrap();
+        assert_eq!(output.len(), 1);
+        assert_eq!(output[0], 1);
+    }
+
+    #[test]
+    fn test_immediate_mode_multiplication() {
+        let program = Program {
+            program: vec![1002, 4, 3, 4, 33],
+        };
+        let mut computer = Computer::new(&program);
+        computer.run(vec![]).unwrap();
+        assert_eq!(computer.memory[4], 99);
+    }
+
+    #[test]
+    fn test_negative_values() {
+        let program = Program {
+            program: vec![1101, 100, -1, 4, 0],
+        };
+        let mut computer = Computer::new(&program);
+        computer.run(vec![]).unwrap();
+        assert_eq!(computer.memory[4], 99);
+    }
+
+    #[test]
+    fn test_equal_to_position() {
+        let program = Program {
+            program: vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8],
+        };
+
+        assert_eq!(Computer::new(&program).run(vec![9]).unwrap()[0], 0);
+        assert_eq!(Computer::new(&program).run(vec![8]).unwrap()[0], 1);
+        assert_eq!(Computer::new(&program).run(vec![7]).unwrap()[0], 0);
+    }
+
+    #[test]
+    fn test_equal_to_immediate() {
+        let program = Program {
+            program: vec![3, 3, 1108, -1, 8, 3, 4, 3, 99],
+        };
+
+        assert_eq!(Computer::new(&program).run(vec![9]).unwrap()[0], 0);
+        assert_eq!(Computer::new(&program).run(vec![8]).unwrap()[0], 1);
+        assert_eq!(Computer::new(&program).run(vec![7]).unwrap()[0], 0);
+    }
+
+    #[test]
+    fn test_less_than_position() {
+        let program = Program {
+            program: vec![3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8],
+        };
+
+        assert_eq!(Computer::new(&program).run(vec![9]).unwrap()[0], 0);
+        assert_eq!(Computer::new(&program).run(vec![8]).unwrap()[0], 0);
+        assert_eq!(Computer::new(&program).run(vec![7]).unwrap()[0], 1);
+    }
+
+    #[test]
+    fn test_less_than_immediate() {
+        let program = Program {
+            program: vec![3, 3, 1107, -1, 8, 3, 4, 3, 99],
+        };
+
+        assert_eq!(Computer::new(&program).run(vec![9]).unwrap()[0], 0);
+        assert_eq!(Computer::new(&program).run(vec![8]).unwrap()[0], 0);
+        assert_eq!(Computer::new(&program).run(vec![7]).unwrap()[0], 1);
+    }
+
+    #[test]
+    fn test_jump_position_mode() {
+        let program = Program {
+            program: vec![3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9],
+        };
+        assert_eq!(Computer::new(&program).run(vec![0]).unwrap()[0], 0);
+        assert_eq!(Computer::new(&program).run(vec![1]).unwrap()[0], 1);
+    }
+
+    #[test]
+    fn test_jump_immediate_mode() {
+        let program = Program {
+            program: vec![3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1],
+        };
+        assert_eq!(Computer::new(&program).run(vec![0]).unwrap()[0], 0);
+        assert_eq!(Computer::new(&program).run(vec![1]).unwrap()[0], 1);
+    }
+
+    #[test]
+    fn test_large_program() {
+        let program = Program {
+            program: vec![
+                3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36,
+                98, 0, 0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000,
+                1, 20, 4, 20, 1105, 1, 46, 98, 99,
+            ],
+        };
+
+        assert_eq!(Computer::new(&program).run(vec![7]).unwrap()[0], 999);
+        assert_eq!(Computer::new(&program).run(vec![8]).unwrap()[0], 1000);
+        assert_eq!(Computer::new(&program).run(vec![9]).unwrap()[0], 1001);
+    }
+}
@@ -0,0 +1,122 @@
+advent_of_code::solution!(6);
+
+use log::info;
+use std::collections::hash_map::Entry;
+use std::collections::*;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct Planet<'s> {
+    name: &'s str,
+    parent: Option<&'s str>,
+    children: Vec<&'s str>,
+}
+
+#[derive(Clone, Debug)]
+struct StarChart<'s> {
+    planets: HashMap<&'s str, Planet<'s>>,
+}
+
+impl<'s> StarChart<'s> {
+    fn get_planet(&self, planet: &'s str) -> Option<&'s Planet<'_>> {
+        self.planets.get(planet)
+    }
+
+    fn parent_of(&self, planet: &'s str) -> Option<&'s str> {
+        self.planets.get(planet).map(|p| p.parent).unwrap_or(None)
+    }
+
+    fn from_string(contents: &str) -> StarChart<'_> {
+        let mut planets = HashMap::<&str, Planet>::new();
+
+        contents.lines().for_each(|x| {
+            let bodies: Vec<&str> = x.split(")").take(2).collect();
+
+            let planet = bodies[0];
+            let orbiter = bodies[1];
+
+            match planets.entry(planet) {
+                Entry::Occupied(slot) => {
+                    slot.into_mut().children.push(orbiter);
+                }
+                Entry::Vacant(slot) => {
+                    slot.insert(Planet {
+                        name: planet,
+                        parent: None,
+                        children: vec![orbiter],
+                    });
+                }
+            }
+
+            match planets.entry(orbiter) {
+                Entry::Occupied(slot) => {
+                    slot.into_mut().parent = Some(planet);
+                }
+                Entry::Vacant(slot) => {
+                    slot.insert(Planet {
+                        name: orbiter,
+                        parent: Some(planet),
+                        children: Vec::new(),
+                    });
+                }
+            }
+        });
+
+        StarChart { planets }
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let input = StarChart::from_string(input);
+
+    fn walk(star_chart: &StarChart, current_planet: &str, current_depth: usize) -> usize {
+        let children_total = star_chart
+            .get_planet(current_planet)
+            .map(|x| {
+                x.children
+                    .iter()
+                    .map(|child| walk(star_chart, child, current_depth + 1))
+                    .sum()
+            })
+            .unwrap_or(0);
+
+        current_depth + children_total
+    }
+
+    let total = walk(&input, "COM", 0);
+
+    info!("Total orbital distances {}", total);
+    Some(total)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let input = StarChart::from_string(input);
+
+    fn path_to<'s>(input: &'s StarChart, planet: &'s str) -> Vec<&'s str> {
+        let mut path: Vec<&'s str> = vec![];
+        let mut current = planet;
+
+        while let Some(parent) = input.parent_of(current) {
+            current = parent;
+            path.push(current);
+        }
+
+        path.reverse();
+        path
+    }
+
+    let santa_path = path_to(&input, "SAN");
+    let you_path = path_to(&input, "YOU");
+
+    let common_path_length: usize = santa_path
+        .iter()
+        .zip(you_path.iter())
+        .take_while(|(s, y)| s == y)
+        .count();
+    let path_to_santa = santa_path.len() + you_path.len() - 2 * common_path_length;
+
+    info!(
+        "Number of orbital transfers to get to santa {}",
+        path_to_santa
+    );
+    Some(path_to_santa)
+}
@@ -0,0 +1,103 @@
+advent_of_code::solution!(7);
+
+use log::{debug, info};
+
+use advent_of_code::common::intcode::*;
+
+pub fn part_one(input: &str) -> Option<i128> {
+    use permutohedron::heap_recursive;
+    let program = &Program::parse(input).ok()?;
+    let mut best = 0;
+    let mut data = [0, 1, 2, 3, 4];
+    heap_recursive(&mut data, |permutation| {
+        debug!("{:?}", permutation);
+        let amplified = run_amplifier(permutation.to_vec(), program);
+        if amplified > best {
+            best = amplified;
+        }
+    });
+    info!("{}", best);
+    Some(best)
+}
+
+pub fn part_two(input: &str) -> Option<i128> {
+    use permutohedron::heap_recursive;
+    let program = &Program::parse(input).ok()?;
+    let mut best = 0;
+    let mut data = [5, 6, 7, 8, 9];
+
+    heap_recursive(&mut data, |permutation| {
+        debug!("{:?}", permutation);
+        let amplified = run_amplifier_recursive(permutation.to_vec(), program);
+        if amplified > best {
+            best = amplified;
+        }
+    });
+
+    info!("{}", best);
+    Some(best)
+}
+
+fn run_amplifier(phase_sequence: Vec<i128>, program: &Program) -> i128 {
+    Pipeline::new(program, phase_sequence.len())
+        .seed(&phase_sequence)
+        .run(vec![0])
+        .expect("amplifier failed")
+        .expect("No output generated!")
+}
+
+fn run_amplifier_recursive(phase_sequence: Vec<i128>, program: &Program) -> i128 {
+    Pipeline::new(program, phase_sequence.len())
+        .seed(&phase_sequence)
+        .feedback()
+        .run(vec![0])
+        .expect("amplifier failed")
+        .expect("No output generated!")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amplifier_correct() {
+        assert_eq!(
+            43210,
+            run_amplifier(
+                vec![4, 3, 2, 1, 0],
+                &Program {
+                    program: vec![3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0]
+                }
+            )
+        );
+    }
+    #[test]
+    fn amplifier_recursive_correct() {
+        assert_eq!(
+            139629729,
+            run_amplifier_recursive(
+                vec![9, 8, 7, 6, 5],
+                &Program {
+                    program: vec![
+                        3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27,
+                        1001, 28, -1, 28, 1005, 28, 6, 99, 0, 0, 5
+                    ]
+                }
+            )
+        );
+        assert_eq!(
+            18216,
+            run_amplifier_recursive(
+                vec![9, 7, 8, 5, 6],
+                &Program {
+                    program: vec![
+                        3, 52, 1001, 52, -5, 52, 3, 53, 1, 52, 56, 54, 1007, 54, 5, 55, 1005, 55,
+                        26, 1001, 54, -5, 54, 1105, 1, 12, 1, 53, 54, 53, 1008, 54, 0, 55, 1001,
+                        55, 1, 55, 2, 53, 55, 53, 4, 53, 1001, 56, -1, 56, 1005, 56, 6, 99, 0, 0,
+                        0, 0, 10
+                    ]
+                }
+            )
+        );
+    }
+}
@@ -0,0 +1,85 @@
+advent_of_code::solution!(8);
+
+use log::{debug, info};
+use std::fmt;
+use std::{char, str};
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub layers: Vec<Vec<u32>>,
+}
+
+impl Image {
+    fn pixel(&self, x: usize, y: usize) -> u32 {
+        self.layers
+            .iter()
+            .map(|layer| layer[y * self.width + x])
+            .find(|&color| color != 2)
+            .unwrap_or(2)
+    }
+
+    fn from_string(contents: &str, width: usize, height: usize) -> Image {
+        let pixels_per_layer = width * height;
+
+        let layers = contents
+            .as_bytes()
+            .chunks(pixels_per_layer)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .map(|x| (*x as char).to_digit(10).unwrap())
+                    .collect::<Vec<u32>>()
+            })
+            .collect();
+
+        Image {
+            width,
+            height,
+            layers,
+        }
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr: String = (0..self.height)
+            .map(|y| {
+                let line: String = (0..self.width)
+                    .map(|x| {
+                        //char::from_digit(self.pixel(x, y), 10).unwrap()
+                        let value = self.pixel(x, y);
+                        if value == 1 {
+                            '█'
+                        } else {
+                            ' '
+                        }
+                    })
+                    .collect();
+                format!("{}\n", line)
+            })
+            .collect();
+        write!(f, "{}", repr)
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let input = Image::from_string(input.trim(), 25, 6);
+    let best_layer = input
+        .layers
+        .iter()
+        .min_by_key(|layer| layer.iter().filter(|&x| *x == 0).count())
+        .unwrap();
+    let one_digits = best_layer.iter().filter(|&x| *x == 1).count();
+    let two_digits = best_layer.iter().filter(|&x| *x == 2).count();
+    debug!("{:?}", best_layer);
+    info!("1 * 2 : {}", one_digits * two_digits);
+    Some(one_digits * two_digits)
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    let input = Image::from_string(input.trim(), 25, 6);
+    info!("\n{}", input);
+    Some(input.to_string())
+}
@@ -0,0 +1,21 @@
+advent_of_code::solution!(9);
+
+use log::info;
+
+use advent_of_code::common::intcode::*;
+
+pub fn part_one(input: &str) -> Option<i128> {
+    let program = Program::parse(input).ok()?;
+    let mut computer = Computer::new(&program);
+    let output = computer.run(vec![1]).ok()?;
+    info!("BOOST {:?}", output);
+    output.last().copied()
+}
+
+pub fn part_two(input: &str) -> Option<i128> {
+    let program = Program::parse(input).ok()?;
+    let mut computer = Computer::new(&program);
+    let output = computer.run(vec![2]).ok()?;
+    info!("BOOST {:?}", output);
+    output.last().copied()
+}
@@ -0,0 +1,126 @@
+advent_of_code::solution!(11);
+
+use log::{debug, info};
+use std::fmt;
+use std::fmt::Display;
+
+use advent_of_code::common::intcode::*;
+use advent_of_code::sparse::SparseGrid;
+
+#[derive(Debug)]
+struct Canvas {
+    paint: SparseGrid<(isize, isize), usize>,
+}
+
+impl Canvas {
+    fn new() -> Canvas {
+        Canvas {
+            paint: SparseGrid::new(),
+        }
+    }
+
+    fn paint(&mut self, x: isize, y: isize, color: usize) {
+        self.paint.insert((x, y), color);
+    }
+
+    fn color_at(&self, x: isize, y: isize) -> usize {
+        *self.paint.get((x, y)).unwrap_or(&0)
+    }
+}
+
+impl Display for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = self.paint.render_with(|_, color| {
+            let value = color.copied().unwrap_or(0);
+            if value == 1 {
+                '█'
+            } else {
+                ' '
+            }
+        });
+        write!(f, "{}", repr)
+    }
+}
+
+#[derive(Debug)]
+struct Robot {
+    x: isize,
+    y: isize,
+    direction: usize, //0 ^, 1 >, 2 v, 3 <
+}
+
+impl Robot {
+    fn new() -> Robot {
+        Robot {
+            x: 0,
+            y: 0,
+            direction: 0,
+        }
+    }
+
+    fn turn(&mut self, turn: usize) {
+        if turn == 0 {
+            self.direction = (self.direction + 3) % 4;
+        } else {
+            self.direction = (self.direction + 1) % 4;
+        }
+    }
+
+    fn move_forward(&mut self) {
+        match self.direction {
+            0 => self.y -= 1,
+            1 => self.x += 1,
+            2 => self.y += 1,
+            3 => self.x -= 1,
+            _ => panic!("unknown direction {}", self.direction),
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let program = &Program::parse(input).ok()?;
+    let mut computer = Computer::new(program);
+
+    let mut robot = Robot::new();
+    let mut canvas = Canvas::new();
+    while !computer.is_halted() {
+        let color = canvas.color_at(robot.x, robot.y);
+        computer.write_input(color as i128);
+        computer.execute().ok()?;
+        let paint = computer.read_output().expect("No output from robot!") as usize;
+        let turn = computer.read_output().expect("No output from robot!") as usize;
+        debug!("Robot at position {:?}", robot);
+        debug!("Painting with {} and turning {}", paint, turn);
+        canvas.paint(robot.x, robot.y, paint);
+        robot.turn(turn);
+        robot.move_forward();
+    }
+
+    info!("painted panels {:?}", canvas.paint.len());
+    Some(canvas.paint.len())
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    let program = &Program::parse(input).ok()?;
+    let mut computer = Computer::new(program);
+
+    let mut robot = Robot::new();
+    let mut canvas = Canvas::new();
+    canvas.paint(0, 0, 1);
+
+    while !computer.is_halted() {
+        let color = canvas.color_at(robot.x, robot.y);
+        computer.write_input(color as i128);
+        computer.execute().ok()?;
+        let paint = computer.read_output().expect("No output from robot!") as usize;
+        let turn = computer.read_output().expect("No output from robot!") as usize;
+        debug!("Robot at position {:?}", robot);
+        debug!("Painting with {} and turning {}", paint, turn);
+        canvas.paint(robot.x, robot.y, paint);
+        robot.turn(turn);
+        robot.move_forward();
+    }
+
+    info!("\n{}", canvas);
+    Some(canvas.to_string())
+}
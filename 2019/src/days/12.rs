@@ -1,36 +1,34 @@
-use log::{debug, info};
-use std::fs;
-use std::collections::HashSet;
-use std::ops::AddAssign;
-use num::integer::lcm;
+advent_of_code::solution!(12);
+
 use itertools::izip;
+use num::integer::lcm;
+use std::collections::HashSet;
 
 #[derive(Hash, Clone, Eq, PartialEq, Debug)]
-struct AxisSimulation{
+struct AxisSimulation {
     positions: Vec<isize>,
-    velocities: Vec<isize>
+    velocities: Vec<isize>,
 }
 
-impl AxisSimulation{
+impl AxisSimulation {
     fn new() -> AxisSimulation {
-        AxisSimulation{
+        AxisSimulation {
             positions: Vec::new(),
-            velocities: Vec::new()
+            velocities: Vec::new(),
         }
     }
-    
+
     fn tick(&mut self) {
-        let gravities: Vec<isize> = self.positions
+        let gravities: Vec<isize> = self
+            .positions
             .iter()
             .map(|moon| {
                 self.positions
                     .iter()
-                    .fold(0, |accum, other| {
-                        accum + (other - moon).signum()
-                    })
+                    .fold(0, |accum, other| accum + (other - moon).signum())
             })
             .collect();
-        
+
         self.velocities
             .iter_mut()
             .zip(gravities)
@@ -53,7 +51,7 @@ impl AxisSimulation{
         loop {
             state.tick();
             if visited.replace(state.clone()).is_some() {
-                break
+                break;
             }
         }
         visited.len()
@@ -61,10 +59,10 @@ impl AxisSimulation{
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
-struct Simulation{
+struct Simulation {
     x_axis: AxisSimulation,
     y_axis: AxisSimulation,
-    z_axis: AxisSimulation
+    z_axis: AxisSimulation,
 }
 
 impl Simulation {
@@ -73,25 +71,24 @@ impl Simulation {
         let mut y_axis = AxisSimulation::new();
         let mut z_axis = AxisSimulation::new();
 
-        data
-            .lines()
-            .for_each(|line| {
-                let data = &line[1..line.len()-1];
-                let values: Vec<isize> = data.split(",").map(|part| {
-                    part.split("=").last().unwrap().parse::<isize>().unwrap()
-                }).collect();
-                x_axis.positions.push(values[0]);
-                x_axis.velocities.push(0);
-                y_axis.positions.push(values[1]);
-                y_axis.velocities.push(0);
-                z_axis.positions.push(values[2]);
-                z_axis.velocities.push(0);
-            });
+        data.lines().for_each(|line| {
+            let data = &line[1..line.len() - 1];
+            let values: Vec<isize> = data
+                .split(",")
+                .map(|part| part.split("=").last().unwrap().parse::<isize>().unwrap())
+                .collect();
+            x_axis.positions.push(values[0]);
+            x_axis.velocities.push(0);
+            y_axis.positions.push(values[1]);
+            y_axis.velocities.push(0);
+            z_axis.positions.push(values[2]);
+            z_axis.velocities.push(0);
+        });
 
-        Simulation{
-            x_axis: x_axis,
-            y_axis: y_axis,
-            z_axis: z_axis
+        Simulation {
+            x_axis,
+            y_axis,
+            z_axis,
         }
     }
 
@@ -102,17 +99,8 @@ impl Simulation {
     }
 }
 
-pub fn solve(input_file: &str){
-    let contents = fs::read_to_string(input_file)
-        .expect("Something went wrong reading the file");
-
-    let input = Simulation::from_string(&contents);
-
-    part1(&input);
-    part2(&input);
-}
-
-fn part1(input: &Simulation) {
+pub fn part_one(input: &str) -> Option<isize> {
+    let input = Simulation::from_string(input);
     let mut state = input.to_owned();
     (1..=1000).for_each(|_| state.tick());
 
@@ -123,20 +111,25 @@ fn part1(input: &Simulation) {
         state.y_axis.velocities,
         state.z_axis.positions,
         state.z_axis.velocities
-    ).map(|(px, vx, py, vy, pz, vz)| {
+    )
+    .map(|(px, vx, py, vy, pz, vz)| {
         let potential = px.abs() + py.abs() + pz.abs();
         let kinetic = vx.abs() + vy.abs() + vz.abs();
         potential * kinetic
-    }).sum();
-    
+    })
+    .sum();
+
     println!("Total system energy {}", total_energy);
+    Some(total_energy)
 }
 
-fn part2(state: &Simulation) {
+pub fn part_two(input: &str) -> Option<usize> {
+    let state = Simulation::from_string(input);
     let x_cycle = state.x_axis.cycle_size();
     let y_cycle = state.y_axis.cycle_size();
     let z_cycle = state.z_axis.cycle_size();
-    
+
     let cycle_size = lcm(x_cycle, lcm(y_cycle, z_cycle));
     println!("Found cycle of size {} steps", cycle_size);
+    Some(cycle_size)
 }
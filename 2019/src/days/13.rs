@@ -0,0 +1,123 @@
+advent_of_code::solution!(13);
+
+use log::info;
+use std::fmt;
+use std::fmt::Display;
+
+use advent_of_code::common::intcode::*;
+use advent_of_code::sparse::SparseGrid;
+
+#[derive(Debug)]
+struct Canvas {
+    paint: SparseGrid<(isize, isize), usize>,
+}
+
+impl Canvas {
+    fn new() -> Canvas {
+        Canvas {
+            paint: SparseGrid::new(),
+        }
+    }
+
+    fn paint(&mut self, x: isize, y: isize, color: usize) {
+        self.paint.insert((x, y), color);
+    }
+}
+
+impl Display for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = self.paint.render_with(|_, color| {
+            let value = color.copied().unwrap_or(0);
+            match value {
+                0 => ' ',
+                1 => '█',
+                2 => '■',
+                3 => '=',
+                4 => '●',
+                _ => panic!("Unknown character to paint {}", value),
+            }
+        });
+        write!(f, "{}", repr)
+    }
+}
+
+/// The screen and joystick of the arcade cabinet, the joystick follows the ball with the paddle.
+struct Cabinet {
+    canvas: Canvas,
+    score: usize,
+    paddle_position: isize,
+    ball_position: isize,
+    pending: Vec<i128>,
+}
+
+impl Cabinet {
+    fn new() -> Cabinet {
+        Cabinet {
+            canvas: Canvas::new(),
+            score: 0,
+            paddle_position: 0,
+            ball_position: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    fn play(program: &Program, coins: Option<i128>) -> Result<Cabinet, IntcodeError> {
+        let mut computer = Computer::new(program);
+        if let Some(coins) = coins {
+            computer.memory[0] = coins;
+        }
+        let mut cabinet = Cabinet::new();
+        computer.run_device(&mut cabinet)?;
+        Ok(cabinet)
+    }
+}
+
+impl IoDevice for Cabinet {
+    fn read(&mut self) -> Option<i128> {
+        info!("{}", self);
+        Some((self.ball_position - self.paddle_position).signum() as i128)
+    }
+
+    fn write(&mut self, value: i128) {
+        self.pending.push(value);
+        if let [x, y, data] = self.pending[..] {
+            self.pending.clear();
+            let x = x as isize;
+            let y = y as isize;
+            let data = data as usize;
+            if x == -1 {
+                self.score = data;
+            } else {
+                self.canvas.paint(x, y, data);
+                match data {
+                    3 => self.paddle_position = x,
+                    4 => self.ball_position = x,
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Display for Cabinet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\n{}\nSCORE:{}\n", self.canvas, self.score)
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let program = &Program::parse(input).ok()?;
+    let cabinet = Cabinet::play(program, None).ok()?;
+    info!("\n{}", cabinet);
+    let blocks = cabinet.canvas.paint.values().filter(|x| **x == 2).count();
+    println!("Block count {:?}", blocks);
+    Some(blocks)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let program = &Program::parse(input).ok()?;
+    //infinite coins
+    let cabinet = Cabinet::play(program, Some(2)).ok()?;
+    println!("Final score is: {}", cabinet.score);
+    Some(cabinet.score)
+}
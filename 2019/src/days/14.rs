@@ -1,53 +1,58 @@
-use log::{debug, info};
-use std::fs;
+advent_of_code::solution!(14);
+
+use log::debug;
+use std::cmp::min;
 use std::collections::HashMap;
 use std::collections::VecDeque;
-use std::ops::AddAssign;
-use num::integer::lcm;
-use itertools::izip;
-use std::cmp::min;
 
 #[derive(Debug, Clone)]
-struct Reaction{
+struct Reaction {
     inputs: Vec<(usize, String)>,
-    output: (usize, String)
+    output: (usize, String),
 }
 
 #[derive(Debug, Clone)]
-struct Refinery{
-    reactions: HashMap<String, Reaction>
+struct Refinery {
+    reactions: HashMap<String, Reaction>,
 }
 
-impl Refinery{
+impl Refinery {
     fn from_string(contents: &str) -> Refinery {
         let mut reactions: HashMap<String, Reaction> = HashMap::new();
 
         contents.lines().for_each(|line| {
             let mut split = line.split(" => ");
-            let inputs: Vec<(usize, String)> = split.next().unwrap().split(", ").map(|elem| {
-                let split: Vec<&str> = elem.split(" ").collect();
-                let count = split[0].parse::<usize>().unwrap();
-                let element = String::from(split[1]);
-                (count, element)
-            }).collect();
+            let inputs: Vec<(usize, String)> = split
+                .next()
+                .unwrap()
+                .split(", ")
+                .map(|elem| {
+                    let split: Vec<&str> = elem.split(" ").collect();
+                    let count = split[0].parse::<usize>().unwrap();
+                    let element = String::from(split[1]);
+                    (count, element)
+                })
+                .collect();
             let output_parts: Vec<&str> = split.next().unwrap().split(" ").collect();
             let output_count = output_parts[0].parse::<usize>().unwrap();
             let output_element = String::from(output_parts[1]);
 
             if reactions.contains_key(&output_element) {
-                panic!("reaction for {} already exists: {:?}", output_element, reactions.get(&output_element));
+                panic!(
+                    "reaction for {} already exists: {:?}",
+                    output_element,
+                    reactions.get(&output_element)
+                );
             }
 
-            let reaction = Reaction{
-                inputs: inputs,
-                output: (output_count, output_element.clone())
+            let reaction = Reaction {
+                inputs,
+                output: (output_count, output_element.clone()),
             };
             reactions.insert(output_element, reaction);
         });
 
-        Refinery{
-            reactions: reactions
-        }
+        Refinery { reactions }
     }
 
     fn reactions_for(&self, output: &String) -> &Reaction {
@@ -59,37 +64,33 @@ impl Refinery{
         let mut byproducts: HashMap<String, usize> = HashMap::new();
         production_queue.push_back((count, String::from("FUEL")));
         let mut ore_required = 0;
-    
+
         while !production_queue.is_empty() {
             let (queued, element) = production_queue.pop_front().unwrap();
-            
+
             let to_produce = match byproducts.get_mut(&element) {
                 Some(existing_byproduct) => {
                     let to_use = min(*existing_byproduct, queued);
                     *existing_byproduct -= to_use;
                     debug!("Used {} existing byproduct of {}", to_use, element);
                     queued - to_use
-                },
-                None => queued
+                }
+                None => queued,
             };
-    
+
             if to_produce == 0 {
                 continue;
             }
-    
+
             let reaction = self.reactions_for(&element);
-            let reaction_count = (to_produce + reaction.output.0 - 1) / reaction.output.0;
+            let reaction_count = to_produce.div_ceil(reaction.output.0);
             let byproduct = reaction_count * reaction.output.0 - to_produce;
             debug!(
-                "Producing {}x{} with reaction {:?} {} times with {} byproduct", 
-                element, 
-                to_produce,  
-                reaction, 
-                reaction_count, 
-                byproduct
+                "Producing {}x{} with reaction {:?} {} times with {} byproduct",
+                element, to_produce, reaction, reaction_count, byproduct
             );
             assert!(reaction_count > 0, "Reaction count was zero");
-    
+
             reaction.inputs.iter().for_each(|(count, input_element)| {
                 if input_element != "ORE" {
                     production_queue.push_back((count * reaction_count, input_element.clone()));
@@ -107,23 +108,16 @@ impl Refinery{
     }
 }
 
-pub fn solve(input_file: &str){
-    let contents = fs::read_to_string(input_file)
-        .expect("Something went wrong reading the file");
-
-    let input = Refinery::from_string(&contents);
-    debug!("{:?}", input);
-
-    part1(&input);
-    part2(&input);
-}
-
-fn part1(refinery: &Refinery) {
+pub fn part_one(input: &str) -> Option<usize> {
+    let refinery = Refinery::from_string(input);
+    debug!("{:?}", refinery);
     let ore_required = refinery.produce_fuel(1);
     println!("It took {} ORE to produce FUEL", ore_required);
+    Some(ore_required)
 }
 
-fn part2(refinery: &Refinery) {
+pub fn part_two(input: &str) -> Option<usize> {
+    let refinery = Refinery::from_string(input);
     let max_ore = 1000000000000usize;
     let max_ore_required = refinery.produce_fuel(1);
     //upper bound for ore required
@@ -134,21 +128,24 @@ fn part2(refinery: &Refinery) {
         let batches = max_ore / ore_required;
         let single_batches = (max_ore - ore_required * batches) / max_ore_required;
         if batches <= 1 && single_batches == 0 {
-            break
+            break;
         }
         to_produce = batches * to_produce + single_batches;
     }
-    println!("Its possible to produce {} FUEL with {} ORE", to_produce, max_ore);
+    println!(
+        "Its possible to produce {} FUEL with {} ORE",
+        to_produce, max_ore
+    );
+    Some(to_produce)
 }
 
 #[cfg(test)]
-mod tests{
+mod tests {
     use super::*;
-    use env_logger;
 
     #[test]
     fn simple() {
-        env_logger::init();
+        let _ = env_logger::try_init();
         let input = r#"
 9 ORE => 2 A
 8 ORE => 3 B
@@ -156,14 +153,14 @@ mod tests{
 3 A, 4 B => 1 AB
 5 B, 7 C => 1 BC
 4 C, 1 A => 1 CA
-2 AB, 3 BC, 4 CA => 1 FUEL"#.trim();
-        let refinery = Refinery::from_string(input);
-        part1(&refinery);
+2 AB, 3 BC, 4 CA => 1 FUEL"#
+            .trim();
+        assert_eq!(part_one(input), Some(165));
     }
 
     #[test]
     fn more() {
-        env_logger::init();
+        let _ = env_logger::try_init();
         let input = r#"
 157 ORE => 5 NZVS
 165 ORE => 6 DCFZ
@@ -173,9 +170,9 @@ mod tests{
 177 ORE => 5 HKGWZ
 7 DCFZ, 7 PSHF => 2 XJWVT
 165 ORE => 2 GPVTF
-3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT"#.trim();
-        let refinery = Refinery::from_string(input);
-        part1(&refinery);
-        part2(&refinery);
+3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT"#
+            .trim();
+        assert_eq!(part_one(input), Some(13312));
+        assert_eq!(part_two(input), Some(82892753));
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,172 @@
+advent_of_code::solution!(15);
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use advent_of_code::common::canvas::Canvas;
+use advent_of_code::common::intcode::*;
+use advent_of_code::direction::Direction;
+
+/// The movement command the droid understands for a direction.
+fn movement_command(direction: Direction) -> i128 {
+    match direction {
+        Direction::North => 1,
+        Direction::South => 2,
+        Direction::West => 3,
+        Direction::East => 4,
+        _ => panic!("The droid can't move {}", direction),
+    }
+}
+
+struct Robot {
+    computer: Computer,
+    x: isize,
+    y: isize,
+}
+
+impl Robot {
+    fn new(program: &Program) -> Robot {
+        Robot {
+            computer: Computer::new(program),
+            x: 0,
+            y: 0,
+        }
+    }
+
+    /// A copy of the robot that moves on independently from here.
+    fn fork(&self) -> Robot {
+        Robot {
+            computer: self.computer.fork(),
+            x: self.x,
+            y: self.y,
+        }
+    }
+
+    fn target(&self, move_command: Direction) -> (isize, isize) {
+        let (x, y): (isize, isize) = move_command.offset();
+        (self.x + x, self.y + y)
+    }
+
+    fn command(&mut self, move_command: Direction) -> Result<i128, IntcodeError> {
+        self.computer.write_input(movement_command(move_command));
+        self.computer.execute()?;
+        let result = self.computer.read_output().unwrap();
+        if result != 0 {
+            let (new_x, new_y) = self.target(move_command);
+            self.x = new_x;
+            self.y = new_y;
+        }
+        Ok(result)
+    }
+}
+
+struct Area {
+    canvas: Canvas,
+    oxygen_location: Option<(isize, isize)>,
+}
+
+/// Maps the whole area by forking the robot into every unexplored neighbor of every open cell.
+fn explore(program: &Program) -> Result<Area, IntcodeError> {
+    let mut area = Area {
+        canvas: Canvas::new(),
+        oxygen_location: None,
+    };
+    area.canvas.paint(0, 0, '.');
+
+    let mut robots = VecDeque::from(vec![Robot::new(program)]);
+    while let Some(robot) = robots.pop_front() {
+        for direction in Direction::CARDINALS.iter() {
+            let (x, y) = robot.target(*direction);
+            if area.canvas.color_at(x, y) != ' ' {
+                continue;
+            }
+            let mut fork = robot.fork();
+            match fork.command(*direction)? {
+                0 => area.canvas.paint(x, y, '#'),
+                1 => {
+                    area.canvas.paint(x, y, '.');
+                    robots.push_back(fork);
+                }
+                2 => {
+                    area.canvas.paint(x, y, 'o');
+                    area.oxygen_location = Some((x, y));
+                    robots.push_back(fork);
+                }
+                result => panic!("Unknown result {}", result),
+            }
+        }
+    }
+    println!("{}", area.canvas);
+    println!("{:?}", area.oxygen_location);
+    Ok(area)
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let area = explore(&Program::parse(input).ok()?).ok()?;
+    //we assume the oxygen gets revealed
+    let oxygen_location = area.oxygen_location.unwrap();
+
+    {
+        let mut visited: HashSet<(isize, isize)> = HashSet::new();
+        let mut queue: VecDeque<(isize, isize, usize)> = VecDeque::new();
+
+        queue.push_back((0, 0, 0));
+        let path_length = loop {
+            let (position_x, position_y, length) = queue.pop_front().unwrap();
+            if oxygen_location.0 == position_x && oxygen_location.1 == position_y {
+                break length;
+            }
+            visited.insert((position_x, position_y));
+
+            [
+                (position_x - 1, position_y),
+                (position_x + 1, position_y),
+                (position_x, position_y - 1),
+                (position_x, position_y + 1),
+            ]
+            .iter()
+            .filter(|(x, y)| area.canvas.color_at(*x, *y) != '#')
+            .filter(|target| !visited.contains(target))
+            .for_each(|(x, y)| {
+                queue.push_back((*x, *y, length + 1));
+            });
+        };
+
+        println!("Path length {}", path_length);
+        Some(path_length)
+    }
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let area = explore(&Program::parse(input).ok()?).ok()?;
+    let oxygen_location = area.oxygen_location.unwrap();
+
+    {
+        let mut visited: HashSet<(isize, isize)> = HashSet::new();
+        let mut queue: VecDeque<(isize, isize, usize)> = VecDeque::new();
+        let mut minutes = 0;
+
+        queue.push_back((oxygen_location.0, oxygen_location.1, 0));
+        while !queue.is_empty() {
+            let (position_x, position_y, length) = queue.pop_front().unwrap();
+            visited.insert((position_x, position_y));
+            minutes = length;
+
+            [
+                (position_x - 1, position_y),
+                (position_x + 1, position_y),
+                (position_x, position_y - 1),
+                (position_x, position_y + 1),
+            ]
+            .iter()
+            .filter(|(x, y)| area.canvas.color_at(*x, *y) == '.')
+            .filter(|target| !visited.contains(target))
+            .for_each(|(x, y)| {
+                queue.push_back((*x, *y, length + 1));
+            });
+        }
+
+        println!("Time to fill up with oxygen {} minutes", minutes);
+        Some(minutes)
+    }
+}
@@ -1,87 +1,90 @@
+advent_of_code::solution!(16);
 
-use log::{debug, info};
-use std::fs;
+use log::debug;
 use std::char;
 use std::fmt;
 use std::fmt::Display;
-use std::time::Instant;
 
 #[derive(Clone, Debug)]
-struct Signal{
-    parts: Vec<isize>
+struct Signal {
+    parts: Vec<isize>,
 }
 
-impl Signal{
-    fn from_string(input: &str) -> Signal{
-        let parts: Vec<isize> = input.chars().map(|x| x.to_digit(10).unwrap() as isize).collect();
+impl Signal {
+    fn from_string(input: &str) -> Signal {
+        let parts: Vec<isize> = input
+            .chars()
+            .map(|x| x.to_digit(10).unwrap() as isize)
+            .collect();
 
-        Signal{
-            parts: parts
-        }
+        Signal { parts }
     }
 
-    fn fft(&mut self, base_pattern: &Vec<isize>) {
-        let new_parts: Vec<isize> = (0..self.parts.len()).map(|idx| {
-            let result: isize = self.parts.iter().enumerate().map(|(jdx, x)| {
-                let index = ((jdx + 1) / (idx + 1)) % base_pattern.len();
-                (x * base_pattern[index]) % 10
-            }).sum();
-            result.abs() % 10
-        }).collect();
+    fn fft(&mut self, base_pattern: &[isize]) {
+        let new_parts: Vec<isize> = (0..self.parts.len())
+            .map(|idx| {
+                let result: isize = self
+                    .parts
+                    .iter()
+                    .enumerate()
+                    .map(|(jdx, x)| {
+                        let index = ((jdx + 1) / (idx + 1)) % base_pattern.len();
+                        (x * base_pattern[index]) % 10
+                    })
+                    .sum();
+                result.abs() % 10
+            })
+            .collect();
 
         self.parts.clear();
         self.parts.extend(new_parts.iter());
     }
 }
 
-impl Display for Signal{
+impl Display for Signal {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let s: String = self.parts.iter().map(|x| char::from_digit(*x as u32, 10).unwrap()).collect();
+        let s: String = self
+            .parts
+            .iter()
+            .map(|x| char::from_digit(*x as u32, 10).unwrap())
+            .collect();
         write!(f, "{}", s)
     }
 }
 
-pub fn solve(input_file: &str){
-    let contents = fs::read_to_string(input_file)
-        .expect("Something went wrong reading the file");
-
-    let input = Signal::from_string(&contents);
+pub fn part_one(input: &str) -> Option<String> {
+    let input = Signal::from_string(input.trim());
     debug!("{:?}", input);
-
-    let part1_time = Instant::now();
-    part1(&input);
-    println!("Part 1 took {} millis", part1_time.elapsed().as_millis());
-    let part2_time = Instant::now();
-    part2(&input);
-    println!("Part 2 took {} millis", part2_time.elapsed().as_millis());
-}
-
-fn part1(input: &Signal) {
     let base_pattern = vec![0, 1, 0, -1];
     let mut signal = input.clone();
     for _ in 0..100 {
         signal.fft(&base_pattern);
     }
     println!("{}", signal);
+    Some(signal.to_string()[..8].to_string())
 }
 
-fn part2(input: &Signal) {
+pub fn part_two(input: &str) -> Option<String> {
+    let input = Signal::from_string(input.trim());
     let len = input.parts.len();
-    let offset: usize = input.parts
-        .iter().take(7).fold(0usize, |acc, x|{
-            acc * 10 + *x as usize
-        });
+    let offset: usize = input
+        .parts
+        .iter()
+        .take(7)
+        .fold(0usize, |acc, x| acc * 10 + *x as usize);
     println!("Offset {}", offset);
     println!("Input sequence length {}", len);
     println!("Large sequence length {}", len * 10000);
 
-    let mut parts: Vec<isize> = input.parts
-        .iter().cloned()
+    let mut parts: Vec<isize> = input
+        .parts
+        .iter()
+        .cloned()
         .cycle()
         .take(len * 10000)
         .skip(offset)
         .collect();
-    
+
     for _ in 0..100 {
         let mut total: isize = parts.iter().sum();
 
@@ -92,18 +95,19 @@ fn part2(input: &Signal) {
         });
     }
 
-    let answer: String = parts.iter()
+    let answer: String = parts
+        .iter()
         .take(8)
         .map(|x| char::from_digit(*x as u32, 10).unwrap())
         .collect();
-    
+
     println!("{}", answer);
+    Some(answer)
 }
 
 #[cfg(test)]
-mod tests{
+mod tests {
     use super::*;
-    use env_logger;
 
     #[test]
     fn test() {
@@ -129,7 +133,9 @@ mod tests{
 
     #[test]
     fn test3() {
-        let input = Signal::from_string("03036732577212944063491565474664");
-        part2(&input);
+        assert_eq!(
+            part_two("03036732577212944063491565474664"),
+            Some(String::from("84462026"))
+        );
     }
-}
\ No newline at end of file
+}
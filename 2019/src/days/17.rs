@@ -0,0 +1,197 @@
+advent_of_code::solution!(17);
+
+use log::debug;
+
+use advent_of_code::common::canvas::Canvas;
+use advent_of_code::common::intcode::*;
+use advent_of_code::common::point::Point;
+use advent_of_code::direction::Direction;
+
+struct Robot {
+    computer: Computer,
+    console: AsciiConsole,
+    canvas: Canvas,
+    position_x: isize,
+    position_y: isize,
+}
+
+impl Robot {
+    fn new(program: &Program) -> Robot {
+        Robot {
+            computer: Computer::new(program),
+            console: AsciiConsole::new(),
+            canvas: Canvas::new(),
+            position_x: 0,
+            position_y: 0,
+        }
+    }
+
+    fn scan(&mut self) -> Result<(), IntcodeError> {
+        self.computer.run_device(&mut self.console)?;
+
+        let mut x = 0;
+        let mut y = 0;
+        self.console.take_text().chars().for_each(|point| {
+            if point == '\n' {
+                x = 0;
+                y += 1;
+            } else {
+                self.canvas.paint(x, y, point);
+                if point == '^' || point == '<' || point == '>' || point == 'v' {
+                    self.position_x = x;
+                    self.position_y = y;
+                }
+                x += 1;
+            }
+        });
+        Ok(())
+    }
+}
+
+pub fn part_one(input: &str) -> Option<isize> {
+    let program = &Program::parse(input).ok()?;
+    let mut robot = Robot::new(program);
+    robot.scan().ok()?;
+
+    let alignment: isize = compute_alignment(&robot.canvas);
+
+    println!("{}", robot.canvas);
+    println!("Alignment: {}", alignment);
+    Some(alignment)
+}
+
+pub fn part_two(input: &str) -> Option<i128> {
+    let program = &Program::parse(input).ok()?;
+    let mut robot = Robot::new(program);
+    robot.computer.memory[0] = 2;
+    robot.scan().ok()?;
+
+    let path = generate_path(&robot.canvas, robot.position_x, robot.position_y);
+    let path_command: String = path
+        .moves
+        .iter()
+        .map(|x| x.command().to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+    println!("{}", path_command);
+
+    let robot_input = r#"A,B,A,C,A,A,C,B,C,B
+L,12,L,8,R,12
+L,10,L,8,L,12,R,12
+R,12,L,8,L,10
+n"#;
+
+    robot_input
+        .lines()
+        .for_each(|line| robot.console.write_line(line));
+    robot.computer.run_device(&mut robot.console).ok()?;
+    println!("{}", robot.console.take_text());
+    let dust = robot.console.values().last().copied();
+    println!("{:?}", dust);
+    dust
+}
+
+fn compute_alignment(canvas: &Canvas) -> isize {
+    canvas
+        .iter()
+        .flat_map(|(Point { x, y }, color)| {
+            let adjacent = [
+                canvas.color_at(x - 1, y) == '#',
+                canvas.color_at(x + 1, y) == '#',
+                canvas.color_at(x, y - 1) == '#',
+                canvas.color_at(x, y + 1) == '#',
+            ]
+            .iter()
+            .filter(|c| **c)
+            .count();
+            if color == '#' && adjacent >= 3 {
+                debug!("Intersection at {} {}", x, y);
+                Some(x * y)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Move {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+struct Path {
+    moves: Vec<Move>,
+}
+
+impl Move {
+    fn command(&self) -> String {
+        use Move::*;
+        match self {
+            Forward(count) => count.to_string(),
+            Left => String::from("L"),
+            Right => String::from("R"),
+        }
+    }
+}
+
+fn generate_path(canvas: &Canvas, robot_x: isize, robot_y: isize) -> Path {
+    let mut last_turn = Point {
+        x: robot_x,
+        y: robot_y,
+    };
+    let mut position = Point {
+        x: robot_x,
+        y: robot_y,
+    };
+    //would have to change this for a general input
+    let mut direction = Direction::West;
+    let mut path: Vec<Move> = Vec::new();
+    path.push(Move::Left);
+    loop {
+        if canvas.color_at_point(position + direction.into()) == '#' {
+            position += direction.into();
+        } else {
+            path.push(Move::Forward((position - last_turn).abs().unsigned_abs()));
+            last_turn = position;
+            let left_turn =
+                canvas.color_at_point(position + direction.rotate_counterclockwise().into());
+            let right_turn = canvas.color_at_point(position + direction.rotate_clockwise().into());
+            if left_turn == '#' {
+                path.push(Move::Left);
+                direction = direction.rotate_counterclockwise();
+            } else if right_turn == '#' {
+                path.push(Move::Right);
+                direction = direction.rotate_clockwise();
+            } else {
+                debug!("Dead end at {:?}", position);
+                break Path { moves: path };
+            }
+            debug!("Going {:?} from {:?}", direction, position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn name() {
+        let input = r#"
+..#..........
+..#..........
+#######...###
+#.#...#...#.#
+#############
+..#...#...#..
+..#####...^.."#
+            .trim();
+        let canvas = Canvas::from_str(input).unwrap();
+        println!("{}", canvas);
+        println!("{}", compute_alignment(&canvas));
+    }
+}
@@ -0,0 +1,188 @@
+advent_of_code::solution!(18);
+
+use advent_of_code::common::canvas::Canvas;
+use advent_of_code::common::graph::*;
+use advent_of_code::common::point::Point;
+use advent_of_code::maze::PoiGraph;
+use log::debug;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let input = Canvas::from_str(input).unwrap();
+    println!("{}", input);
+    let (distance, order) = collect_all_keys(&input);
+    println!("Best path is {} with order {}", distance, order);
+    Some(distance)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let mut canvas = Canvas::from_str(input).unwrap();
+    let robot_position = canvas.find('@').unwrap();
+    canvas.paint_at(robot_position, '#');
+    canvas.paint_at(robot_position + Point::new(-1, 0), '#');
+    canvas.paint_at(robot_position + Point::new(1, 0), '#');
+    canvas.paint_at(robot_position + Point::new(0, -1), '#');
+    canvas.paint_at(robot_position + Point::new(0, 1), '#');
+    canvas.paint_at(robot_position + Point::new(1, 1), '@');
+    canvas.paint_at(robot_position + Point::new(-1, 1), '@');
+    canvas.paint_at(robot_position + Point::new(1, -1), '@');
+    canvas.paint_at(robot_position + Point::new(-1, -1), '@');
+    debug!("{}", canvas);
+    let (distance, order) = collect_all_keys(&canvas);
+    println!("Best path is {} with order {}", distance, order);
+    Some(distance)
+}
+
+/// What the robots know about the vault: every key as a bit, and for every key or robot the keys
+/// reachable from it with the distance and the keys of the doors on the way.
+struct Vault {
+    key_bits: HashMap<Point, u32>,
+    paths: HashMap<Point, Vec<(Point, usize, u32)>>,
+}
+
+/// The robot positions, in a fixed order, and the keys collected as a bitmask.
+type State = (Vec<Point>, u32);
+
+impl WeightedNeighbors<State> for Vault {
+    fn weighted_neighbors(&self, (positions, keys): State) -> Vec<(State, usize)> {
+        positions
+            .iter()
+            .enumerate()
+            .flat_map(|(robot, from)| {
+                self.paths
+                    .get(from)
+                    .into_iter()
+                    .flatten()
+                    .filter(|(to, _, required)| {
+                        keys & self.key_bits[to] == 0 && keys & required == *required
+                    })
+                    .map(|(to, distance, _)| {
+                        let mut next = positions.clone();
+                        next[robot] = *to;
+                        ((next, keys | self.key_bits[to]), *distance)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+fn key_bit(key: char) -> u32 {
+    1 << (key as u32 - 'a' as u32)
+}
+
+fn collect_all_keys(canvas: &Canvas) -> (usize, String) {
+    let keys: Vec<(Point, char)> = canvas
+        .iter()
+        .filter(|(_, value)| value.is_alphabetic() && value.is_lowercase())
+        .collect();
+
+    let robots: Vec<Point> = canvas
+        .iter()
+        .filter(|(_, value)| *value == '@')
+        .map(|(p, _)| p)
+        .collect();
+
+    let graph = corridors(canvas);
+    let mut paths: HashMap<Point, Vec<(Point, usize, u32)>> = HashMap::new();
+    for from_position in keys.iter().map(|(p, _)| p).chain(robots.iter()) {
+        let shortest = dijkstra(vec![*from_position], &graph);
+        for (to_position, to) in keys.iter() {
+            if to_position == from_position {
+                continue;
+            }
+            if let (Some(distance), Some(route)) =
+                (shortest.distance(to_position), shortest.path(to_position))
+            {
+                //the doors on the way need their keys
+                let keys_required = route
+                    .iter()
+                    .map(|p| canvas.color_at_point(*p))
+                    .filter(|c| c.is_uppercase())
+                    .fold(0, |required, c| required | key_bit(c.to_ascii_lowercase()));
+                debug!(
+                    "{:?} -> {}: {} {:b}",
+                    from_position, to, distance, keys_required
+                );
+                paths.entry(*from_position).or_default().push((
+                    *to_position,
+                    distance,
+                    keys_required,
+                ));
+            }
+        }
+    }
+
+    let vault = Vault {
+        key_bits: keys.iter().map(|(p, c)| (*p, key_bit(*c))).collect(),
+        paths,
+    };
+    let all_keys = keys.iter().fold(0, |all, (_, c)| all | key_bit(*c));
+
+    // every state is only searched once, however many orders of collecting its keys lead to it
+    let shortest = dijkstra(vec![(robots, 0)], &vault);
+    let (done, distance) = shortest
+        .distances()
+        .iter()
+        .filter(|((_, collected), _)| *collected == all_keys)
+        .min_by_key(|(_, distance)| **distance)
+        .map(|(state, distance)| (state.clone(), *distance))
+        .unwrap_or_default();
+
+    let key_names: HashMap<u32, char> = keys.iter().map(|(_, c)| (key_bit(*c), *c)).collect();
+    let order: String = shortest
+        .path(&done)
+        .unwrap_or_default()
+        .windows(2)
+        .map(|step| key_names[&(step[1].1 & !step[0].1)])
+        .collect();
+    (distance, order)
+}
+
+/// The maze contracted to the keys, the doors and the robots.
+fn corridors(canvas: &Canvas) -> PoiGraph<Point> {
+    let points = canvas
+        .iter()
+        .filter(|(_, value)| value.is_alphabetic() || *value == '@')
+        .map(|(p, _)| p);
+
+    PoiGraph::new(points, |point| {
+        [
+            *point + Point::new(-1, 0),
+            *point + Point::new(1, 0),
+            *point + Point::new(0, -1),
+            *point + Point::new(0, 1),
+        ]
+        .iter()
+        .filter(|p| {
+            let color = canvas.color_at_point(**p);
+            color != '#' && color != ' '
+        })
+        .copied()
+        .collect::<Vec<Point>>()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use env_logger::*;
+
+    #[test]
+    fn name() {
+        let env = Env::new().filter_or("RUST_LOG", "debug");
+        let _ = try_init_from_env(env);
+        let contents = r#"
+#############
+#DcBa.#.GhKl#
+#.###...#I###
+#e#d#.@.#j#k#
+###C#...###J#
+#fEbA.#.FgHi#
+#############"#
+            .trim();
+
+        assert_eq!(part_two(contents), Some(32));
+    }
+}
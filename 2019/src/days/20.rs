@@ -0,0 +1,352 @@
+advent_of_code::solution!(20);
+
+use advent_of_code::common::graph::*;
+use advent_of_code::common::grid::*;
+use advent_of_code::common::point::*;
+use advent_of_code::maze::PoiGraph;
+use std::cmp::min;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+struct PlutoMap {
+    grid: Grid,
+    portal_connections: HashMap<Point, Point>,
+    start: Point,
+    finish: Point,
+}
+
+impl PlutoMap {
+    fn new(grid: &Grid) -> PlutoMap {
+        let mut portals: HashMap<String, (Point, Point)> = HashMap::new();
+        let mut portal_connections: HashMap<Point, Point> = HashMap::new();
+
+        fn update(
+            portal: String,
+            location: Point,
+            exit_location: Point,
+            portals: &mut HashMap<String, (Point, Point)>,
+            portal_connections: &mut HashMap<Point, Point>,
+        ) {
+            if let Some((paired_loc, paired_exit)) =
+                portals.insert(portal, (location, exit_location))
+            {
+                portal_connections.insert(location, paired_exit);
+                portal_connections.insert(paired_loc, exit_location);
+            }
+        }
+
+        for x in 0..grid.width {
+            for y in 0..grid.height {
+                if grid.get_unsafe(x, y).is_alphabetic() {
+                    if grid.get_unsafe(x, y + 1).is_alphabetic() {
+                        //check below
+                        let mut portal_vec = [grid.get(x, y).unwrap(), grid.get(x, y + 1).unwrap()];
+                        portal_vec.sort();
+                        let portal_str = portal_vec.iter().collect();
+
+                        let (portal_location, exit_location) = if grid.get_unsafe(x, y + 2) == '.' {
+                            (
+                                Point::new(x as isize, y as isize + 1),
+                                Point::new(x as isize, y as isize + 2),
+                            )
+                        } else {
+                            (
+                                Point::new(x as isize, y as isize),
+                                Point::new(x as isize, y as isize - 1),
+                            )
+                        };
+                        update(
+                            portal_str,
+                            portal_location,
+                            exit_location,
+                            &mut portals,
+                            &mut portal_connections,
+                        );
+                    } else if grid.get_unsafe(x + 1, y).is_alphabetic() {
+                        //check to the right
+                        let mut portal_vec = [grid.get(x, y).unwrap(), grid.get(x + 1, y).unwrap()];
+                        portal_vec.sort();
+                        let portal_str = portal_vec.iter().collect();
+
+                        let (portal_location, exit_location) = if grid.get_unsafe(x + 2, y) == '.' {
+                            (
+                                Point::new(x as isize + 1, y as isize),
+                                Point::new(x as isize + 2, y as isize),
+                            )
+                        } else {
+                            (
+                                Point::new(x as isize, y as isize),
+                                Point::new(x as isize - 1, y as isize),
+                            )
+                        };
+                        update(
+                            portal_str,
+                            portal_location,
+                            exit_location,
+                            &mut portals,
+                            &mut portal_connections,
+                        );
+                    }
+                }
+            }
+        }
+
+        let (_, start) = portals.get(&String::from("AA")).unwrap();
+        let (_, end) = portals.get(&String::from("ZZ")).unwrap();
+
+        PlutoMap {
+            grid: grid.clone(),
+            portal_connections,
+            start: *start,
+            finish: *end,
+        }
+    }
+}
+
+impl PlutoMap {
+    /// The open cells next to `point`, without stepping into portals.
+    fn walkable(&self, point: &Point) -> Vec<Point> {
+        [
+            *point + Point::new(-1, 0),
+            *point + Point::new(1, 0),
+            *point + Point::new(0, -1),
+            *point + Point::new(0, 1),
+        ]
+        .iter()
+        .filter(|p| self.grid.get_unsafe(p.x as usize, p.y as usize) == '.')
+        .copied()
+        .collect()
+    }
+
+    /// The maze contracted to the entrance, the exit and the cells next to the portals.
+    fn corridors(&self) -> PoiGraph<Point> {
+        let points = self
+            .portal_connections
+            .values()
+            .copied()
+            .chain(vec![self.start, self.finish]);
+        PoiGraph::new(points, |point| self.walkable(point))
+    }
+
+    /// Where stepping into the portals next to the position leads. In the recursive maze the inner portals
+    /// go a level deeper and the outer ones a level up, the outer portals of the outermost level are walls.
+    fn teleports(&self, state: State, recursive: bool) -> Vec<State> {
+        [
+            state.position + Point::new(-1, 0),
+            state.position + Point::new(1, 0),
+            state.position + Point::new(0, -1),
+            state.position + Point::new(0, 1),
+        ]
+        .iter()
+        .filter_map(|target| {
+            let connection = self.portal_connections.get(target)?;
+            if !recursive {
+                return Some(State::new(*connection, state.recursion));
+            }
+            let dist = min(
+                min(target.x, self.grid.width as isize - target.x),
+                min(target.y, self.grid.height as isize - target.y),
+            );
+            let recursion = if dist < 3 {
+                state.recursion - 1
+            } else {
+                state.recursion + 1
+            };
+            if (0..=100).contains(&recursion) {
+                Some(State::new(*connection, recursion))
+            } else {
+                None
+            }
+        })
+        .collect()
+    }
+}
+
+#[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
+struct State {
+    position: Point,
+    recursion: isize,
+}
+
+impl State {
+    fn new(position: Point, recursion: isize) -> State {
+        State {
+            position,
+            recursion,
+        }
+    }
+}
+
+/// Walks the corridors between portals on the same level, and takes a portal in a single step.
+struct Donut<'a> {
+    map: &'a PlutoMap,
+    corridors: PoiGraph<Point>,
+    recursive: bool,
+}
+
+impl<'a> Donut<'a> {
+    fn new(map: &'a PlutoMap, recursive: bool) -> Donut<'a> {
+        Donut {
+            map,
+            corridors: map.corridors(),
+            recursive,
+        }
+    }
+
+    fn shortest(&self) -> Option<usize> {
+        let start = State::new(self.map.start, 0);
+        let finish = State::new(self.map.finish, 0);
+        dijkstra_route(start, finish, self).map(|(shortest, _)| shortest)
+    }
+}
+
+impl<'a> WeightedNeighbors<State> for Donut<'a> {
+    fn weighted_neighbors(&self, state: State) -> Vec<(State, usize)> {
+        self.corridors
+            .edges(&state.position)
+            .into_iter()
+            .map(|(position, distance)| (State::new(position, state.recursion), distance))
+            .chain(
+                self.map
+                    .teleports(state, self.recursive)
+                    .into_iter()
+                    .map(|next| (next, 1)),
+            )
+            .collect()
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let map = PlutoMap::new(&Grid::from_str(input).unwrap());
+    Donut::new(&map, false).shortest()
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let map = PlutoMap::new(&Grid::from_str(input).unwrap());
+    Donut::new(&map, true).shortest()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use env_logger::*;
+
+    #[test]
+    fn test_small_input() {
+        let env = Env::new().filter_or("RUST_LOG", "debug");
+        let _ = try_init_from_env(env);
+        let contents = r#"         A           
+         A           
+  #######.#########  
+  #######.........#  
+  #######.#######.#  
+  #######.#######.#  
+  #######.#######.#  
+  #####  B    ###.#  
+BC...##  C    ###.#  
+  ##.##       ###.#  
+  ##...DE  F  ###.#  
+  #####    G  ###.#  
+  #########.#####.#  
+DE..#######...###.#  
+  #.#########.###.#  
+FG..#########.....#  
+  ###########.#####  
+             Z       
+             Z       "#;
+
+        assert_eq!(part_one(contents), Some(23));
+        assert_eq!(part_two(contents), Some(26));
+    }
+
+    #[test]
+    fn test_larger_input() {
+        let env = Env::new().filter_or("RUST_LOG", "debug");
+        let _ = try_init_from_env(env);
+        let contents = r#"                   A               
+                   A               
+  #################.#############  
+  #.#...#...................#.#.#  
+  #.#.#.###.###.###.#########.#.#  
+  #.#.#.......#...#.....#.#.#...#  
+  #.#########.###.#####.#.#.###.#  
+  #.............#.#.....#.......#  
+  ###.###########.###.#####.#.#.#  
+  #.....#        A   C    #.#.#.#  
+  #######        S   P    #####.#  
+  #.#...#                 #......VT
+  #.#.#.#                 #.#####  
+  #...#.#               YN....#.#  
+  #.###.#                 #####.#  
+DI....#.#                 #.....#  
+  #####.#                 #.###.#  
+ZZ......#               QG....#..AS
+  ###.###                 #######  
+JO..#.#.#                 #.....#  
+  #.#.#.#                 ###.#.#  
+  #...#..DI             BU....#..LF
+  #####.#                 #.#####  
+YN......#               VT..#....QG
+  #.###.#                 #.###.#  
+  #.#...#                 #.....#  
+  ###.###    J L     J    #.#.###  
+  #.....#    O F     P    #.#...#  
+  #.###.#####.#.#####.#####.###.#  
+  #...#.#.#...#.....#.....#.#...#  
+  #.#####.###.###.#.#.#########.#  
+  #...#.#.....#...#.#.#.#.....#.#  
+  #.###.#####.###.###.#.#.#######  
+  #.#.........#...#.............#  
+  #########.###.###.#############  
+           B   J   C               
+           U   P   P               "#;
+
+        assert_eq!(part_one(contents), Some(58));
+    }
+
+    #[test]
+    fn test_recursive_input() {
+        let env = Env::new().filter_or("RUST_LOG", "debug");
+        let _ = try_init_from_env(env);
+        let contents = r#"             Z L X W       C                 
+             Z P Q B       K                 
+  ###########.#.#.#.#######.###############  
+  #...#.......#.#.......#.#.......#.#.#...#  
+  ###.#.#.#.#.#.#.#.###.#.#.#######.#.#.###  
+  #.#...#.#.#...#.#.#...#...#...#.#.......#  
+  #.###.#######.###.###.#.###.###.#.#######  
+  #...#.......#.#...#...#.............#...#  
+  #.#########.#######.#.#######.#######.###  
+  #...#.#    F       R I       Z    #.#.#.#  
+  #.###.#    D       E C       H    #.#.#.#  
+  #.#...#                           #...#.#  
+  #.###.#                           #.###.#  
+  #.#....OA                       WB..#.#..ZH
+  #.###.#                           #.#.#.#  
+CJ......#                           #.....#  
+  #######                           #######  
+  #.#....CK                         #......IC
+  #.###.#                           #.###.#  
+  #.....#                           #...#.#  
+  ###.###                           #.#.#.#  
+XF....#.#                         RF..#.#.#  
+  #####.#                           #######  
+  #......CJ                       NM..#...#  
+  ###.#.#                           #.###.#  
+RE....#.#                           #......RF
+  ###.###        X   X       L      #.#.#.#  
+  #.....#        F   Q       P      #.#.#.#  
+  ###.###########.###.#######.#########.###  
+  #.....#...#.....#.......#...#.....#.#...#  
+  #####.#.###.#######.#######.###.###.#.#.#  
+  #.......#.......#.#.#.#.#...#...#...#.#.#  
+  #####.###.#####.#.#.#.#.###.###.#.###.###  
+  #.......#.....#.#...#...............#...#  
+  #############.#.#.###.###################  
+               A O F   N                     
+               A A D   M                     "#;
+
+        assert_eq!(part_two(contents), Some(396));
+    }
+}
@@ -1,49 +1,36 @@
-use log::{debug, info};
-use std::char;
+advent_of_code::solution!(21);
 
-use crate::common::intcode::*;
+use log::debug;
 
-struct SpringDroid{
-    program: Program
+use advent_of_code::common::intcode::*;
+
+struct SpringDroid {
+    program: Program,
 }
 
-impl SpringDroid{
+impl SpringDroid {
     fn new(program: &Program) -> SpringDroid {
-
         SpringDroid {
-            program: program.clone()
+            program: program.clone(),
         }
     }
 
-    fn walk(&self, instructions: &str) -> i128 {
+    fn walk(&self, instructions: &str) -> Option<i128> {
         let mut computer = Computer::new(&self.program);
-        let code = instructions.chars().map(|c| {
-            c as i128
-        }).collect();
-        let output = computer.run(code);
-        let output_str: String = output.iter()
-            .map(|i| char::from_u32(*i as u32).unwrap_or('x'))
-            .collect();
-        
-        debug!("\n{}\n", output_str);
-        *output.last().unwrap()
-    }
-}
+        let mut console = AsciiConsole::new();
+        instructions
+            .lines()
+            .for_each(|line| console.write_line(line));
+        computer.run_device(&mut console).ok()?;
 
-pub fn solve(input_file: &str){
-    use std::time::Instant;
-
-    let input = SpringDroid::new(&Program::from_file(&input_file));
-
-    let part1_time = Instant::now();
-    part1(&input);
-    println!("Part 1 took {} millis", part1_time.elapsed().as_millis());
-    let part2_time = Instant::now();
-    part2(&input);
-    println!("Part 2 took {} millis", part2_time.elapsed().as_millis());
+        debug!("\n{}\n", console.take_text());
+        //the hull damage is the only value that is not ASCII, the droid fell into space without it
+        console.values().last().copied()
+    }
 }
 
-fn part1(droid: &SpringDroid) {
+pub fn part_one(input: &str) -> Option<i128> {
+    let droid = SpringDroid::new(&Program::parse(input).ok()?);
     // CNF can be directly written with 3 registers:
     // * J holds running value of conjunctions
     // * T holds current clause value
@@ -64,12 +51,19 @@ NOT T J
 AND D J
 # J = D & !(A & B & C)
 WALK
-    "#.trim().lines().filter(|x| !x.starts_with("#")).map(|x| format!("{}\n", x)).collect();
-    let solution = droid.walk(&format!("{}\n", program));
+    "#
+    .trim()
+    .lines()
+    .filter(|x| !x.starts_with("#"))
+    .map(|x| format!("{}\n", x))
+    .collect();
+    let solution = droid.walk(&format!("{}\n", program))?;
     println!("Solution is {}", solution);
+    Some(solution)
 }
 
-fn part2(droid: &SpringDroid) {
+pub fn part_two(input: &str) -> Option<i128> {
+    let droid = SpringDroid::new(&Program::parse(input).ok()?);
     // jump if there is a hole between 1-3, not on 4, and not on 5 or 8 - if there is a hole on 8 its risky to jump since we might be forced to jump again and into 8
     // (NOT a OR NOT b OR NOT c) AND d AND (e OR h)
     let program: String = r#"
@@ -87,6 +81,7 @@ AND T J
 RUN
     "#.trim().lines().filter(|x| !x.starts_with("#")).map(|x| format!("{}\n", x)).collect();
     debug!("{}", program);
-    let solution = droid.walk(&format!("{}\n", program));
+    let solution = droid.walk(&format!("{}\n", program))?;
     println!("Solution is {}", solution);
+    Some(solution)
 }
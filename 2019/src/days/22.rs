@@ -1,14 +1,14 @@
-use log::{debug, info};
+advent_of_code::solution!(22);
+
 use std::str::FromStr;
-use std::fs;
 
-enum Shuffle{
+enum Shuffle {
     Deal,
     Cut(i128),
-    DealIncrement(i128)
+    DealIncrement(i128),
 }
 
-impl FromStr for Shuffle{
+impl FromStr for Shuffle {
     type Err = &'static str;
 
     fn from_str(input: &str) -> Result<Shuffle, Self::Err> {
@@ -28,37 +28,31 @@ impl FromStr for Shuffle{
     }
 }
 
-pub fn solve(input_file: &str){
-    use std::time::Instant;
-
-    let contents = fs::read_to_string(input_file)
-        .expect("Something went wrong reading the file");
-
-    let input: Vec<Shuffle> = contents.lines().map(|x| Shuffle::from_str(x).unwrap()).collect();
-
-    let part1_time = Instant::now();
-    part1(&input);
-    println!("Part 1 took {} millis", part1_time.elapsed().as_millis());
-    let part2_time = Instant::now();
-    part2(&input);
-    println!("Part 2 took {} millis", part2_time.elapsed().as_millis());
+fn parse(input: &str) -> Vec<Shuffle> {
+    input
+        .lines()
+        .map(|x| Shuffle::from_str(x).unwrap())
+        .collect()
 }
 
-fn part1(commands: &Vec<Shuffle>) {
+pub fn part_one(input: &str) -> Option<i128> {
+    let commands = parse(input);
     let n = 10007;
     let result: i128 = commands.iter().fold(2019, |position, command| {
         let tick = match command {
             Shuffle::Deal => n - 1 - position,
             Shuffle::Cut(count) => (position - count) % n,
-            Shuffle::DealIncrement(count) => (position * count) % n
+            Shuffle::DealIncrement(count) => (position * count) % n,
         };
         //debug!("{:?}", tick);
         tick
     });
     println!("{}", result);
+    Some(result)
 }
 
-fn part2(commands: &Vec<Shuffle>) {
+pub fn part_two(input: &str) -> Option<i128> {
+    let commands = parse(input);
     let m = 119315717514047i128;
     let combination = Combine::new(&commands, m);
 
@@ -66,24 +60,28 @@ fn part2(commands: &Vec<Shuffle>) {
     let n = 101741582076661i128;
     let result = combination.apply_times(x, n, m);
     println!("{}", result);
+    Some(result)
 }
 
 struct Combine(i128, i128);
 
-impl Combine{
-    fn new(commands: &Vec<Shuffle>, modulo: i128) -> Combine {
-        commands.iter().rev().fold(Combine(1, 0), |Combine(a, b), command| {
-            let next = match command {
-                Shuffle::Cut(count) => (a, b + count),
-                Shuffle::DealIncrement(count) => {
-                    let inv = modular_pow(*count, modulo - 2, modulo);
-                    (a * inv, b * inv)
-                }
-                Shuffle::Deal => (-a, - b - 1),
-            };
-            //debug!("{:?} -> {}", tick, ((x * tick.0 + tick.1) % n + n) % n);
-            Combine(next.0 % modulo, next.1 % modulo)
-        })
+impl Combine {
+    fn new(commands: &[Shuffle], modulo: i128) -> Combine {
+        commands
+            .iter()
+            .rev()
+            .fold(Combine(1, 0), |Combine(a, b), command| {
+                let next = match command {
+                    Shuffle::Cut(count) => (a, b + count),
+                    Shuffle::DealIncrement(count) => {
+                        let inv = modular_pow(*count, modulo - 2, modulo);
+                        (a * inv, b * inv)
+                    }
+                    Shuffle::Deal => (-a, -b - 1),
+                };
+                //debug!("{:?} -> {}", tick, ((x * tick.0 + tick.1) % n + n) % n);
+                Combine(next.0 % modulo, next.1 % modulo)
+            })
     }
 
     fn apply_times(&self, x: i128, times: i128, modulo: i128) -> i128 {
@@ -114,7 +112,7 @@ fn modular_pow(base: i128, exponent: i128, modulus: i128) -> i128 {
     if modulus == 1 {
         return 0i128;
     }
-    assert_eq!((modulus - 1).checked_mul(modulus - 1).is_some(), true);
+    assert!((modulus - 1).checked_mul(modulus - 1).is_some());
     //assert that (modulus - 1) * (modulus - 1) does not overflow
     let mut result: i128 = 1;
     let mut base = base % modulus;
@@ -123,16 +121,15 @@ fn modular_pow(base: i128, exponent: i128, modulus: i128) -> i128 {
         if exponent % 2 == 1 {
             result = (result * base) % modulus;
         }
-        exponent = exponent >> 1;
+        exponent >>= 1;
         base = (base * base) % modulus;
     }
     result
 }
 
 #[cfg(test)]
-mod tests{
+mod tests {
     use super::*;
-    use env_logger::*;
 
     #[test]
     fn test_mod_exponent() {
@@ -152,8 +149,12 @@ cut 3
 deal with increment 9
 deal with increment 3
 cut -1
-        "#.trim();
-        let commands: Vec<Shuffle> = contents.lines().map(|x| Shuffle::from_str(x).unwrap()).collect();
-        let n = 10;
+        "#
+        .trim();
+        let _commands: Vec<Shuffle> = contents
+            .lines()
+            .map(|x| Shuffle::from_str(x).unwrap())
+            .collect();
+        let _n = 10;
     }
 }
@@ -0,0 +1,54 @@
+advent_of_code::solution!(23);
+
+use log::info;
+use std::collections::HashSet;
+
+use advent_of_code::common::intcode::*;
+
+pub fn part_one(input: &str) -> Option<i128> {
+    let nic = &Program::parse(input).ok()?;
+    let mut network = Network::new(nic, 50);
+
+    let result = loop {
+        match network.next_event().ok()? {
+            Event::Packet(packet) if packet.address == NAT => break packet,
+            Event::Packet(packet) => info!("Dropping packet {:?}", packet),
+            Event::Idle => return None,
+        }
+    };
+
+    println!("{:?}", result);
+    Some(result.y)
+}
+
+pub fn part_two(input: &str) -> Option<i128> {
+    let nic = &Program::parse(input).ok()?;
+    let mut network = Network::new(nic, 50);
+
+    let mut seen_values: HashSet<i128> = HashSet::new();
+    let mut nat = Nat::new();
+
+    let result = loop {
+        match network.next_event().ok()? {
+            Event::Packet(packet) if packet.address == NAT => nat.receive(packet),
+            Event::Packet(packet) => info!("Dropping packet {:?}", packet),
+            Event::Idle => {
+                let to_send = nat.wake()?;
+                info!("Network idle, pushing {:?}", to_send);
+                if !seen_values.insert(to_send.y) {
+                    break to_send.y;
+                }
+                network.send(to_send);
+            }
+        }
+    };
+
+    println!("{:?}", result);
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_mod_exponent() {}
+}
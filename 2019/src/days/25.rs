@@ -0,0 +1,307 @@
+advent_of_code::solution!(25);
+
+use log::debug;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use advent_of_code::common::graph::*;
+use advent_of_code::common::intcode::*;
+use advent_of_code::direction::Direction;
+
+#[derive(Clone, Debug)]
+struct RoomDescription {
+    name: String,
+    doors: Vec<Direction>,
+    items: Vec<String>,
+}
+
+#[derive(Debug)]
+enum ActionResult {
+    ItemPickup,
+    ItemDrop,
+    RoomDescription(RoomDescription),
+    //unused Inventory(Vec<String>),
+    AnalysisResult(String),
+}
+
+impl ActionResult {
+    fn from_str(input: &str) -> Result<Self, String> {
+        let split: Vec<&str> = input.split("\n\n").collect();
+
+        if input.contains("== Pressure-Sensitive Floor ==") {
+            //let result = input.lines().skip(9).next().unwrap().to_string();
+            Ok(ActionResult::AnalysisResult(input.to_string()))
+        } else if input.contains("You take") {
+            Ok(ActionResult::ItemPickup)
+        } else if input.contains("You drop") {
+            Ok(ActionResult::ItemDrop)
+        } else if split.len() == 4 || split.len() == 5 {
+            let doors: Vec<Direction> = split[2]
+                .lines()
+                .skip(1)
+                .map(|x| x.trim_start_matches("- ").parse().unwrap())
+                .collect();
+
+            let items: Vec<String> = if split.len() == 5 {
+                split[3]
+                    .lines()
+                    .skip(1)
+                    .map(|x| x.split("-").last().unwrap().trim().to_string())
+                    .collect()
+            } else {
+                vec![]
+            };
+            let room_name = input.lines().nth(3).unwrap().to_string();
+
+            Ok(ActionResult::RoomDescription(RoomDescription {
+                name: room_name,
+                doors,
+                items,
+            }))
+        } else {
+            Err(format!("Could not parse action result: {}", input))
+        }
+    }
+}
+
+struct Robit {
+    computer: Computer,
+    console: AsciiConsole,
+    items: HashSet<String>,
+}
+
+impl Robit {
+    fn new(program: &Program) -> Robit {
+        Robit {
+            computer: Computer::new(program),
+            console: AsciiConsole::new(),
+            items: HashSet::new(),
+        }
+    }
+
+    fn movement(&mut self, direction: Direction) -> Result<ActionResult, String> {
+        self.act(direction.name())
+    }
+
+    fn pickup(&mut self, what: &str) -> Result<ActionResult, String> {
+        let result = self.act(&format!("take {}", what));
+        self.items.insert(what.to_string());
+        result
+    }
+
+    /// A copy of the droid in the same room with the same items, that acts independently from here.
+    fn fork(&self) -> Robit {
+        Robit {
+            computer: self.computer.fork(),
+            console: self.console.clone(),
+            items: self.items.clone(),
+        }
+    }
+
+    fn drop(&mut self, what: &str) -> Result<ActionResult, String> {
+        let result = self.act(&format!("drop {}", what));
+        self.items.remove(what);
+        result
+    }
+
+    /// Sends a command and parses the reply, an empty command only reads what the droid printed so far.
+    fn act(&mut self, command: &str) -> Result<ActionResult, String> {
+        debug!("== Action == {}", command);
+        if !command.is_empty() {
+            self.console.write_line(command);
+        }
+        self.computer
+            .run_device(&mut self.console)
+            .map_err(|e| e.to_string())?;
+        let output_str = self.console.take_text();
+        debug!("{}", output_str);
+        ActionResult::from_str(&output_str)
+    }
+}
+
+#[derive(Debug)]
+struct Map {
+    connections: HashMap<String, HashMap<String, Direction>>,
+}
+
+impl Map {
+    fn new() -> Map {
+        Map {
+            connections: HashMap::new(),
+        }
+    }
+
+    fn move_to(&mut self, robit: &mut Robit, from: String, to: String) {
+        debug!("{} -> {}", from, to);
+        debug!("{:?}", self.connections);
+        let route = bfs_route(from.clone(), to, self).unwrap();
+
+        let mut position = from;
+        for step in route.iter().skip(1) {
+            let direction = self.connections.get(&position).unwrap().get(step).unwrap();
+            match robit.movement(*direction) {
+                Ok(ActionResult::RoomDescription(_)) => {}
+                result => panic!("{:?}", result),
+            }
+            position = step.clone();
+        }
+    }
+
+    fn connect(&mut self, from: String, to: String, direction: Direction) {
+        if !self.connections.contains_key(&from) {
+            self.connections.insert(from.clone(), HashMap::new());
+        }
+        self.connections
+            .get_mut(&from)
+            .unwrap()
+            .insert(to, direction);
+    }
+
+    fn explore(&mut self, robit: &mut Robit, item_blacklist: &HashSet<String>) {
+        let mut position = String::from("== Hull Breach ==");
+
+        let mut visited: HashSet<(String, Direction)> = HashSet::new();
+        let mut to_visit: VecDeque<(String, Direction)> = VecDeque::new();
+
+        match robit.act("") {
+            Ok(ActionResult::RoomDescription(description)) => {
+                to_visit.extend(
+                    description
+                        .doors
+                        .iter()
+                        .map(|door| (position.clone(), *door)),
+                );
+            }
+            result => panic!("{:?}", result),
+        };
+
+        while !to_visit.is_empty() {
+            let (next_room, next_direction) = to_visit.pop_front().unwrap();
+            debug!(
+                "Going to {:?} and taking door {:?}",
+                next_room, next_direction
+            );
+            debug!("State {:?}", self);
+            debug!("To visit {:?}", to_visit);
+            self.move_to(robit, position.clone(), next_room.clone());
+            let room_info = robit.movement(next_direction);
+
+            match room_info {
+                Ok(ActionResult::RoomDescription(room_info)) => {
+                    self.connect(next_room.clone(), room_info.name.clone(), next_direction);
+                    self.connect(
+                        room_info.name.clone(),
+                        next_room.clone(),
+                        next_direction.opposite(),
+                    );
+
+                    //do not add doors from checkpoint entrance
+                    if room_info.name != "== Security Checkpoint ==" {
+                        to_visit.extend(
+                            room_info
+                                .doors
+                                .iter()
+                                .map(|door| (room_info.name.clone(), *door))
+                                .filter(|node| !visited.contains(node)),
+                        );
+                    }
+
+                    for item in room_info.items {
+                        if !item_blacklist.contains(&item) {
+                            robit.pickup(&item).unwrap();
+                        }
+                    }
+                    position = room_info.name;
+                }
+                _ => panic!(
+                    "Could not move from {:?} in direction {:?}",
+                    next_room, next_direction
+                ),
+            }
+            visited.insert((next_room, next_direction));
+        }
+        self.move_to(robit, position, String::from("== Security Checkpoint =="));
+    }
+}
+
+impl Neighbors<String> for Map {
+    fn neighbors(&self, point: String) -> Vec<String> {
+        self.connections
+            .get(&point)
+            .map(|connected_rooms| connected_rooms.keys().map(|k| k.to_owned()).collect())
+            .unwrap_or(vec![])
+    }
+}
+
+/// Plays the game by hand from stdin, call it from `part_one` instead of the search to explore the ship.
+#[allow(dead_code)]
+fn play(bot: &Program) {
+    let mut robit = Robit::new(bot);
+    use std::io::{self, BufRead};
+
+    let stdin = io::stdin();
+    for line in stdin.lock().lines() {
+        let result = robit.act(&line.unwrap());
+        println!("{:?}", result);
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let bot = &Program::parse(input).ok()?;
+    //play(bot);
+    let mut map = Map::new();
+    let mut blacklist: HashSet<String> = HashSet::new();
+    blacklist.insert(String::from("escape pod"));
+    blacklist.insert(String::from("photons"));
+    blacklist.insert(String::from("molten lava"));
+    blacklist.insert(String::from("infinite loop"));
+    blacklist.insert(String::from("giant electromagnet"));
+    let mut robit = Robit::new(bot);
+
+    map.explore(&mut robit, &blacklist);
+
+    let all_items: Vec<String> = robit.items.iter().map(|x| x.to_owned()).collect();
+    println!("{:?}", all_items);
+
+    use itertools::Itertools;
+
+    //try every combination on a fork of the droid carrying everything
+    (1..=all_items.len())
+        .flat_map(|i| all_items.iter().combinations(i))
+        .find_map(|combination| {
+            let mut attempt = robit.fork();
+            all_items
+                .iter()
+                .filter(|item| !combination.contains(item))
+                .for_each(|item| {
+                    attempt.drop(item).unwrap();
+                });
+            match attempt.movement(Direction::East) {
+                Ok(ActionResult::AnalysisResult(analysis)) => {
+                    if analysis.contains("Droids on this ship are lighter")
+                        || analysis.contains("Droids on this ship are heavier")
+                    {
+                        None
+                    } else {
+                        println!("Win! {} {:?}", analysis, combination);
+                        //the airlock password is the only number in the message
+                        analysis
+                            .split_whitespace()
+                            .find_map(|word| word.parse::<u64>().ok())
+                    }
+                }
+                _ => panic!(),
+            }
+        })
+}
+
+pub fn part_two(_input: &str) -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_mod_exponent() {}
+}
@@ -0,0 +1,3 @@
+pub mod common;
+
+pub use aoc_core::*;
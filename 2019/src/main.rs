@@ -1,15 +1,9 @@
-#[macro_use] extern crate cached;
-
-use std::env;
-use env_logger;
-use std::time::Instant;
-
-mod common;
-mod day25;
-use day25::solve;
+/// Registry of all scaffolded solutions, generated by `build.rs`.
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
 
 fn main() {
     env_logger::init();
-    let args: Vec<String> = env::args().collect();
-    solve(&args[1]);
+    aoc_core::template::cli::main(solutions::SOLUTIONS);
 }
@@ -0,0 +1,37 @@
+advent_of_code::solution!(1);
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let elf_caloric_cache: u32 = input
+        .split("\n\n")
+        .map(|elf_info| elf_info.lines().map(|x| x.parse::<u32>().unwrap()).sum())
+        .max()
+        .unwrap();
+    Some(elf_caloric_cache)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut elf_caloric_caches: Vec<u32> = input
+        .split("\n\n")
+        .map(|elf_info| elf_info.lines().map(|x| x.parse::<u32>().unwrap()).sum())
+        .collect();
+    elf_caloric_caches.sort();
+    elf_caloric_caches.reverse();
+    Some(elf_caloric_caches.iter().take(3).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        assert_eq!(part_one(&input), Some(24000));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        assert_eq!(part_two(&input), Some(45000));
+    }
+}
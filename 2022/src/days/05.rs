@@ -1,3 +1,5 @@
+advent_of_code::solution!(5);
+
 use itertools::Itertools;
 use regex::Regex;
 
@@ -5,23 +7,34 @@ pub fn parse(input: &str, stacks: &mut Vec<Vec<char>>) -> Vec<(usize, usize, usi
     let (stacks_str, instructions_str): (&str, &str) = input.split("\n\n").collect_tuple().unwrap();
 
     let instruction_pattern = Regex::new("move ([0-9]+) from ([0-9]+) to ([0-9]+)").unwrap();
-    let instructions: Vec<(usize, usize, usize)> = instructions_str.lines().map(|x| {
-        let captures = instruction_pattern.captures(x).unwrap();
-        let count = captures.get(1)
-            .map(|x| x.as_str().parse::<usize>().unwrap()).unwrap();
-        let from = captures.get(2)
-            .map(|x| x.as_str().parse::<usize>().unwrap()).unwrap();
-        let to = captures.get(3)
-            .map(|x| x.as_str().parse::<usize>().unwrap()).unwrap();
-        (count, from-1, to-1)
-    }).collect();
+    let instructions: Vec<(usize, usize, usize)> = instructions_str
+        .lines()
+        .map(|x| {
+            let captures = instruction_pattern.captures(x).unwrap();
+            let count = captures
+                .get(1)
+                .map(|x| x.as_str().parse::<usize>().unwrap())
+                .unwrap();
+            let from = captures
+                .get(2)
+                .map(|x| x.as_str().parse::<usize>().unwrap())
+                .unwrap();
+            let to = captures
+                .get(3)
+                .map(|x| x.as_str().parse::<usize>().unwrap())
+                .unwrap();
+            (count, from - 1, to - 1)
+        })
+        .collect();
 
     let stack_count = (stacks_str.lines().last().unwrap().len() + 1) / 4;
     for _ in 0..=stack_count {
         stacks.push(Vec::new());
     }
 
-    stacks_str.lines().rev()
+    stacks_str
+        .lines()
+        .rev()
         .filter(|x| !x.chars().nth(1).unwrap().is_ascii_digit())
         .for_each(|line| {
             line.chars().enumerate().for_each(|(pos, c)| {
@@ -70,25 +83,19 @@ pub fn part_two(input: &str) -> Option<String> {
     Some(result)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_one(&input), Some(String::from("CMZ")));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_two(&input), Some(String::from("MCD")));
     }
 }
@@ -1,9 +1,9 @@
+advent_of_code::solution!(6);
+
 use itertools::Itertools;
 
 pub fn is_unique(data: &[char]) -> bool {
-    data
-        .iter()
-        .all_unique()
+    data.iter().all_unique()
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
@@ -30,25 +30,19 @@ pub fn part_two(input: &str) -> Option<usize> {
         })
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 6, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 6, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_one(&input), Some(7));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 6, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_two(&input), Some(19));
     }
 }
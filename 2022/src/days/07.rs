@@ -1,5 +1,7 @@
-use std::collections::HashMap;
+advent_of_code::solution!(7);
+
 use itertools::Itertools;
+use std::collections::HashMap;
 
 fn parse(input: &str, dir_sizes: &mut HashMap<String, u32>) {
     let mut cwd: Vec<&str> = Vec::new();
@@ -16,9 +18,7 @@ fn parse(input: &str, dir_sizes: &mut HashMap<String, u32>) {
                 target
                     .split('/')
                     .filter(|x| !x.is_empty())
-                    .for_each(|x| {
-                        cwd.push(x)
-                    });
+                    .for_each(|x| cwd.push(x));
             } else {
                 cwd.push(target);
             }
@@ -27,9 +27,7 @@ fn parse(input: &str, dir_sizes: &mut HashMap<String, u32>) {
             if line.starts_with("dir") {
                 //do nothing
             } else {
-                let size: u32 = line.split(' ')
-                    .next().unwrap()
-                    .parse().unwrap();
+                let size: u32 = line.split(' ').next().unwrap().parse().unwrap();
                 for idx in 0..=cwd.len() {
                     let path: String = cwd.iter().take(idx).join("/");
                     let dir_size = dir_sizes.get(&path).unwrap_or(&0) + size;
@@ -41,12 +39,12 @@ fn parse(input: &str, dir_sizes: &mut HashMap<String, u32>) {
     });
 }
 
-
 pub fn part_one(input: &str) -> Option<u32> {
     let mut dir_sizes: HashMap<String, u32> = HashMap::new();
     parse(input, &mut dir_sizes);
-    let result = dir_sizes.iter()
-        .map(|(_, size)| *size)
+    let result = dir_sizes
+        .values()
+        .copied()
         .filter(|size| *size < 100000)
         .sum();
     Some(result)
@@ -59,32 +57,27 @@ pub fn part_two(input: &str) -> Option<u32> {
     let required_space: u32 = 30000000;
     let used_space: u32 = *dir_sizes.get("").unwrap_or(&0u32);
     let min_delete = required_space - (disk_size - used_space);
-    let to_delete = dir_sizes.iter()
-        .map(|(_, size)| *size)
+    let to_delete = dir_sizes
+        .values()
+        .copied()
         .filter(|size| *size >= min_delete)
         .min();
     to_delete
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_one(&input), Some(95437));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_two(&input), Some(24933642));
     }
 }
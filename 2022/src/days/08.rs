@@ -1,3 +1,5 @@
+advent_of_code::solution!(8);
+
 #[derive(Debug)]
 struct Grid {
     width: usize,
@@ -7,9 +9,9 @@ struct Grid {
 
 impl Grid {
     pub fn full(value: usize, width: usize, height: usize) -> Grid {
-        let data: Vec<Vec<usize>> = (0..height).map(|_| {
-            (0..width).map(|_| value).collect()
-        }).collect();
+        let data: Vec<Vec<usize>> = (0..height)
+            .map(|_| (0..width).map(|_| value).collect())
+            .collect();
         Grid {
             width,
             height,
@@ -17,7 +19,7 @@ impl Grid {
         }
     }
 
-    pub fn iter(&self, x: isize, y: isize, direction: [isize; 2]) -> GridIterator {
+    pub fn iter(&self, x: isize, y: isize, direction: [isize; 2]) -> GridIterator<'_> {
         GridIterator {
             x,
             y,
@@ -61,9 +63,14 @@ impl<'a> Iterator for GridIterator<'a> {
 
 impl Grid {
     pub fn parse(input: &str) -> Grid {
-        let data: Vec<Vec<usize>> = input.lines().map(|line| {
-            line.chars().map(|x| x.to_digit(10).unwrap() as usize).collect()
-        }).collect();
+        let data: Vec<Vec<usize>> = input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|x| x.to_digit(10).unwrap() as usize)
+                    .collect()
+            })
+            .collect();
         let height = data.len();
         let width = data[0].len();
         Grid {
@@ -82,8 +89,13 @@ pub fn part_one(input: &str) -> Option<u32> {
         for normal_reverse in [1, -1] {
             let idx_max = if row_col { grid.width } else { grid.height };
             for idx in 0..idx_max {
-                let idx_start: isize =
-                    if normal_reverse > 0 { -1 } else if row_col { grid.height as isize } else { grid.width as isize };
+                let idx_start: isize = if normal_reverse > 0 {
+                    -1
+                } else if row_col {
+                    grid.height as isize
+                } else {
+                    grid.width as isize
+                };
 
                 let x = if row_col { idx as isize } else { idx_start };
                 let y = if row_col { idx_start } else { idx as isize };
@@ -102,7 +114,9 @@ pub fn part_one(input: &str) -> Option<u32> {
         }
     }
 
-    let visible_count: u32 = visibility.data.iter()
+    let visible_count: u32 = visibility
+        .data
+        .iter()
         .map(|x| x.iter().map(|x| *x as u32).sum::<u32>())
         .sum();
     // println!("{:?}", visibility);
@@ -117,8 +131,13 @@ pub fn part_two(input: &str) -> Option<usize> {
         for normal_reverse in [1, -1] {
             let idx_max = if row_col { grid.width } else { grid.height };
             for idx in 0..idx_max {
-                let idx_start: isize =
-                    if normal_reverse > 0 { -1 } else if row_col { grid.height as isize } else { grid.width as isize };
+                let idx_start: isize = if normal_reverse > 0 {
+                    -1
+                } else if row_col {
+                    grid.height as isize
+                } else {
+                    grid.width as isize
+                };
 
                 let x = if row_col { idx as isize } else { idx_start };
                 let y = if row_col { idx_start } else { idx as isize };
@@ -128,9 +147,13 @@ pub fn part_two(input: &str) -> Option<usize> {
                 ];
                 let mut stack: Vec<usize> = Vec::new();
                 for item in grid.iter(x, y, direction) {
-                    let outlook = stack.iter().rev().enumerate()
+                    let outlook = stack
+                        .iter()
+                        .rev()
+                        .enumerate()
                         .find(|(_, x)| **x >= item.value())
-                        .map(|(x, _)| x + 1).unwrap_or(stack.len());
+                        .map(|(x, _)| x + 1)
+                        .unwrap_or(stack.len());
                     //TODO this could clean up the stack so that it does not grow so much
                     // or maybe have like a hashmap from tree height to visibility so that
                     // its static sized
@@ -141,30 +164,28 @@ pub fn part_two(input: &str) -> Option<usize> {
         }
     }
     // println!("{:?}", visibility);
-    let best_visibility = visibility.data.iter()
-        .flat_map(|x| x.iter().max()).max().unwrap();
+    let best_visibility = visibility
+        .data
+        .iter()
+        .flat_map(|x| x.iter().max())
+        .max()
+        .unwrap();
     Some(*best_visibility)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 8, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 8, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_one(&input), Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 8, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_two(&input), Some(8));
     }
 }
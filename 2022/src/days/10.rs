@@ -1,4 +1,4 @@
-extern crate core;
+advent_of_code::solution!(10);
 
 use itertools::Itertools;
 use queues::{IsQueue, Queue};
@@ -138,51 +138,42 @@ pub fn part_two(input: &str) -> Option<String> {
         })
         .collect::<Vec<char>>()
         .chunks(40)
-        .map(|x| {
-            x.iter().collect()
-        }).collect();
+        .map(|x| x.iter().collect())
+        .collect();
     render.iter().for_each(|line| {
         println!("{}", line);
     });
     Some(String::from("ECZUZALR"))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         let cpu = Cpu::new();
-        cpu.interpret(&input).for_each(|state| {
-            match state.cycle {
-                1 => assert_eq!(state.register, 1),
-                2 => assert_eq!(state.register, 1),
-                3 => assert_eq!(state.register, 1),
-                4 => assert_eq!(state.register, 4),
-                5 => assert_eq!(state.register, 4),
-                6 => assert_eq!(state.register, -1),
-                _ => panic!("")
-            }
+        cpu.interpret(&input).for_each(|state| match state.cycle {
+            1 => assert_eq!(state.register, 1),
+            2 => assert_eq!(state.register, 1),
+            3 => assert_eq!(state.register, 1),
+            4 => assert_eq!(state.register, 4),
+            5 => assert_eq!(state.register, 4),
+            6 => assert_eq!(state.register, -1),
+            _ => panic!(""),
         });
     }
 
     #[test]
     fn test_part_one_larger() {
-        let input = advent_of_code::read_file("examples", 10, Some("larger"));
+        let input = advent_of_code::template::read_file_part("examples", DAY, 2);
         assert_eq!(part_one(&input), Some(13140))
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10, Some("larger"));
+        let input = advent_of_code::template::read_file_part("examples", DAY, 2);
         assert_eq!(part_two(&input), Some(String::from("ECZUZALR")));
     }
 }
@@ -1,3 +1,5 @@
+advent_of_code::solution!(12);
+
 use std::collections::{HashMap, HashSet};
 
 use queues::{IsQueue, Queue};
@@ -14,19 +16,13 @@ struct Grid {
 impl From<&str> for Grid {
     fn from(input: &str) -> Self {
         let mut data: HashMap<Point2<i32>, char> = HashMap::new();
-        input
-            .lines().enumerate()
-            .for_each(|(y, line)| {
-                line.chars().enumerate().for_each(|(x, c)| {
-                    data.insert(Point2::new(x as i32, y as i32), c);
-                })
-            });
-        let start_position = *data.iter().find(|(_, c)| {
-            **c == 'S'
-        }).map(|x| x.0).unwrap();
-        let end_position = *data.iter().find(|(_, c)| {
-            **c == 'E'
-        }).map(|x| x.0).unwrap();
+        input.lines().enumerate().for_each(|(y, line)| {
+            line.chars().enumerate().for_each(|(x, c)| {
+                data.insert(Point2::new(x as i32, y as i32), c);
+            })
+        });
+        let start_position = *data.iter().find(|(_, c)| **c == 'S').map(|x| x.0).unwrap();
+        let end_position = *data.iter().find(|(_, c)| **c == 'E').map(|x| x.0).unwrap();
         Grid {
             data,
             start_position,
@@ -56,24 +52,28 @@ impl PartOne<u32> for Grid {
                 break;
             }
             let neighbors = self.neighbors(position);
-            neighbors.iter().filter(|candidate| {
-                !visited.contains_key(candidate)
-            }).for_each(|candidate| {
-                queue.add((steps + 1, *candidate)).expect("");
-            });
+            neighbors
+                .iter()
+                .filter(|candidate| !visited.contains_key(candidate))
+                .for_each(|candidate| {
+                    queue.add((steps + 1, *candidate)).expect("");
+                });
         }
         visited.get(&self.end_position).copied()
     }
 
     fn neighbors(&self, position: Point2<i32>) -> Vec<Point2<i32>> {
-        let mut neighbors: Vec<Point2<i32>> = Vec::new();
-        neighbors.push(Point2::new(position.x + 1, position.y));
-        neighbors.push(Point2::new(position.x - 1, position.y));
-        neighbors.push(Point2::new(position.x, position.y + 1));
-        neighbors.push(Point2::new(position.x, position.y - 1));
-        neighbors.iter().filter(|x| {
-            self.is_valid_transition(&position, x)
-        }).copied().collect()
+        let neighbors = [
+            Point2::new(position.x + 1, position.y),
+            Point2::new(position.x - 1, position.y),
+            Point2::new(position.x, position.y + 1),
+            Point2::new(position.x, position.y - 1),
+        ];
+        neighbors
+            .iter()
+            .filter(|x| self.is_valid_transition(&position, x))
+            .copied()
+            .collect()
     }
 
     fn is_valid_transition(&self, position: &Point2<i32>, next: &Point2<i32>) -> bool {
@@ -94,7 +94,6 @@ impl PartOne<u32> for Grid {
     }
 }
 
-
 pub trait PartTwo<T> {
     fn find_path_part_two(&self) -> Option<u32>;
     fn reverse_neighbors(&self, position: Point2<i32>) -> Vec<Point2<i32>>;
@@ -103,9 +102,12 @@ pub trait PartTwo<T> {
 impl PartTwo<u32> for Grid {
     fn find_path_part_two(&self) -> Option<u32> {
         let mut to_visit: HashSet<Point2<i32>> = HashSet::new();
-        self.data.iter()
+        self.data
+            .iter()
             .filter(|(_, c)| **c == 'a')
-            .for_each(|(pos, _)| { to_visit.insert(*pos); });
+            .for_each(|(pos, _)| {
+                to_visit.insert(*pos);
+            });
         let mut visited: HashMap<Point2<i32>, u32> = HashMap::new();
         let mut queue: Queue<(u32, Point2<i32>)> = Queue::new();
         queue.add((0, self.end_position)).expect("");
@@ -117,33 +119,37 @@ impl PartTwo<u32> for Grid {
             visited.insert(position, steps);
             to_visit.remove(&position);
             if to_visit.is_empty() {
-                break
+                break;
             }
             let neighbors = self.reverse_neighbors(position);
-            neighbors.iter().filter(|candidate| {
-                !visited.contains_key(candidate)
-            }).for_each(|candidate| {
-                queue.add((steps + 1, *candidate)).expect("");
-            });
+            neighbors
+                .iter()
+                .filter(|candidate| !visited.contains_key(candidate))
+                .for_each(|candidate| {
+                    queue.add((steps + 1, *candidate)).expect("");
+                });
         }
 
-        self.data.iter()
+        self.data
+            .iter()
             .filter(|(_, c)| **c == 'a')
-            .flat_map(|(pos, _)| {
-                visited.get(pos)
-            })
-            .min().copied()
+            .flat_map(|(pos, _)| visited.get(pos))
+            .min()
+            .copied()
     }
 
     fn reverse_neighbors(&self, position: Point2<i32>) -> Vec<Point2<i32>> {
-        let mut neighbors: Vec<Point2<i32>> = Vec::new();
-        neighbors.push(Point2::new(position.x + 1, position.y));
-        neighbors.push(Point2::new(position.x - 1, position.y));
-        neighbors.push(Point2::new(position.x, position.y + 1));
-        neighbors.push(Point2::new(position.x, position.y - 1));
-        neighbors.iter().filter(|x| {
-            self.is_valid_transition(x, &position)
-        }).copied().collect()
+        let neighbors = [
+            Point2::new(position.x + 1, position.y),
+            Point2::new(position.x - 1, position.y),
+            Point2::new(position.x, position.y + 1),
+            Point2::new(position.x, position.y - 1),
+        ];
+        neighbors
+            .iter()
+            .filter(|x| self.is_valid_transition(x, &position))
+            .copied()
+            .collect()
     }
 }
 
@@ -157,25 +163,19 @@ pub fn part_two(input: &str) -> Option<u32> {
     grid.find_path_part_two()
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 12, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 12, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_one(&input), Some(31));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 12, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_two(&input), Some(29));
     }
 }
@@ -1,21 +1,20 @@
-extern crate core;
+advent_of_code::solution!(13);
 
+use itertools::Itertools;
 use std::cmp::Ordering;
 use std::str::FromStr;
-use itertools::Itertools;
 
 #[derive(Debug, Clone, Eq)]
-pub enum Signal{
+pub enum Signal {
     Single(u8),
-    Multiple(Vec<Signal>)
+    Multiple(Vec<Signal>),
 }
 
-
-impl FromStr for Signal{
+impl FromStr for Signal {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = &s[1..s.len()-1];
+        let s = &s[1..s.len() - 1];
         let mut stack: Vec<Vec<Signal>> = vec![];
         let mut multiple: Vec<Signal> = vec![];
         let mut single: Option<u8> = None;
@@ -24,20 +23,19 @@ impl FromStr for Signal{
             match c {
                 b'0'..=b'9' => {
                     // append to current single number
-                    single = Some(single
-                        .map(|curr| curr * 10 + c - b'0')
-                        .unwrap_or(c - b'0')
-                    );
+                    single = Some(single.map(|curr| curr * 10 + c - b'0').unwrap_or(c - b'0'));
                 }
                 b',' => {
                     // finalize a number if there is one in progress
-                    single.iter()
+                    single
+                        .iter()
                         .for_each(|x| multiple.push(Signal::Single(*x)));
                     single = None;
                 }
                 b'[' => {
                     // finalize a number if there is one in progress
-                    single.iter()
+                    single
+                        .iter()
                         .for_each(|x| multiple.push(Signal::Single(*x)));
                     single = None;
                     // push the current signal to the top of the stack
@@ -47,7 +45,8 @@ impl FromStr for Signal{
                 }
                 b']' => {
                     // finalize a number if there is one in progress
-                    single.iter()
+                    single
+                        .iter()
                         .for_each(|x| multiple.push(Signal::Single(*x)));
                     single = None;
                     // push the current in-progress signal to the one at the top of the stack
@@ -55,11 +54,12 @@ impl FromStr for Signal{
                     multiple = stack.pop().unwrap();
                     multiple.push(signal);
                 }
-                _ => panic!("Unexpected char {}", c)
+                _ => panic!("Unexpected char {}", c),
             }
         });
 
-        single.iter()
+        single
+            .iter()
             .for_each(|x| multiple.push(Signal::Single(*x)));
         Ok(Signal::Multiple(multiple))
     }
@@ -85,75 +85,80 @@ impl Ord for Signal {
             (left @ Signal::Single(_), Signal::Multiple(right)) => {
                 let left_list = std::slice::from_ref(left);
                 left_list.cmp(right.as_slice())
-            },
-            (Signal::Multiple(left), right @ Signal::Single(_)) =>{
+            }
+            (Signal::Multiple(left), right @ Signal::Single(_)) => {
                 let right_list = std::slice::from_ref(right);
                 left.as_slice().cmp(right_list)
-            },
+            }
         }
     }
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
-    let data: Vec<(Signal, Signal)> = input.split("\n\n").map(|pair| {
-        let signal_pair = pair.lines()
-            .map(|line| Signal::from_str(line).expect(""))
-            .next_tuple()
-            .unwrap();
-        signal_pair
-    }).collect();
-
-    let result: usize = data.iter().enumerate()
-        .flat_map(|(idx, (first, second))| {
-            if first <= second {
-                Some(idx+1)
-            } else {
-                None
-            }
+    let data: Vec<(Signal, Signal)> = input
+        .split("\n\n")
+        .map(|pair| {
+            let signal_pair = pair
+                .lines()
+                .map(|line| Signal::from_str(line).expect(""))
+                .next_tuple()
+                .unwrap();
+            signal_pair
         })
+        .collect();
+
+    let result: usize = data
+        .iter()
+        .enumerate()
+        .flat_map(
+            |(idx, (first, second))| {
+                if first <= second {
+                    Some(idx + 1)
+                } else {
+                    None
+                }
+            },
+        )
         .sum();
     Some(result)
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    let mut data: Vec<Signal> = input.lines().flat_map(|line| {
-        if line.is_empty() {
-            None
-        } else {
-            Signal::from_str(line).ok()
-        }
-    }).collect();
+    let mut data: Vec<Signal> = input
+        .lines()
+        .flat_map(|line| {
+            if line.is_empty() {
+                None
+            } else {
+                Signal::from_str(line).ok()
+            }
+        })
+        .collect();
     data.push(Signal::Single(2));
     data.push(Signal::Single(6));
     data.sort();
-    let result = data.iter().enumerate()
-        .filter(|(_, x)| {
-            matches!(x, Signal::Single(2) | Signal::Single(6))
-        })
+    let result = data
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| matches!(x, Signal::Single(2) | Signal::Single(6)))
         .map(|x| x.0 + 1)
         .product();
     Some(result)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 13, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_one(&input), Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_two(&input), Some(140));
     }
 }
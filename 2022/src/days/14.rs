@@ -0,0 +1,152 @@
+advent_of_code::solution!(14);
+
+use advent_of_code::helpers::Point2;
+use advent_of_code::sparse::SparseGrid;
+use itertools::Itertools;
+use num::signum;
+use std::fmt::{Debug, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Rock,
+    Sand,
+}
+
+#[derive(Clone)]
+struct Grid {
+    cave: SparseGrid<Point2<i32>, Tile>,
+    bottom: i32,
+}
+
+impl Grid {
+    pub fn fall_sand(&mut self) -> bool {
+        let mut sand = Point2::new(500, 0);
+        while sand.y < self.bottom {
+            let candidates = [
+                sand + Point2::new(0, 1),
+                sand + Point2::new(-1, 1),
+                sand + Point2::new(1, 1),
+            ];
+            let maybe_resolved = candidates
+                .iter()
+                .find(|candidate| !self.cave.contains(**candidate));
+            if let Some(resolved) = maybe_resolved {
+                sand = *resolved;
+            } else {
+                break;
+            }
+        }
+        if sand.y >= self.bottom || (sand.x == 500 && sand.y == 0) {
+            // println!("Exiting with sand at rest {:?}. Bottom {}", sand, self.bottom);
+            false
+        } else {
+            self.cave.insert(sand, Tile::Sand);
+            true
+        }
+    }
+
+    fn sand(&self) -> usize {
+        self.cave
+            .values()
+            .filter(|tile| **tile == Tile::Sand)
+            .count()
+    }
+}
+
+impl Debug for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // the source is added so the drawing starts at the top
+        let source = Point2::new(500, 0);
+        let mut cave = self.cave.clone();
+        cave.insert(source, Tile::Sand);
+        let s = cave.render_with(|point, tile| match tile {
+            _ if point == source => 'x',
+            Some(Tile::Rock) => '#',
+            Some(Tile::Sand) => 'o',
+            None => '.',
+        });
+        writeln!(f, "{}", s)
+    }
+}
+
+impl FromStr for Grid {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cave = SparseGrid::new();
+        s.lines().for_each(|line| {
+            let points: Vec<Point2<i32>> = line
+                .split(" -> ")
+                .map(|point| {
+                    let (x, y) = point
+                        .split(',')
+                        .map(|n| n.parse::<i32>().expect(""))
+                        .next_tuple()
+                        .unwrap();
+                    Point2::new(x, y)
+                })
+                .collect();
+            points.windows(2).for_each(|point_line| {
+                let vector = point_line[1] - point_line[0];
+                let step = Point2::new(signum(vector.x), signum(vector.y));
+                let mut point = point_line[0];
+                while point != point_line[1] {
+                    cave.insert(point, Tile::Rock);
+                    point += step;
+                }
+                cave.insert(point_line[1], Tile::Rock);
+            });
+        });
+
+        let bottom = cave.bounds().unwrap().1.y;
+
+        Ok(Grid { cave, bottom })
+    }
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let mut grid = Grid::from_str(input).expect("");
+    // println!("{:?}", grid);
+    while grid.fall_sand() {
+        // println!("{:?}", grid);
+    }
+    // println!("{:?}", grid);
+    Some(grid.sand())
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let mut grid = Grid::from_str(input).expect("");
+
+    // create the bottom floor
+    grid.bottom += 2;
+    let start_x = 500 - grid.bottom;
+    let end_x = 500 + grid.bottom;
+    for x in start_x..=end_x {
+        grid.cave.insert(Point2::new(x, grid.bottom), Tile::Rock);
+    }
+
+    // println!("{:?}", grid);
+    while grid.fall_sand() {
+        // println!("{:?}", grid);
+    }
+    // println!("{:?}", grid);
+    Some(grid.sand() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        assert_eq!(part_one(&input), Some(24));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        assert_eq!(part_two(&input), Some(93));
+    }
+}
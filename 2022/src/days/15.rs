@@ -1,3 +1,5 @@
+advent_of_code::solution!(15);
+
 use std::collections::HashSet;
 use std::str::FromStr;
 
@@ -14,22 +16,33 @@ struct SensorBeacon {
 
 impl SensorBeacon {
     pub fn distance_to(&self, point: &Point2<i32>) -> i32 {
-        (self.sensor.x - point.x).abs() +
-            (self.sensor.y - point.y).abs()
+        (self.sensor.x - point.x).abs() + (self.sensor.y - point.y).abs()
     }
 
     /*
     I dont really understand why i need to specify the lifetime here :(
      */
     pub fn perimeter_of(&self) -> impl Iterator<Item = Point2<i32>> + '_ {
-        (0..=self.radius).flat_map(|delta|
+        (0..=self.radius).flat_map(|delta| {
             [
-                Point2::new(self.sensor.x - delta, self.sensor.y - self.radius - 1 + delta),
-                Point2::new(self.sensor.x + self.radius + 1 - delta, self.sensor.y + delta),
-                Point2::new(self.sensor.x - delta, self.sensor.y + self.radius + 1 + delta),
-                Point2::new(self.sensor.x - self.radius - 1 + delta, self.sensor.y - delta)
+                Point2::new(
+                    self.sensor.x - delta,
+                    self.sensor.y - self.radius - 1 + delta,
+                ),
+                Point2::new(
+                    self.sensor.x + self.radius + 1 - delta,
+                    self.sensor.y + delta,
+                ),
+                Point2::new(
+                    self.sensor.x - delta,
+                    self.sensor.y + self.radius + 1 + delta,
+                ),
+                Point2::new(
+                    self.sensor.x - self.radius - 1 + delta,
+                    self.sensor.y - delta,
+                ),
             ]
-        )
+        })
     }
 }
 
@@ -38,27 +51,34 @@ impl FromStr for SensorBeacon {
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
         let pattern = Regex::new(
-            r"Sensor at x=([-\d]+), y=([-\d]+): closest beacon is at x=([-\d]+), y=([-\d]+)"
-        ).expect("");
+            r"Sensor at x=([-\d]+), y=([-\d]+): closest beacon is at x=([-\d]+), y=([-\d]+)",
+        )
+        .expect("");
         let captures = pattern.captures(line).expect("");
-        let s_x = captures.get(1)
-            .and_then(|x| x.as_str().parse::<i32>().ok()).unwrap();
-        let s_y = captures.get(2)
-            .and_then(|x| x.as_str().parse::<i32>().ok()).unwrap();
-        let b_x = captures.get(3)
-            .and_then(|x| x.as_str().parse::<i32>().ok()).unwrap();
-        let b_y = captures.get(4)
-            .and_then(|x| x.as_str().parse::<i32>().ok()).unwrap();
+        let s_x = captures
+            .get(1)
+            .and_then(|x| x.as_str().parse::<i32>().ok())
+            .unwrap();
+        let s_y = captures
+            .get(2)
+            .and_then(|x| x.as_str().parse::<i32>().ok())
+            .unwrap();
+        let b_x = captures
+            .get(3)
+            .and_then(|x| x.as_str().parse::<i32>().ok())
+            .unwrap();
+        let b_y = captures
+            .get(4)
+            .and_then(|x| x.as_str().parse::<i32>().ok())
+            .unwrap();
 
         let radius = (s_x - b_x).abs() + (s_y - b_y).abs();
 
-        Ok(
-            SensorBeacon {
-                sensor: Point2::new(s_x, s_y),
-                beacon: Point2::new(b_x, b_y),
-                radius,
-            }
-        )
+        Ok(SensorBeacon {
+            sensor: Point2::new(s_x, s_y),
+            beacon: Point2::new(b_x, b_y),
+            radius,
+        })
     }
 }
 
@@ -70,12 +90,12 @@ impl FromStr for Readings {
     type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let readings: Vec<SensorBeacon> = input.lines()
-            .map(|x| SensorBeacon::from_str(x).expect("")).collect();
+        let readings: Vec<SensorBeacon> = input
+            .lines()
+            .map(|x| SensorBeacon::from_str(x).expect(""))
+            .collect();
 
-        Ok(Readings {
-            readings
-        })
+        Ok(Readings { readings })
     }
 }
 
@@ -103,25 +123,22 @@ impl Readings {
     }
 
     pub fn scan_area(&self, min_coord: i32, max_coord: i32) -> Option<Point2<i32>> {
-        self.readings.iter()
+        self.readings
+            .iter()
             .flat_map(|sensor| sensor.perimeter_of())
             .filter(|p| p.x >= min_coord && p.x <= max_coord)
             .filter(|p| p.y >= min_coord && p.y <= max_coord)
             .find(|pos| {
-                self.readings.iter()
-                    .all(|sensor|
-                        sensor.distance_to(pos) > sensor.radius
-                    )
+                self.readings
+                    .iter()
+                    .all(|sensor| sensor.distance_to(pos) > sensor.radius)
             })
     }
-
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
     let readings = Readings::from_str(input).expect("");
-    Some(
-        readings.count_line(2000000)
-    )
+    Some(readings.count_line(2000000))
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
@@ -130,26 +147,20 @@ pub fn part_two(input: &str) -> Option<u64> {
     result.map(|p| (p.x as u64) * 4000000 + (p.y as u64))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 15, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 15, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         let readings = Readings::from_str(&input).expect("");
         assert_eq!(readings.count_line(10), 26);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 15, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
 
         let readings = Readings::from_str(&input).expect("");
         let result = readings.scan_area(0, 20);
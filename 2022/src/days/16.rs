@@ -1,17 +1,19 @@
-use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+advent_of_code::solution!(16);
+
+use std::collections::{BTreeSet, HashMap};
 use std::fmt::Debug;
 use std::str::FromStr;
 
-use petgraph::{Directed, Graph};
 use petgraph::adj::DefaultIx;
 use petgraph::algo::floyd_warshall;
 use petgraph::graph::NodeIndex;
+use petgraph::{Directed, Graph};
 use regex::Regex;
 
 #[derive(Debug)]
 struct CaveSystem {
     flow_rates: HashMap<u16, u32>,
-    distances: HashMap<(u16, u16), u32>
+    distances: HashMap<(u16, u16), u32>,
 }
 
 impl FromStr for CaveSystem {
@@ -22,65 +24,60 @@ impl FromStr for CaveSystem {
         let mut nodes: HashMap<u16, NodeIndex<DefaultIx>> = HashMap::new();
         let mut flow_rates: HashMap<u16, u32> = HashMap::new();
 
-        let pattern = Regex::new(r"Valve ([A-Z]+).*rate=(\d+);.*valves? ([A-Z, ]+)")
-            .unwrap();
+        let pattern = Regex::new(r"Valve ([A-Z]+).*rate=(\d+);.*valves? ([A-Z, ]+)").unwrap();
 
         input.lines().for_each(|line| {
             let captures = pattern.captures(line).unwrap();
             let valve: String = String::from(captures.get(1).unwrap().as_str());
             let valve_id = CaveSystem::valve_id(&valve);
-            let flow_rate = captures.get(2)
+            let flow_rate = captures
+                .get(2)
                 .map(|x| x.as_str().parse::<u32>().unwrap())
                 .unwrap();
-            let tunnels: Vec<String> = captures.get(3).unwrap()
-                .as_str().split(", ").map(String::from).collect();
+            let tunnels: Vec<String> = captures
+                .get(3)
+                .unwrap()
+                .as_str()
+                .split(", ")
+                .map(String::from)
+                .collect();
             // println!("Valve {:?}, flow_rate {:?}, tunnels {:?}", valve, flow_rate, tunnels);
             flow_rates.insert(valve_id, flow_rate);
 
-            if !nodes.contains_key(&valve_id) {
-                nodes.insert(valve_id, graph.add_node(()));
-            }
+            nodes.entry(valve_id).or_insert_with(|| graph.add_node(()));
             let valve_node = *nodes.get(&valve_id).unwrap();
 
             tunnels.iter().for_each(|x| {
                 let x_id = CaveSystem::valve_id(x);
-                if !nodes.contains_key(&x_id) {
-                    nodes.insert(x_id, graph.add_node(()));
-                }
+                nodes.entry(x_id).or_insert_with(|| graph.add_node(()));
                 let tunnel_node = *nodes.get(&x_id).unwrap();
                 graph.add_edge(valve_node, tunnel_node, ());
             })
         });
 
-        let res = floyd_warshall(
-            &graph,
-            |_| 1,
-        ).expect("");
+        let res = floyd_warshall(&graph, |_| 1).expect("");
         let mut distances: HashMap<(u16, u16), u32> = HashMap::new();
         nodes.iter().for_each(|from| {
             nodes.iter().for_each(|to| {
                 if let Some(distance) = res.get(&(*from.1, *to.1)) {
-                    distances.insert(
-                        (from.0.clone(), to.0.clone()),
-                        *distance as u32,
-                    );
+                    distances.insert((*from.0, *to.0), *distance as u32);
                 }
             });
         });
 
         Ok(CaveSystem {
             flow_rates,
-            distances
+            distances,
         })
     }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
-struct IterState{
+struct IterState {
     position: u16,
     visited: BTreeSet<u16>,
     time: u32,
-    released_pressure: u32
+    released_pressure: u32,
 }
 
 impl CaveSystem {
@@ -92,22 +89,22 @@ impl CaveSystem {
     }
 
     pub fn open_valves(&self, max_time: u32) -> u32 {
-        let state = IterState{
+        let state = IterState {
             position: CaveSystem::valve_id("AA"),
             visited: Default::default(),
             time: max_time,
-            released_pressure: 0
+            released_pressure: 0,
         };
         let mut cache: HashMap<IterState, u32> = HashMap::new();
         self.turn_valves(&state, &mut cache)
     }
 
     pub fn open_valves_double(&self, max_time: u32) -> u32 {
-        let state = IterState{
+        let state = IterState {
             position: CaveSystem::valve_id("AA"),
             visited: Default::default(),
             time: max_time,
-            released_pressure: 0
+            released_pressure: 0,
         };
         let mut cache: HashMap<IterState, u32> = HashMap::new();
         self.turn_valves_double(&state, max_time, &mut cache)
@@ -117,7 +114,8 @@ impl CaveSystem {
         if let Some(result) = cache.get(state) {
             return *result;
         }
-        let result = self.next(&state)
+        let result = self
+            .next(state)
             .map(|next_state| self.turn_valves(&next_state, cache))
             .max()
             .unwrap_or(state.released_pressure);
@@ -129,7 +127,7 @@ impl CaveSystem {
         &self,
         state: &IterState,
         max_time: u32,
-        cache: &mut HashMap<IterState, u32>
+        cache: &mut HashMap<IterState, u32>,
     ) -> u32 {
         if let Some(&result) = cache.get(state) {
             return result;
@@ -147,7 +145,8 @@ impl CaveSystem {
         let mut elephant_cache: HashMap<IterState, u32> = HashMap::new();
         let elephant_pressure = self.turn_valves(&elephant, &mut elephant_cache);
         // or we continue recursively
-        let recursive_max = self.next(&state)
+        let recursive_max = self
+            .next(state)
             .map(|next_state| self.turn_valves_double(&next_state, max_time, cache))
             .max()
             .unwrap_or(state.released_pressure);
@@ -156,15 +155,16 @@ impl CaveSystem {
         result
     }
 
-    pub fn next<'a>(
-        &'a self,
-        state: &'a IterState
-    ) -> impl Iterator<Item = IterState> + 'a {
+    pub fn next<'a>(&'a self, state: &'a IterState) -> impl Iterator<Item = IterState> + 'a {
         self.distances
             .iter()
             .filter_map(move |((from, to), distance)| {
                 let flow_rate = self.flow_rates[to];
-                if state.position == *from && !state.visited.contains(&to) && state.time > *distance && flow_rate > 0 {
+                if state.position == *from
+                    && !state.visited.contains(to)
+                    && state.time > *distance
+                    && flow_rate > 0
+                {
                     let mut next_state = state.clone();
                     next_state.position = *to;
                     next_state.time -= distance + 1;
@@ -178,12 +178,6 @@ impl CaveSystem {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
-struct ActorState {
-    position: u16,
-    time_left: i64
-}
-
 pub fn part_one(input: &str) -> Option<u32> {
     let cave_system = CaveSystem::from_str(input).expect("");
     Some(cave_system.open_valves(30))
@@ -194,39 +188,34 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(cave_system.open_valves_double(26))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 16, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 16, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_one(&input), Some(1651));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 16, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_two(&input), Some(1707));
     }
 
     #[test]
     fn test_recursion_floor() {
-        let input = advent_of_code::read_file("examples", 16, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         let cave_system = CaveSystem::from_str(&input).expect("");
-        let state = IterState{
+        let state = IterState {
             position: CaveSystem::valve_id("DD"),
-            visited: vec!["BB", "DD", "EE", "HH", "JJ"].iter()
+            visited: ["BB", "DD", "EE", "HH", "JJ"]
+                .iter()
                 .map(|x| CaveSystem::valve_id(x))
                 .collect(),
             time: 8,
-            released_pressure: 0
+            released_pressure: 0,
         };
         let mut cache: HashMap<IterState, u32> = HashMap::new();
         assert_eq!(cave_system.turn_valves(&state, &mut cache), 12);
@@ -1,10 +1,11 @@
-extern crate core;
+advent_of_code::solution!(17);
 
 use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 use std::str::FromStr;
 
 use advent_of_code::helpers::Point2;
+use advent_of_code::sparse::SparseGrid;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 enum Movement {
@@ -16,17 +17,11 @@ enum Movement {
 #[derive(Debug, Clone)]
 struct Block {
     points: Vec<Point2<i32>>,
-    height: i32
 }
 
 impl Block {
     pub fn new(points: Vec<Point2<i32>>) -> Block {
-        let max_y = points.iter().map(|p| p.y)
-            .max().unwrap();
-        Block {
-            points,
-            height: max_y
-        }
+        Block { points }
     }
 
     pub fn blocks() -> Vec<Block> {
@@ -54,27 +49,21 @@ impl Block {
             Point2::new(0, 0),
             Point2::new(0, 1),
             Point2::new(0, 2),
-            Point2::new(0, 3)
+            Point2::new(0, 3),
         ]);
         let box_block = Block::new(vec![
             Point2::new(0, 0),
             Point2::new(1, 0),
             Point2::new(0, 1),
-            Point2::new(1, 1)
+            Point2::new(1, 1),
         ]);
-        vec![
-            line_block,
-            plus_block,
-            l_block,
-            col_block,
-            box_block
-        ]
+        vec![line_block, plus_block, l_block, col_block, box_block]
     }
 }
 
 #[derive(Clone)]
 struct Chamber {
-    materialized: Vec<[bool; 7]>,
+    rocks: SparseGrid<Point2<i32>, ()>,
     jet_stream: Vec<Movement>,
     time: usize,
 }
@@ -83,17 +72,17 @@ impl FromStr for Chamber {
     type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let jet_stream = input.chars()
+        let jet_stream = input
+            .chars()
             .filter(|c| !c.is_whitespace())
-            .map(|c| {
-                match c {
-                    '<' => Movement::Left,
-                    '>' => Movement::Right,
-                    _ => panic!("Unknown character {}", c)
-                }
-            }).collect();
+            .map(|c| match c {
+                '<' => Movement::Left,
+                '>' => Movement::Right,
+                _ => panic!("Unknown character {}", c),
+            })
+            .collect();
         Ok(Chamber {
-            materialized: vec![],
+            rocks: SparseGrid::new(),
             jet_stream,
             time: 0,
         })
@@ -102,47 +91,38 @@ impl FromStr for Chamber {
 
 impl Debug for Chamber {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let s: String = self.materialized.iter().rev()
+        let s: String = (0..self.height() as i32)
+            .rev()
+            .map(|y| self.row(y))
             .flat_map(|line| {
-                let mut line_vec: Vec<char> = line.iter().map(|x| {
-                    if *x {
-                        '#'
-                    } else {
-                        '.'
-                    }
-                }).collect();
+                let mut line_vec: Vec<char> =
+                    line.iter().map(|x| if *x { '#' } else { '.' }).collect();
                 line_vec.push('\n');
                 line_vec
-            }).into_iter().collect();
+            })
+            .collect();
         writeln!(f, "{}", s)
     }
 }
 
-
 impl Chamber {
-    pub fn materialize(
-        &mut self,
-        block: &Block,
-        position: &Point2<i32>,
-    ) {
-        let top_row = block.height + position.y;
-        while self.materialized.len() <= top_row as usize {
-            self.materialized.push([false; 7]);
-        }
+    /// The height of the tower, the rows are counted from the floor up.
+    pub fn height(&self) -> usize {
+        self.rocks.bounds().map_or(0, |(_, max)| max.y as usize + 1)
+    }
+
+    fn row(&self, y: i32) -> [bool; 7] {
+        std::array::from_fn(|x| self.rocks.contains(Point2::new(x as i32, y)))
+    }
+
+    pub fn materialize(&mut self, block: &Block, position: &Point2<i32>) {
         block.points.iter().for_each(|point| {
-            let placed_point = *point + *position;
-            self.materialized[placed_point.y as usize][placed_point.x as usize] = true;
+            self.rocks.insert(*point + *position, ());
         });
     }
 
-    pub fn drop_block(
-        &mut self,
-        block: &Block,
-    ) {
-        let mut block_position: Point2<i32> = Point2::new(
-            2,
-            self.materialized.len() as i32 + 3,
-        );
+    pub fn drop_block(&mut self, block: &Block) {
+        let mut block_position: Point2<i32> = Point2::new(2, self.height() as i32 + 3);
 
         loop {
             let movement = self.jet_stream[self.time];
@@ -158,7 +138,7 @@ impl Chamber {
                 block_position.y -= 1;
             } else {
                 // println!("Rock falls 1 unit, causing it to come to rest");
-                break
+                break;
             }
         }
         self.materialize(block, &block_position);
@@ -180,11 +160,9 @@ impl Chamber {
             if point_position.x < 0 || point_position.x >= 7 || point_position.y < 0 {
                 // collision with walls
                 true
-            } else if point_position.y < self.materialized.len() as i32 {
-                // collision with materialized block
-                self.materialized[point_position.y as usize][point_position.x as usize]
             } else {
-                false
+                // collision with materialized block
+                self.rocks.contains(point_position)
             }
         })
     }
@@ -192,12 +170,13 @@ impl Chamber {
     pub fn get_block_snapshot(&self) -> Vec<[bool; 7]> {
         let mut top_view = [false; 7];
         let mut state: Vec<[bool; 7]> = Vec::new();
-        for row in self.materialized.iter().rev() {
-            state.push(*row);
-            row.iter().enumerate()
+        for row in (0..self.height() as i32).rev().map(|y| self.row(y)) {
+            state.push(row);
+            row.iter()
+                .enumerate()
                 .for_each(|(idx, val)| top_view[idx] |= val);
             if row.iter().all(|x| x.eq(&true)) {
-                break
+                break;
             }
         }
         state
@@ -209,7 +188,7 @@ pub fn part_one(input: &str) -> Option<usize> {
     Block::blocks().iter().cycle().take(2022).for_each(|block| {
         chamber.drop_block(block);
     });
-    Some(chamber.materialized.len())
+    Some(chamber.height())
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
@@ -232,13 +211,13 @@ pub fn part_two(input: &str) -> Option<usize> {
         if states.contains_key(&cur_state) {
             idx = i;
             state = cur_state;
-            break
+            break;
         }
-        states.insert(cur_state, (i, chamber.materialized.len()));
+        states.insert(cur_state, (i, chamber.height()));
     }
 
     let cycle_start = states[&state];
-    let cycle_end = (idx, chamber.materialized.len());
+    let cycle_end = (idx, chamber.height());
     let cycle_height = cycle_end.1 - cycle_start.1;
     let cycle_length = cycle_end.0 - cycle_start.0;
 
@@ -248,35 +227,29 @@ pub fn part_two(input: &str) -> Option<usize> {
         chamber.drop_block(block);
     }
     let cycle_count = (round_count - (cycle_start.0 + cycle_offset)) / cycle_length;
-    Some(cycle_height * (cycle_count - 1) + chamber.materialized.len())
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 17, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    Some(cycle_height * (cycle_count - 1) + chamber.height())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    // #[test]
-    // fn test_part_one() {
-    //     let input = advent_of_code::read_file("examples", 17, None);
-    //     assert_eq!(part_one(&input), Some(3068));
-    // }
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::template::read_file("examples", DAY);
+        assert_eq!(part_one(&input), Some(3068));
+    }
 
     // #[test]
     // fn test_part_two() {
-    //     let input = advent_of_code::read_file("examples", 17, None);
+    //     let input = advent_of_code::template::read_file("examples", DAY);
     //     assert_eq!(part_two(&input), Some(1514285714288));
     // }
 
     #[test]
     fn test_drop_block() {
         let mut chamber = Chamber::from_str("<><><>").expect("");
-        chamber.materialized.push([false, false, true, false, false, false, false]);
+        chamber.rocks.insert(Point2::new(2, 0), ());
         let block = Block::new(vec![
             Point2::new(0, 0),
             Point2::new(1, 0),
@@ -291,7 +264,7 @@ mod tests {
     #[test]
     fn test_materialize() {
         let mut chamber = Chamber::from_str("<><><>").expect("");
-        chamber.materialized.push([false, false, true, false, false, false, false]);
+        chamber.rocks.insert(Point2::new(2, 0), ());
         let block = Block::new(vec![
             Point2::new(0, 0),
             Point2::new(1, 0),
@@ -306,37 +279,17 @@ mod tests {
     #[test]
     fn test_has_collision() {
         let mut chamber = Chamber::from_str("<><><>").expect("");
-        chamber.materialized.push([false, false, true, false, false, false, false]);
+        chamber.rocks.insert(Point2::new(2, 0), ());
         let block = Block::new(vec![
             Point2::new(0, 0),
             Point2::new(1, 0),
             Point2::new(2, 0),
             Point2::new(3, 0),
         ]);
-        assert_eq!(chamber.has_collision(
-            &block,
-            &Point2::new(2, 1),
-            Movement::Down,
-        ), true);
-        assert_eq!(chamber.has_collision(
-            &block,
-            &Point2::new(2, 2),
-            Movement::Down,
-        ), false);
-        assert_eq!(chamber.has_collision(
-            &block,
-            &Point2::new(3, 1),
-            Movement::Down,
-        ), false);
-        assert_eq!(chamber.has_collision(
-            &block,
-            &Point2::new(3, 0),
-            Movement::Left,
-        ), true);
-        assert_eq!(chamber.has_collision(
-            &block,
-            &Point2::new(3, 0),
-            Movement::Down,
-        ), true);
+        assert!(chamber.has_collision(&block, &Point2::new(2, 1), Movement::Down,));
+        assert!(!chamber.has_collision(&block, &Point2::new(2, 2), Movement::Down,));
+        assert!(!chamber.has_collision(&block, &Point2::new(3, 1), Movement::Down,));
+        assert!(chamber.has_collision(&block, &Point2::new(3, 0), Movement::Left,));
+        assert!(chamber.has_collision(&block, &Point2::new(3, 0), Movement::Down,));
     }
 }
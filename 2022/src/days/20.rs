@@ -1,3 +1,5 @@
+advent_of_code::solution!(20);
+
 use std::collections::VecDeque;
 use std::fmt::{Debug, Formatter};
 use std::str::FromStr;
@@ -12,11 +14,12 @@ impl EncodedFile {
         for _ in 0..rounds {
             for original_idx in 0..self.data.len() {
                 // find the element to move
-                let idx = self.data.iter()
+                let idx = self
+                    .data
+                    .iter()
                     .enumerate()
-                    .find_map(|(ni, (oi, _))| {
-                        (*oi == original_idx).then_some(ni)
-                    }).unwrap();
+                    .find_map(|(ni, (oi, _))| (*oi == original_idx).then_some(ni))
+                    .unwrap();
                 // rotate list to start
                 self.data.rotate_left(idx);
                 let (oi, val) = self.data.pop_front().unwrap();
@@ -30,13 +33,15 @@ impl EncodedFile {
         }
     }
 
-    pub fn iter_zero(&self) -> impl Iterator<Item=i64> + '_ {
-        let start_idx = self.data
+    pub fn iter_zero(&self) -> impl Iterator<Item = i64> + '_ {
+        let start_idx = self
+            .data
             .iter()
             .enumerate()
             .find_map(|(idx, (_, val))| (0 == *val).then_some(idx))
             .unwrap();
-        self.data.iter()
+        self.data
+            .iter()
             .cycle()
             .map(|(_, val)| *val)
             .skip(start_idx)
@@ -44,11 +49,7 @@ impl EncodedFile {
     }
 
     pub fn coordinates(&self, x: usize, y: usize, z: usize) -> (i64, i64, i64) {
-        (
-            self.get(x),
-            self.get(y),
-            self.get(z)
-        )
+        (self.get(x), self.get(y), self.get(z))
     }
 
     fn get(&self, idx: usize) -> i64 {
@@ -68,13 +69,15 @@ impl FromStr for EncodedFile {
     type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let data = input.lines().enumerate().map(|(idx, line)| {
-            let value = line.parse::<i64>().unwrap();
-            (idx, value)
-        }).collect();
-        Ok(EncodedFile {
-            data
-        })
+        let data = input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                let value = line.parse::<i64>().unwrap();
+                (idx, value)
+            })
+            .collect();
+        Ok(EncodedFile { data })
     }
 }
 
@@ -95,25 +98,19 @@ pub fn part_two(input: &str) -> Option<i64> {
     Some((xc + yc + zc) * decryption_key)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 20, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 20, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_one(&input), Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 20, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_two(&input), Some(1623178306));
     }
 }
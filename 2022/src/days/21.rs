@@ -1,3 +1,5 @@
+advent_of_code::solution!(21);
+
 use std::collections::HashMap;
 use std::str::FromStr;
 
@@ -14,21 +16,13 @@ enum Operation {
 impl Operation {
     pub fn apply(&self, left: &Expression, right: &Expression) -> Expression {
         match (left, right) {
-            (Expression::Literal(left_i), Expression::Literal(right_i)) => {
-                match self {
-                    Operation::Sum => Expression::Literal(left_i + right_i),
-                    Operation::Sub => Expression::Literal(left_i - right_i),
-                    Operation::Div => Expression::Literal(left_i / right_i),
-                    Operation::Mul => Expression::Literal(left_i * right_i)
-                }
-            }
-            (x, y) => {
-                Expression::Op(
-                    *self,
-                    Box::new(x.clone()),
-                    Box::new(y.clone()),
-                )
-            }
+            (Expression::Literal(left_i), Expression::Literal(right_i)) => match self {
+                Operation::Sum => Expression::Literal(left_i + right_i),
+                Operation::Sub => Expression::Literal(left_i - right_i),
+                Operation::Div => Expression::Literal(left_i / right_i),
+                Operation::Mul => Expression::Literal(left_i * right_i),
+            },
+            (x, y) => Expression::Op(*self, Box::new(x.clone()), Box::new(y.clone())),
         }
     }
 
@@ -37,7 +31,7 @@ impl Operation {
             Operation::Sum => Operation::Sub,
             Operation::Sub => Operation::Sum,
             Operation::Div => Operation::Mul,
-            Operation::Mul => Operation::Div
+            Operation::Mul => Operation::Div,
         }
     }
 }
@@ -54,7 +48,7 @@ impl Expression {
     pub fn value(&self) -> Option<u64> {
         match self {
             Expression::Literal(value) => Some(*value),
-            _ => None
+            _ => None,
         }
     }
 
@@ -67,12 +61,12 @@ impl Expression {
                     match op {
                         // equation: c_1 ~ x = c_2
                         //           x = c_2 !~ c_1
-                        Operation::Sum | Operation::Mul =>
-                            right.invert(op.invert().apply(&value, left)),
+                        Operation::Sum | Operation::Mul => {
+                            right.invert(op.invert().apply(&value, left))
+                        }
                         // equation: c_1 ~ x = c_2
                         //           x = c_1 ~ c_2
-                        Operation::Sub | Operation::Div =>
-                            right.invert(op.apply(left, &value))
+                        Operation::Sub | Operation::Div => right.invert(op.apply(left, &value)),
                     }
                 } else {
                     // equation: x ~ c_1 = c_2
@@ -80,7 +74,7 @@ impl Expression {
                     left.invert(op.invert().apply(&value, right))
                 }
             }
-            _ => panic!("")
+            _ => panic!(""),
         }
     }
 }
@@ -94,7 +88,7 @@ impl FromStr for Operation {
             "-" => Ok(Operation::Sub),
             "/" => Ok(Operation::Div),
             "*" => Ok(Operation::Mul),
-            _ => Err(format!("Unknown operation {}", input))
+            _ => Err(format!("Unknown operation {}", input)),
         }
     }
 }
@@ -106,14 +100,10 @@ struct Equations {
 impl Equations {
     pub fn eval(&self, expression: &Expression) -> Expression {
         match expression {
-            Expression::Op(op, expr1, expr2) =>
-                op.apply(&self.eval(expr1), &self.eval(expr2)),
-            Expression::Literal(num) =>
-                Expression::Literal(*num),
-            Expression::Variable(var) =>
-                self.eval(&self.equations[var]),
-            Expression::Unknown(name) =>
-                Expression::Unknown(name.clone()),
+            Expression::Op(op, expr1, expr2) => op.apply(&self.eval(expr1), &self.eval(expr2)),
+            Expression::Literal(num) => Expression::Literal(*num),
+            Expression::Variable(var) => self.eval(&self.equations[var]),
+            Expression::Unknown(name) => Expression::Unknown(name.clone()),
         }
     }
 }
@@ -143,9 +133,7 @@ impl FromStr for Equations {
                 );
             }
         });
-        Ok(Equations {
-            equations: result
-        })
+        Ok(Equations { equations: result })
     }
 }
 
@@ -153,13 +141,15 @@ pub fn part_one(input: &str) -> Option<u64> {
     let equations = Equations::from_str(input).expect("");
     match equations.eval(&Expression::Variable("root".to_string())) {
         Expression::Literal(num) => Some(num),
-        _ => None
+        _ => None,
     }
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
     let mut equations = Equations::from_str(input).expect("");
-    equations.equations.insert("humn".to_string(), Expression::Unknown("humn".to_string()));
+    equations
+        .equations
+        .insert("humn".to_string(), Expression::Unknown("humn".to_string()));
     let root_eq = equations.equations.get("root").unwrap();
     match root_eq {
         Expression::Op(_, left, right) => {
@@ -167,38 +157,31 @@ pub fn part_two(input: &str) -> Option<u64> {
             let value = equations.eval(right);
             left_eq.invert(value).value()
         }
-        _ => panic!("")
+        _ => panic!(""),
     }
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 21, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
-    use std::ptr::eq;
 
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 21, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_one(&input), Some(152));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 21, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_two(&input), Some(301));
     }
 
     #[test]
     fn test_invert() {
-        let mut equations = Equations {
-            equations: Default::default()
+        let _equations = Equations {
+            equations: Default::default(),
         };
         // equation 13 - x == 7 ~ x == 6
         let op_unknown_right = Expression::Op(
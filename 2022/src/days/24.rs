@@ -1,19 +1,12 @@
+advent_of_code::solution!(24);
+
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::{Debug, Formatter};
 use std::str::FromStr;
 
+use advent_of_code::direction::Direction;
 use advent_of_code::helpers::Point2;
 
-use crate::Direction::{East, North, South, West};
-
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-enum Direction {
-    North,
-    South,
-    West,
-    East,
-}
-
 #[derive(Clone)]
 struct Valley {
     blizzards: HashSet<(Point2<i32>, Direction)>,
@@ -25,36 +18,35 @@ struct Valley {
 
 impl Valley {
     pub fn occupied_after(&self, steps: i32) -> HashSet<Point2<i32>> {
-        self.blizzards.iter()
+        self.blizzards
+            .iter()
             .map(|(point, direction)| {
-                let vector = match direction {
-                    North => Point2::new(0, -1),
-                    South => Point2::new(0, 1),
-                    West => Point2::new(-1, 0),
-                    East => Point2::new(1, 0)
-                };
+                let vector = Point2::<i32>::from(*direction);
                 let next_position = Point2::new(
                     (point.x + vector.x * (steps + 1) - 1).rem_euclid(self.width - 2) + 1,
                     (point.y + vector.y * (steps + 1) - 1).rem_euclid(self.height - 2) + 1,
                 );
                 // println!("Moving blizz {:?} at {:?} -> {:?}", direction, point, next_position);
                 next_position
-            }).collect()
+            })
+            .collect()
     }
 
-    pub fn possible_moves<'a>(&'a self, position: &'a Point2<i32>, blizzards: &'a HashSet<Point2<i32>>) -> impl Iterator<Item=Point2<i32>> + '_ {
-        [
-            (1, 0),
-            (0, 1),
-            (0, -1),
-            (-1, 0),
-            (0, 0),
-        ].iter()
+    pub fn possible_moves<'a>(
+        &'a self,
+        position: &'a Point2<i32>,
+        blizzards: &'a HashSet<Point2<i32>>,
+    ) -> impl Iterator<Item = Point2<i32>> + 'a {
+        [(1, 0), (0, 1), (0, -1), (-1, 0), (0, 0)]
+            .iter()
             .map(|(cx, cy)| Point2::new(position.x + cx, position.y + cy))
             .filter(move |candidate| {
-                let end_or_start = *candidate == self.start_position || *candidate == self.end_position;
-                let out_of_bounds = candidate.x <= 0 || candidate.x >= self.width - 1 ||
-                    candidate.y <= 0 || candidate.y >= self.height - 1;
+                let end_or_start =
+                    *candidate == self.start_position || *candidate == self.end_position;
+                let out_of_bounds = candidate.x <= 0
+                    || candidate.x >= self.width - 1
+                    || candidate.y <= 0
+                    || candidate.y >= self.height - 1;
                 let in_blizzard = blizzards.contains(candidate);
                 // println!("Checking position {:?}, {}, {}, {}", candidate, end_or_start, out_of_bounds, in_blizzard);
                 end_or_start || (!out_of_bounds && !in_blizzard)
@@ -83,10 +75,9 @@ impl Valley {
                 max_steps = steps;
             }
 
-            if !blizzard_positions.contains_key(&steps) {
-                blizzard_positions.insert(steps, self.occupied_after(steps));
-                // println!("Blizzards after {} minutes: {:?}", steps, blizzard_positions[&steps]);
-            }
+            blizzard_positions
+                .entry(steps)
+                .or_insert_with(|| self.occupied_after(steps));
             self.possible_moves(&current_position, &blizzard_positions[&steps])
                 .for_each(|next_position| {
                     if !visited.contains(&(next_position, steps + 1)) {
@@ -102,25 +93,31 @@ impl Valley {
 
 impl Debug for Valley {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let repr: String = (0..self.height).flat_map(|y| {
-            (0..self.width + 1).map(move |x| {
-                if x == self.width {
-                    '\n'
-                } else if x == 0 || x == self.width - 1 || y == 0 || y == self.height - 1 {
-                    '#'
-                } else if let Some(dir) = self.blizzards.iter()
-                    .find_map(|(pos, dir)| (pos.x == x && pos.y == y).then_some(dir)) {
-                    match dir {
-                        North => '^',
-                        South => 'v',
-                        West => '<',
-                        East => '>'
+        let repr: String = (0..self.height)
+            .flat_map(|y| {
+                (0..self.width + 1).map(move |x| {
+                    if x == self.width {
+                        '\n'
+                    } else if x == 0 || x == self.width - 1 || y == 0 || y == self.height - 1 {
+                        '#'
+                    } else if let Some(dir) = self
+                        .blizzards
+                        .iter()
+                        .find_map(|(pos, dir)| (pos.x == x && pos.y == y).then_some(dir))
+                    {
+                        match dir {
+                            Direction::North => '^',
+                            Direction::South => 'v',
+                            Direction::West => '<',
+                            Direction::East => '>',
+                            _ => unreachable!("blizzards only blow orthogonally"),
+                        }
+                    } else {
+                        '.'
                     }
-                } else {
-                    '.'
-                }
+                })
             })
-        }).collect();
+            .collect();
         writeln!(f, "{}", repr)
     }
 }
@@ -132,33 +129,35 @@ impl FromStr for Valley {
         let width = input.lines().next().unwrap().len() as i32;
         let height = input.lines().count() as i32;
         let blizzards: HashSet<(Point2<i32>, Direction)> = input
-            .lines().enumerate()
+            .lines()
+            .enumerate()
             .flat_map(|(y, line)| {
-                line
-                    .chars().enumerate()
+                line.chars()
+                    .enumerate()
                     .filter(|(_, c)| *c != '#' && *c != '.')
                     .map(move |(x, c)| {
-                        let direction = match c {
-                            '^' => North,
-                            'v' => South,
-                            '>' => East,
-                            '<' => West,
-                            _ => panic!("Unsupported blizzard {}", c)
-                        };
+                        let direction = Direction::try_from(c)
+                            .unwrap_or_else(|_| panic!("Unsupported blizzard {}", c));
                         (Point2::new(x as i32, y as i32), direction)
                     })
             })
             .collect();
-        let start_position = input.lines().next().unwrap()
-            .chars().enumerate()
-            .find_map(|(x, c)| {
-                (c == '.').then_some(Point2::new(x as i32, 0))
-            }).unwrap();
-        let end_position = input.lines().last().unwrap()
-            .chars().enumerate()
-            .find_map(|(x, c)| {
-                (c == '.').then_some(Point2::new(x as i32, height - 1))
-            }).unwrap();
+        let start_position = input
+            .lines()
+            .next()
+            .unwrap()
+            .chars()
+            .enumerate()
+            .find_map(|(x, c)| (c == '.').then_some(Point2::new(x as i32, 0)))
+            .unwrap();
+        let end_position = input
+            .lines()
+            .last()
+            .unwrap()
+            .chars()
+            .enumerate()
+            .find_map(|(x, c)| (c == '.').then_some(Point2::new(x as i32, height - 1)))
+            .unwrap();
         Ok(Valley {
             blizzards,
             start_position,
@@ -181,40 +180,39 @@ pub fn part_two(input: &str) -> Option<i32> {
     valley.find_path(second_path, false)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 24, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 24, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_one(&input), Some(18));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 24, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_two(&input), Some(54));
     }
 
     #[test]
     fn test_possible_moves() {
-        let input = advent_of_code::read_file("examples", 24, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         let valley = Valley::from_str(&input).expect("");
         let blizzards = valley.occupied_after(1);
-        let possible_moves: Vec<Point2<i32>> = valley.possible_moves(&valley.start_position, &blizzards).collect();
-        assert_eq!(possible_moves, vec![Point2 { x: 1, y: 1 }, Point2 { x: 1, y: 0 }]);
+        let possible_moves: Vec<Point2<i32>> = valley
+            .possible_moves(&valley.start_position, &blizzards)
+            .collect();
+        assert_eq!(
+            possible_moves,
+            vec![Point2 { x: 1, y: 1 }, Point2 { x: 1, y: 0 }]
+        );
     }
 
     #[test]
     fn test_blizzards_after() {
-        let input = advent_of_code::read_file("examples", 24, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         let valley = Valley::from_str(&input).expect("");
         let blizzards = valley.occupied_after(7);
         assert_eq!(blizzards.iter().filter(|p| p.y == 1).count(), 4);
@@ -225,7 +223,7 @@ mod tests {
 
     #[test]
     fn test_example_path_possible() {
-        let input = advent_of_code::read_file("examples", 24, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         let valley = Valley::from_str(&input).expect("");
         let example_path = [
             Point2::new(1, 0),
@@ -243,14 +241,15 @@ mod tests {
             Point2::new(3, 2),
         ];
         example_path
-            .windows(2).enumerate()
+            .windows(2)
+            .enumerate()
             .for_each(|(steps, moves)| {
                 let blizzards = valley.occupied_after(steps as i32);
-                let possible_moves: Vec<Point2<i32>> = valley.possible_moves(&moves[0], &blizzards).collect();
+                let possible_moves: Vec<Point2<i32>> =
+                    valley.possible_moves(&moves[0], &blizzards).collect();
                 // println!("Paths at {:?} from {:?}: {:?}", steps, moves[0], possible_moves);
-                let move_possible = possible_moves.iter()
-                    .any(|pos| *pos == moves[1]);
-                assert_eq!(move_possible, true);
+                let move_possible = possible_moves.iter().any(|pos| *pos == moves[1]);
+                assert!(move_possible);
             });
     }
 }
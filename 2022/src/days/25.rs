@@ -1,3 +1,5 @@
+advent_of_code::solution!(25);
+
 use std::str::FromStr;
 
 #[derive(Debug, Clone)]
@@ -20,7 +22,7 @@ impl Snafu {
         while state > 0 {
             let remainder = state % 5;
             if remainder >= 3 {
-                digits.push( remainder - 5);
+                digits.push(remainder - 5);
                 state += 5 - remainder;
             } else {
                 digits.push(remainder)
@@ -28,20 +30,21 @@ impl Snafu {
             state /= 5;
         }
         digits.reverse();
-        Snafu{
-            digits
-        }
+        Snafu { digits }
     }
 
     fn encode(&self) -> String {
-        self.digits.iter().map(|d| match d {
-            0 => '0',
-            1 => '1',
-            2 => '2',
-            -1 => '-',
-            -2 => '=',
-            _ => unreachable!()
-        }).collect()
+        self.digits
+            .iter()
+            .map(|d| match d {
+                0 => '0',
+                1 => '1',
+                2 => '2',
+                -1 => '-',
+                -2 => '=',
+                _ => unreachable!(),
+            })
+            .collect()
     }
 }
 
@@ -50,16 +53,17 @@ impl FromStr for Snafu {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Snafu {
-            digits: s.chars().map(|c| {
-                match c {
+            digits: s
+                .chars()
+                .map(|c| match c {
                     '0' => 0,
                     '1' => 1,
                     '2' => 2,
                     '-' => -1,
                     '=' => -2,
-                    _ => unreachable!()
-                }
-            }).collect()
+                    _ => unreachable!(),
+                })
+                .collect(),
         })
     }
 }
@@ -67,9 +71,7 @@ impl FromStr for Snafu {
 pub fn part_one(input: &str) -> Option<String> {
     let decimal = input
         .lines()
-        .map(|line| {
-            Snafu::from_str(line).expect("").value()
-        })
+        .map(|line| Snafu::from_str(line).expect("").value())
         .sum();
 
     Some(Snafu::from_decimal(decimal).encode())
@@ -79,32 +81,29 @@ pub fn part_two(_input: &str) -> Option<u32> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 25, None);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 25, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_one(&input), Some(String::from("2=-1=0")));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 25, None);
+        let input = advent_of_code::template::read_file("examples", DAY);
         assert_eq!(part_two(&input), None);
     }
 
     #[test]
     fn test_examples() {
         assert_eq!(Snafu::from_str("1").expect("").value(), 1);
-        assert_eq!(Snafu::from_str("1121-1110-1=0").expect("").value(), 314159265);
+        assert_eq!(
+            Snafu::from_str("1121-1110-1=0").expect("").value(),
+            314159265
+        );
         assert_eq!(Snafu::from_str("1=11-2").expect("").value(), 2022);
         assert_eq!(Snafu::from_str("1-0---0").expect("").value(), 12345);
         assert_eq!(Snafu::from_str("2=-01").expect("").value(), 976);
@@ -3,6 +3,8 @@
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
 
+use aoc_core::direction::Direction;
+use aoc_core::sparse::GridPoint;
 use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -13,91 +15,105 @@ pub struct Point2<T> {
 
 impl<T> Point2<T> {
     pub fn new(x: T, y: T) -> Point2<T> {
-        Point2 {
-            x,
-            y
-        }
+        Point2 { x, y }
+    }
+}
+
+/// The unit step of a direction, see [`Direction::offset`].
+impl<T: From<i8>> From<Direction> for Point2<T> {
+    fn from(direction: Direction) -> Self {
+        let (x, y) = direction.offset();
+        Point2::new(x, y)
+    }
+}
+
+impl GridPoint for Point2<i32> {
+    fn x(&self) -> i64 {
+        self.x as i64
+    }
+
+    fn y(&self) -> i64 {
+        self.y as i64
+    }
+
+    fn from_xy(x: i64, y: i64) -> Self {
+        Point2::new(x as i32, y as i32)
     }
 }
 
-impl<T: Add<Output=T>> Add for Point2<T>{
+impl<T: Add<Output = T>> Add for Point2<T> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self{
+        Self {
             x: self.x + rhs.x,
-            y: self.y + rhs.y
+            y: self.y + rhs.y,
         }
     }
 }
 
-impl<T: AddAssign> AddAssign for Point2<T>{
+impl<T: AddAssign> AddAssign for Point2<T> {
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
         self.y += rhs.y;
     }
 }
 
-impl<T: Sub<Output=T>> Sub for Point2<T>{
-    type Output = Self<>;
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Self{
+        Self {
             x: self.x - rhs.x,
-            y: self.y - rhs.y
+            y: self.y - rhs.y,
         }
     }
 }
 
-impl<T: SubAssign> SubAssign for Point2<T>{
+impl<T: SubAssign> SubAssign for Point2<T> {
     fn sub_assign(&mut self, rhs: Self) {
         self.x -= rhs.x;
         self.y -= rhs.y;
     }
 }
 
-impl<T: Mul<f32, Output=T>> Mul<f32> for Point2<T>{
+impl<T: Mul<f32, Output = T>> Mul<f32> for Point2<T> {
     type Output = Point2<T>;
 
     fn mul(self, rhs: f32) -> Self::Output {
-        Self{
+        Self {
             x: self.x * rhs,
-            y: self.y * rhs
+            y: self.y * rhs,
         }
     }
 }
 
-
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Point3<T> {
     pub x: T,
     pub y: T,
-    pub z: T
+    pub z: T,
 }
 
 impl<T> Point3<T> {
     pub fn new(x: T, y: T, z: T) -> Point3<T> {
-        Point3 {
-            x,
-            y,
-            z
-        }
+        Point3 { x, y, z }
     }
 }
 
-impl<T: Add<Output=T>> Add for Point3<T>{
+impl<T: Add<Output = T>> Add for Point3<T> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self{
+        Self {
             x: self.x + rhs.x,
             y: self.y + rhs.y,
-            z: self.z + rhs.z
+            z: self.z + rhs.z,
         }
     }
 }
 
-impl<T: AddAssign> AddAssign for Point3<T>{
+impl<T: AddAssign> AddAssign for Point3<T> {
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
         self.y += rhs.y;
@@ -105,19 +121,19 @@ impl<T: AddAssign> AddAssign for Point3<T>{
     }
 }
 
-impl<T: Sub<Output=T>> Sub for Point3<T>{
-    type Output = Self<>;
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Self{
+        Self {
             x: self.x - rhs.x,
             y: self.y - rhs.y,
-            z: self.z - rhs.z
+            z: self.z - rhs.z,
         }
     }
 }
 
-impl<T: SubAssign> SubAssign for Point3<T>{
+impl<T: SubAssign> SubAssign for Point3<T> {
     fn sub_assign(&mut self, rhs: Self) {
         self.x -= rhs.x;
         self.y -= rhs.y;
@@ -125,14 +141,14 @@ impl<T: SubAssign> SubAssign for Point3<T>{
     }
 }
 
-impl<T: Mul<f32, Output=T>> Mul<f32> for Point3<T>{
+impl<T: Mul<f32, Output = T>> Mul<f32> for Point3<T> {
     type Output = Point3<T>;
 
     fn mul(self, rhs: f32) -> Self::Output {
-        Self{
+        Self {
             x: self.x * rhs,
             y: self.y * rhs,
-            z: self.z * rhs
+            z: self.z * rhs,
         }
     }
 }
@@ -129,7 +129,7 @@ struct GearNumbers {
     numbers: Vec<(Number, Point2<usize>)>,
 }
 
-impl From<&Schematic> for crate::GearNumbers {
+impl From<&Schematic> for GearNumbers {
     fn from(schematic: &Schematic) -> Self {
         let mut numbers = Vec::new();
         for y in 0..schematic.height {
@@ -161,7 +161,7 @@ impl From<&Schematic> for crate::GearNumbers {
                 }
             }
         }
-        crate::GearNumbers { numbers }
+        GearNumbers { numbers }
     }
 }
 
@@ -184,20 +184,3 @@ pub fn part_two(input: &str) -> Option<u32> {
         .sum();
     Some(result)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4361));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(467835));
-    }
-}
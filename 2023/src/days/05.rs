@@ -56,7 +56,7 @@ struct Map {
 impl From<&str> for Map {
     fn from(value: &str) -> Self {
         let mut parsed_section: Vec<Mapping> = value.lines().skip(1).map(Mapping::from).collect();
-        parsed_section.sort_by(|a, b| a.range.start.cmp(&b.range.start));
+        parsed_section.sort_by_key(|a| a.range.start);
         Map {
             mapping: parsed_section,
         }
@@ -199,18 +199,6 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(35));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(46));
-    }
-
     #[test]
     fn test_overlap_range() {
         //overlap at range start
@@ -17,7 +17,7 @@ impl From<&str> for Races {
             })
             .collect();
         let races = data
-            .get(0)
+            .first()
             .unwrap()
             .iter()
             .zip(data.get(1).unwrap())
@@ -65,20 +65,3 @@ pub fn part_two(input: &str) -> Option<u64> {
     let race = races.races.first().unwrap();
     Some(race.winning_button_times())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(288));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(71503));
-    }
-}
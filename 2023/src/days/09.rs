@@ -21,11 +21,11 @@ impl OasisReading {
     }
 
     fn predict_previous(&self) -> i32 {
-        let readings = self.readings.iter().copied().rev().collect();
+        let readings: Vec<i32> = self.readings.iter().copied().rev().collect();
         OasisReading::_predict(&readings)
     }
 
-    fn _predict(seq: &Vec<i32>) -> i32 {
+    fn _predict(seq: &[i32]) -> i32 {
         if !seq.iter().any(|x| *x != 0) {
             0
         } else {
@@ -63,18 +63,6 @@ pub fn part_two(input: &str) -> Option<i32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(114));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2));
-    }
-
     #[test]
     fn test_example() {
         let readings = OasisReading::from("10  13  16  21  30  45");
@@ -129,11 +129,7 @@ impl Pipes {
                 .next(position)
                 .iter()
                 .find(|connection| {
-                    !visited.contains(connection)
-                        && self
-                            .next(**connection)
-                            .iter()
-                            .any(|connection| *connection == position)
+                    !visited.contains(connection) && self.next(**connection).contains(&position)
                 })
                 .cloned();
             if let Some(next_position) = next_position_o {
@@ -197,22 +193,3 @@ pub fn part_two(input: &str) -> Option<u32> {
     let result = pipes.cycle_area();
     Some(result)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(8));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(4));
-    }
-}
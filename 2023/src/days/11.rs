@@ -89,12 +89,6 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(374));
-    }
-
     #[test]
     fn test_part_two_simpler() {
         let map = GalaxyMap::new(&advent_of_code::template::read_file("examples", DAY));
@@ -118,24 +118,12 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(21));
-    }
-
     #[test]
     fn test_part_one_simple_schematic() {
         let schematic = SpringCondition::from("???.### 1,1,3");
         assert_eq!(schematic.combinations(), 1);
     }
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(525152));
-    }
-
     #[test]
     fn test_part_two_simple_schematic() {
         let schematic = SpringCondition::from(".??..??...?##. 1,1,3").expand(5);
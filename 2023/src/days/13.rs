@@ -58,7 +58,7 @@ impl Mirrors {
         )
     }
 
-    fn find_mirror_point(numbers: &Vec<u32>, error_count: usize) -> Option<usize> {
+    fn find_mirror_point(numbers: &[u32], error_count: usize) -> Option<usize> {
         (0..numbers.len()).find(|mirror_point| {
             let count = (numbers.len() - mirror_point).min(*mirror_point);
             let errors = (1..=count)
@@ -102,20 +102,3 @@ pub fn part_two(input: &str) -> Option<usize> {
         .sum::<usize>();
     Some(mirrors)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(405));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(400));
-    }
-}
@@ -101,10 +101,8 @@ impl ReflectorDish {
                         self.data[current_bottom][x] = Tile::Rock;
                         current_bottom = current_bottom.saturating_sub(1);
                     }
-                    Tile::Block => {
-                        if y > 0 {
-                            current_bottom = y - 1
-                        };
+                    Tile::Block if y > 0 => {
+                        current_bottom = y - 1;
                     }
                     _ => {}
                 }
@@ -141,10 +139,8 @@ impl ReflectorDish {
                         self.data[y][current_right] = Tile::Rock;
                         current_right = current_right.saturating_sub(1);
                     }
-                    Tile::Block => {
-                        if x > 0 {
-                            current_right = x - 1
-                        };
+                    Tile::Block if x > 0 => {
+                        current_right = x - 1;
                     }
                     _ => {}
                 }
@@ -158,8 +154,7 @@ impl ReflectorDish {
             .enumerate()
             .map(|(y, row)| {
                 row.iter()
-                    .enumerate()
-                    .map(|(_, tile)| match tile {
+                    .map(|tile| match tile {
                         Tile::Empty => 0,
                         Tile::Block => 0,
                         Tile::Rock => self.height - y,
@@ -205,18 +200,6 @@ pub fn part_two(input: &str) -> Option<u32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(136));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(64));
-    }
-
     #[test]
     fn test_part_two_first_cycles() {
         let input = advent_of_code::template::read_file("examples", DAY);
@@ -133,20 +133,3 @@ pub fn part_two(input: &str) -> Option<u32> {
     lens_slots.initialize(&initialization_sequence);
     Some(lens_slots.power())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1320));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(145));
-    }
-}
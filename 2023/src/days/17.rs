@@ -1,3 +1,4 @@
+use advent_of_code::direction::Direction;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 advent_of_code::solution!(17);
@@ -8,37 +9,19 @@ struct CityBlocks {
     height: usize,
 }
 
-#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
-enum Direction {
-    Up = 0,
-    Down = 1,
-    Left = 2,
-    Right = 3,
-}
-
-impl Direction {
-    fn move_by(
-        self,
-        x: usize,
-        y: usize,
-        distance: usize,
-        width: usize,
-        height: usize,
-    ) -> Option<(usize, usize)> {
-        match self {
-            Direction::Up => y.checked_sub(distance).map(|ny| (x, ny)),
-            Direction::Down => (y + distance < height).then_some((x, y + distance)),
-            Direction::Left => x.checked_sub(distance).map(|nx| (nx, y)),
-            Direction::Right => (x + distance < width).then_some((x + distance, y)),
-        }
-    }
-
-    fn next(self) -> [Self; 2] {
-        match self {
-            Direction::Up | Direction::Down => [Direction::Left, Direction::Right],
-            Direction::Left | Direction::Right => [Direction::Up, Direction::Down],
-        }
-    }
+/// The cell `distance` steps from `(x, y)` in `direction`, if it is still inside the city.
+fn move_by(
+    direction: Direction,
+    x: usize,
+    y: usize,
+    distance: usize,
+    width: usize,
+    height: usize,
+) -> Option<(usize, usize)> {
+    let (dx, dy): (isize, isize) = direction.offset();
+    let x = x.checked_add_signed(dx * distance as isize)?;
+    let y = y.checked_add_signed(dy * distance as isize)?;
+    (x < width && y < height).then_some((x, y))
 }
 
 impl From<&str> for CityBlocks {
@@ -67,7 +50,7 @@ struct State {
 
 impl PartialOrd<Self> for State {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(other.heat.cmp(&self.heat))
+        Some(self.cmp(other))
     }
 }
 
@@ -88,14 +71,23 @@ impl State {
     }
 }
 
+fn cardinal_slot(direction: Direction) -> usize {
+    Direction::CARDINALS
+        .iter()
+        .position(|&cardinal| cardinal == direction)
+        .expect("The crucible only moves along the cardinals")
+}
+
 impl CityBlocks {
     fn best_path(&self, min_moves: usize, max_moves: usize) -> u32 {
         let mut frontier: BinaryHeap<State> = BinaryHeap::new();
-        frontier.push(State::new(0, 0, 0, Direction::Right));
-        frontier.push(State::new(0, 0, 0, Direction::Down));
-        let mut best_paths = vec![vec![vec![u32::MAX; 4]; self.width]; self.height];
-        best_paths[0][0][Direction::Right as usize] = 0;
-        best_paths[0][0][Direction::Down as usize] = 0;
+        frontier.push(State::new(0, 0, 0, Direction::East));
+        frontier.push(State::new(0, 0, 0, Direction::South));
+        // the crucible only moves along the cardinals, so one slot for each of them
+        let mut best_paths =
+            vec![vec![vec![u32::MAX; Direction::CARDINALS.len()]; self.width]; self.height];
+        best_paths[0][0][cardinal_slot(Direction::East)] = 0;
+        best_paths[0][0][cardinal_slot(Direction::South)] = 0;
         while let Some(State {
             heat: _,
             x,
@@ -103,27 +95,29 @@ impl CityBlocks {
             previous_direction,
         }) = frontier.pop()
         {
-            let heat = best_paths[y][x][previous_direction as usize];
+            let heat = best_paths[y][x][cardinal_slot(previous_direction)];
             if x == (self.width - 1) && y == (self.height - 1) {
                 return heat;
             }
-            let next_directions = previous_direction.next();
+            let next_directions = [
+                previous_direction.rotate_counterclockwise(),
+                previous_direction.rotate_clockwise(),
+            ];
             for next_direction in next_directions.iter() {
                 for distance in min_moves..=max_moves {
                     let Some((next_x, next_y)) =
-                        next_direction.move_by(x, y, distance, self.width, self.height)
+                        move_by(*next_direction, x, y, distance, self.width, self.height)
                     else {
                         continue;
                     };
                     let mut move_heat = 0;
                     for i in 1..=distance {
-                        let (move_x, move_y) = next_direction
-                            .move_by(x, y, i, self.width, self.height)
-                            .unwrap();
+                        let (move_x, move_y) =
+                            move_by(*next_direction, x, y, i, self.width, self.height).unwrap();
                         move_heat += self.blocks[move_y][move_x];
                     }
                     let next_heat = heat + move_heat;
-                    let direction_index = *next_direction as usize;
+                    let direction_index = cardinal_slot(*next_direction);
                     if next_heat >= best_paths[next_y][next_x][direction_index] {
                         continue;
                     }
@@ -145,20 +139,3 @@ pub fn part_two(input: &str) -> Option<u32> {
     let city_blocks = CityBlocks::from(input);
     Some(city_blocks.best_path(4, 10))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(102));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(94));
-    }
-}
@@ -1,6 +1,6 @@
+use advent_of_code::direction::Direction;
 use advent_of_code::helpers::Point2;
 use std::marker::PhantomData;
-use std::usize;
 advent_of_code::solution!(18);
 
 #[derive(Debug)]
@@ -8,14 +8,6 @@ struct Part1 {}
 #[derive(Debug)]
 struct Part2 {}
 
-#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
-enum Direction {
-    Up = 0,
-    Down = 1,
-    Left = 2,
-    Right = 3,
-}
-
 #[derive(Debug)]
 struct Instruction<Part> {
     direction: Direction,
@@ -26,13 +18,7 @@ struct Instruction<Part> {
 impl From<&str> for Instruction<Part1> {
     fn from(value: &str) -> Self {
         let mut parts = value.split_whitespace();
-        let direction = match parts.next().unwrap() {
-            "U" => Direction::Up,
-            "D" => Direction::Down,
-            "L" => Direction::Left,
-            "R" => Direction::Right,
-            _ => panic!("Invalid direction"),
-        };
+        let direction = parts.next().unwrap().parse().expect("Invalid direction");
         let distance = parts.next().unwrap().parse::<usize>().unwrap();
         Self {
             direction,
@@ -51,10 +37,10 @@ impl From<&str> for Instruction<Part2> {
         let distance_hex = hex[0..5].to_string();
         let distance = usize::from_str_radix(&distance_hex, 16).unwrap();
         let direction = match hex.chars().last().unwrap() {
-            '0' => Direction::Right,
-            '1' => Direction::Down,
-            '2' => Direction::Left,
-            '3' => Direction::Up,
+            '0' => Direction::East,
+            '1' => Direction::South,
+            '2' => Direction::West,
+            '3' => Direction::North,
             _ => panic!("Invalid direction"),
         };
         Self {
@@ -76,12 +62,9 @@ impl<Part> From<&Vec<Instruction<Part>>> for CityBlocks {
         let mut perimeter = Vec::new();
         for instruction in value {
             perimeter.push(position);
-            match instruction.direction {
-                Direction::Up => position.y -= instruction.distance as i64,
-                Direction::Down => position.y += instruction.distance as i64,
-                Direction::Left => position.x -= instruction.distance as i64,
-                Direction::Right => position.x += instruction.distance as i64,
-            }
+            let (x, y): (i64, i64) = instruction.direction.offset();
+            position.x += x * instruction.distance as i64;
+            position.y += y * instruction.distance as i64;
         }
         perimeter.push(position);
         Self { perimeter }
@@ -114,20 +97,3 @@ pub fn part_two(input: &str) -> Option<usize> {
     let city_blocks = CityBlocks::from(&instructions);
     Some(city_blocks.area())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(62));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(952408144115));
-    }
-}
@@ -246,30 +246,3 @@ pub fn part_two(input: &str) -> Option<u64> {
     let result = cycles.iter().fold(cycles[0], |acc, &cycle| lcm(acc, cycle));
     Some(result)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(32000000));
-    }
-
-    #[test]
-    fn test_part_one_part_1() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(11687500));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(1));
-    }
-}
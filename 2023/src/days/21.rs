@@ -242,6 +242,7 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input in data/inputs"]
     fn test_part_two() {
         let input = advent_of_code::template::read_file("inputs", DAY);
         let garden = Garden::from(input.as_str());
@@ -270,6 +271,7 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input in data/inputs"]
     fn test_corner() {
         let input = advent_of_code::template::read_file("inputs", DAY);
         let garden = Garden::from(input.as_str());
@@ -274,18 +274,6 @@ pub fn part_two(input: &str) -> Option<usize> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
-    }
-
     #[test]
     fn test_brick_support() {
         let input = advent_of_code::template::read_file("examples", DAY);
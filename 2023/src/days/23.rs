@@ -1,8 +1,5 @@
 use advent_of_code::helpers::Point2;
-use pathfinding::prelude::bfs;
-use petgraph::algo::all_simple_paths;
-use petgraph::{Directed, Graph};
-use std::collections::{HashMap, HashSet};
+use advent_of_code::maze::PoiGraph;
 advent_of_code::solution!(23);
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -112,65 +109,11 @@ impl IslandMap {
     }
 
     fn longest_path(&self, start: Point2<usize>, end: Point2<usize>) -> isize {
-        let mut graph: Graph<Point2<usize>, isize, Directed> = Graph::new();
-        let mut nodes = Vec::new();
-        let mut node_pointers = HashMap::new();
-        for x in 0..self.width {
-            for y in 0..self.height {
-                let p = Point2::new(x, y);
-                let tile = self.map[p.y][p.x];
-                if tile != Tile::Forest && (p == start || p == end || self.successors(&p).len() > 2)
-                {
-                    nodes.push(p);
-                    node_pointers.insert(p, graph.add_node(p));
-                }
-            }
-        }
-        for &node in nodes.iter() {
-            let mut visited = HashSet::new();
-            // find n next edges
-            for _ in 0..1.max(self.successors(&node).len()) {
-                let path = bfs(
-                    &node,
-                    |neigh| {
-                        if visited.contains(neigh) {
-                            vec![]
-                        } else {
-                            self.successors(neigh)
-                        }
-                    },
-                    |&neigh| {
-                        neigh != node
-                            && node_pointers.contains_key(&neigh)
-                            && !visited.contains(&neigh)
-                    },
-                );
-                if let Some(path) = path {
-                    // connect start and end in graph
-                    let last_node = path.last().cloned().unwrap();
-                    visited.insert(last_node);
-                    graph.add_edge(
-                        *node_pointers.get(&node).unwrap(),
-                        *node_pointers.get(&last_node).unwrap(),
-                        path.len() as isize - 1,
-                    );
-                }
-            }
-        }
-        all_simple_paths::<Vec<_>, _>(&graph, node_pointers[&start], node_pointers[&end], 0, None)
-            .map(|path| {
-                path.windows(2)
-                    .map(|window| {
-                        graph
-                            .edges_connecting(window[0], window[1])
-                            .next()
-                            .unwrap()
-                            .weight()
-                    })
-                    .sum::<isize>()
-            })
-            .max()
-            .unwrap()
+        let junctions = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| Point2::new(x, y)))
+            .filter(|p| self.map[p.y][p.x] != Tile::Forest && self.successors(p).len() > 2);
+        let graph = PoiGraph::new(junctions.chain([start, end]), |p| self.successors(p));
+        graph.longest_path(&start, &end).unwrap() as isize
     }
 }
 
@@ -192,12 +135,6 @@ pub fn part_two(input: &str) -> Option<isize> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(94));
-    }
-
     #[test]
     fn test_successors() {
         let input = advent_of_code::template::read_file("examples", DAY);
@@ -206,10 +143,4 @@ mod tests {
         assert_eq!(map.successors(&Point2::new(1, 1)).len(), 2);
         assert_eq!(map.successors(&Point2::new(3, 5)).len(), 3);
     }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(154));
-    }
 }
@@ -1,8 +1,32 @@
 use advent_of_code::helpers::{Point2, Point3};
+use advent_of_code::template::examples::Example;
+use advent_of_code::template::params::PuzzleParams;
 use num::FromPrimitive;
 use z3::ast::{Ast, Int};
 use z3::{Config, Context, SatResult, Solver};
-advent_of_code::solution!(24);
+advent_of_code::solution!(24, TestArea);
+
+/// Bounds of the test area in which part one looks for intersections, on both the X and the Y axis.
+pub struct TestArea {
+    min: i64,
+    max: i64,
+}
+
+impl PuzzleParams for TestArea {
+    fn real() -> Self {
+        TestArea {
+            min: 200000000000000,
+            max: 400000000000000,
+        }
+    }
+
+    fn example(example: &Example) -> Self {
+        TestArea {
+            min: example.param("min").unwrap_or(7),
+            max: example.param("max").unwrap_or(27),
+        }
+    }
+}
 
 #[derive(Debug, Copy, Clone)]
 struct Hailstone {
@@ -125,12 +149,12 @@ fn find_rock(hailstones: &[Hailstone]) -> i64 {
     x + y + z
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
+pub fn part_one(input: &str, area: &TestArea) -> Option<usize> {
     let hailstones = input.lines().map(Hailstone::from).collect::<Vec<_>>();
-    Some(intersections(&hailstones, 200000000000000, 400000000000000))
+    Some(intersections(&hailstones, area.min, area.max))
 }
 
-pub fn part_two(input: &str) -> Option<i64> {
+pub fn part_two(input: &str, _: &TestArea) -> Option<i64> {
     let hailstones = input.lines().map(Hailstone::from).collect::<Vec<_>>();
     Some(find_rock(&hailstones))
 }
@@ -139,14 +163,6 @@ pub fn part_two(input: &str) -> Option<i64> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::template::read_file("examples", DAY);
-        let hailstones = input.lines().map(Hailstone::from).collect::<Vec<_>>();
-        let result = intersections(&hailstones, 7, 24);
-        assert_eq!(result, 2);
-    }
-
     #[test]
     fn test_line_equation() {
         let mut hailstone = Hailstone::from("19, 13, 30 @ -2, 1, -2");
@@ -179,10 +195,4 @@ mod tests {
         };
         assert!(!time);
     }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(47));
-    }
 }
@@ -0,0 +1,48 @@
+use advent_of_code::helpers::{Direction, Grid, Point2};
+advent_of_code::solution!(4);
+
+fn match_xmas(grid: &Grid<char>, position: Point2<i32>, direction: Direction, data: &str) -> bool {
+    grid.ray(position, direction)
+        .map(|(_, c)| *c)
+        .take(data.len())
+        .eq(data.chars())
+}
+
+fn match_crossmas(grid: &Grid<char>, position: Point2<i32>) -> bool {
+    if grid.get(position) != Some(&'A') {
+        return false;
+    }
+    let corner = |direction| grid.get(position.neighbor(direction)).copied();
+    let diagonal = |from, to| {
+        matches!(
+            (corner(from), corner(to)),
+            (Some('M'), Some('S')) | (Some('S'), Some('M'))
+        )
+    };
+    diagonal(Direction::NorthWest, Direction::SouthEast)
+        && diagonal(Direction::NorthEast, Direction::SouthWest)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = Grid::from(input);
+    let search_string = "XMAS";
+    let count = grid
+        .points()
+        .map(|position| {
+            Direction::ALL
+                .iter()
+                .filter(|&&direction| match_xmas(&grid, position, direction, search_string))
+                .count()
+        })
+        .sum::<usize>();
+    Some(count as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = Grid::from(input);
+    let count = grid
+        .points()
+        .filter(|&position| match_crossmas(&grid, position))
+        .count();
+    Some(count as u32)
+}
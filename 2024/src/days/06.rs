@@ -0,0 +1,85 @@
+use advent_of_code::helpers::{Direction, Grid, Point2};
+use std::collections::HashSet;
+advent_of_code::solution!(6);
+
+#[derive(Debug, Clone)]
+struct Guard {
+    position: Point2<i32>,
+    direction: Direction,
+}
+
+#[derive(Debug)]
+struct Puzzle {
+    /// `true` where there is an obstacle.
+    grid: Grid<bool>,
+    guard: Guard,
+}
+
+impl From<&str> for Puzzle {
+    fn from(input: &str) -> Self {
+        let map = Grid::from(input);
+        let guard = Guard {
+            position: map.position(&'^').unwrap(),
+            direction: Direction::North,
+        };
+        let grid = map.map(|&c| c == '#');
+        Puzzle { grid, guard }
+    }
+}
+
+impl Guard {
+    fn patrol(
+        &self,
+        grid: &Grid<bool>,
+        extra_obstacle: Option<Point2<i32>>,
+    ) -> Option<HashSet<Point2<i32>>> {
+        let mut seen_states = HashSet::new();
+        let mut path = Vec::new();
+        let mut position = self.position;
+        let mut direction = self.direction;
+        path.push(position);
+        loop {
+            let next = position.neighbor(direction);
+            match grid.get(next) {
+                None => return Some(path.into_iter().collect()),
+                Some(&obstacle) if obstacle || extra_obstacle == Some(next) => {
+                    if !seen_states.insert((next, direction)) {
+                        return None;
+                    }
+                    direction = direction.rotate_clockwise();
+                }
+                Some(_) => {
+                    path.push(next);
+                    position = next;
+                }
+            }
+        }
+    }
+
+    fn possible_obstructions(&self, grid: &Grid<bool>) -> HashSet<Point2<i32>> {
+        let mut obstruction_candidates = self.patrol(grid, None).unwrap();
+        obstruction_candidates.remove(&self.position);
+
+        let mut found_obstructions = HashSet::new();
+        // iterate over candidates
+        for candidate in obstruction_candidates {
+            let patrol = self.patrol(grid, Some(candidate));
+            if patrol.is_none() {
+                found_obstructions.insert(candidate);
+            }
+        }
+        found_obstructions
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let puzzle = Puzzle::from(input);
+    let patrol = puzzle.guard.patrol(&puzzle.grid, None).unwrap();
+    Some(patrol.len() as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let puzzle = Puzzle::from(input);
+    let obstructions = puzzle.guard.possible_obstructions(&puzzle.grid);
+    Some(obstructions.len() as u32)
+}
@@ -53,8 +53,7 @@ impl Equation {
         } else {
             vec![Operator::Add, Operator::Multiply]
         };
-        std::iter::repeat(operations)
-            .take(self.values.len() - 1)
+        std::iter::repeat_n(operations, self.values.len() - 1)
             .multi_cartesian_product()
             .any(|operations| self.evaluate(&operations))
     }
@@ -84,25 +83,13 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3749));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(11387));
-    }
-
     #[test]
     fn test_156() {
         let equation = Equation {
             test_value: 156,
             values: vec![15, 6],
         };
-        assert_eq!(equation.is_possible(true), true);
+        assert!(equation.is_possible(true));
     }
 
     #[test]
@@ -111,7 +98,7 @@ mod tests {
             test_value: 7290,
             values: vec![6, 8, 6, 15],
         };
-        assert_eq!(equation.is_possible(true), true);
+        assert!(equation.is_possible(true));
     }
 
     #[test]
@@ -120,6 +107,6 @@ mod tests {
             test_value: 192,
             values: vec![17, 8, 14],
         };
-        assert_eq!(equation.is_possible(true), true);
+        assert!(equation.is_possible(true));
     }
 }
@@ -0,0 +1,81 @@
+use advent_of_code::helpers::{Grid, Point2};
+use std::collections::{HashSet, VecDeque};
+advent_of_code::solution!(10);
+
+#[derive(Debug)]
+struct TopographicMap {
+    map: Grid<u8>,
+}
+
+impl From<&str> for TopographicMap {
+    fn from(value: &str) -> Self {
+        let map = Grid::parse(value, |c| c.to_digit(10).unwrap() as u8);
+        TopographicMap { map }
+    }
+}
+
+impl TopographicMap {
+    /// Every position reachable by a gradual, uphill hike from `position`, once for every distinct trail.
+    fn trails(&self, position: Point2<i32>) -> impl Iterator<Item = Point2<i32>> + '_ {
+        let mut frontier = VecDeque::from([position]);
+        std::iter::from_fn(move || {
+            let next = frontier.pop_front()?;
+            let height = self.map[next];
+            frontier.extend(
+                self.map
+                    .neighbors(next)
+                    .filter(|(_, &neighbor_height)| neighbor_height == height + 1)
+                    .map(|(neighbor, _)| neighbor),
+            );
+            Some(next)
+        })
+    }
+
+    fn trailhead_score(&self, position: Point2<i32>) -> usize {
+        self.trails(position)
+            .filter(|&point| self.map[point] == 9)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    fn trailhead_rating(&self, position: Point2<i32>) -> usize {
+        self.trails(position)
+            .filter(|&point| self.map[point] == 9)
+            .count()
+    }
+
+    fn trailheads(&self) -> impl Iterator<Item = Point2<i32>> + '_ {
+        self.map.find_all(|&height| height == 0)
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let map = TopographicMap::from(input);
+    let score: usize = map
+        .trailheads()
+        .map(|trailhead| map.trailhead_score(trailhead))
+        .sum();
+    Some(score as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let map = TopographicMap::from(input);
+    let score: usize = map
+        .trailheads()
+        .map(|trailhead| map.trailhead_rating(trailhead))
+        .sum();
+    Some(score as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trailheads() {
+        let input: &str = &advent_of_code::template::read_file("examples", DAY);
+        let map = TopographicMap::from(input);
+        let score = map.trailhead_score(Point2::new(2, 0));
+        assert_eq!(score, 5);
+    }
+}
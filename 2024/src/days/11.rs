@@ -32,7 +32,7 @@ impl Input {
         let mut next = Vec::new();
         if stone == 0 {
             next.push(1);
-        } else if stone.to_string().len() % 2 == 0 {
+        } else if stone.to_string().len().is_multiple_of(2) {
             let s = stone.to_string();
             let half = s.len() / 2;
             let left = s[..half].parse().unwrap();
@@ -1,103 +1,50 @@
-use advent_of_code::helpers::{Direction, Point2};
+use advent_of_code::helpers::{Direction, Grid, Point2};
 use std::collections::{HashSet, VecDeque};
-use std::fmt::Display;
 advent_of_code::solution!(12);
 
-#[derive(Debug)]
-struct Grid {
-    data: Vec<Vec<char>>,
-    width: usize,
-    height: usize,
-}
-
-impl From<&str> for Grid {
-    fn from(s: &str) -> Self {
-        let data: Vec<Vec<char>> = s.lines().map(|line| line.chars().collect()).collect();
-        let width = data[0].len();
-        let height = data.len();
-        Self {
-            data,
-            width,
-            height,
-        }
-    }
-}
-
-impl Display for Grid {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in &self.data {
-            for c in row {
-                write!(f, "{}", c)?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
-    }
-}
-
 struct Plot {
     coordinates: HashSet<Point2<i32>>,
     perimeter: HashSet<Point2<i32>>,
 }
 
-impl Grid {
-    fn contains(&self, point: Point2<i32>) -> bool {
-        point.x >= 0 && point.x < self.width as i32 && point.y >= 0 && point.y < self.height as i32
-    }
-
-    fn get(&self, point2: Point2<i32>) -> Option<char> {
-        if self.contains(point2) {
-            Some(self.data[point2.y as usize][point2.x as usize])
-        } else {
-            None
+fn plots(grid: &Grid<char>) -> Vec<Plot> {
+    let mut plots = Vec::new();
+    let mut visited = HashSet::new();
+    for (current, &current_value) in grid.iter() {
+        if visited.contains(&current) {
+            continue;
         }
-    }
+        let mut stack = VecDeque::new();
+        stack.push_back(current);
+        let mut field = HashSet::new();
+        let mut perimeter_points = HashSet::new();
+        while let Some(point) = stack.pop_front() {
+            if visited.contains(&point) {
+                continue;
+            }
+            visited.insert(point);
+            field.insert(point);
 
-    fn plots(&self) -> Vec<Plot> {
-        let mut plots = Vec::new();
-        let mut visited = HashSet::new();
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let current = Point2::new(x as i32, y as i32);
-                let current_value = self.get(current).unwrap();
-                if visited.contains(&current) {
-                    continue;
-                }
-                let mut stack = VecDeque::new();
-                stack.push_back(current);
-                let mut field = HashSet::new();
-                let mut perimeter_points = HashSet::new();
-                while let Some(point) = stack.pop_front() {
-                    if visited.contains(&point) {
-                        continue;
-                    }
-                    visited.insert(point);
-                    field.insert(point);
-
-                    point
-                        .neighbors()
-                        .iter()
-                        .filter(|&&neighbor| self.get(neighbor) == Some(current_value))
-                        .for_each(|neighbor| {
-                            stack.push_back(*neighbor);
-                        });
-
-                    let at_perimeter = point
-                        .neighbors_with_diagonal()
-                        .iter()
-                        .any(|&neighbor| self.get(neighbor) != Some(current_value));
-                    if at_perimeter {
-                        perimeter_points.insert(point);
-                    }
-                }
-                plots.push(Plot {
-                    coordinates: field,
-                    perimeter: perimeter_points.clone(),
+            grid.neighbors(point)
+                .filter(|(_, &value)| value == current_value)
+                .for_each(|(neighbor, _)| {
+                    stack.push_back(neighbor);
                 });
+
+            let at_perimeter = point
+                .neighbors_with_diagonal()
+                .iter()
+                .any(|&neighbor| grid.get(neighbor) != Some(&current_value));
+            if at_perimeter {
+                perimeter_points.insert(point);
             }
         }
-        plots
+        plots.push(Plot {
+            coordinates: field,
+            perimeter: perimeter_points.clone(),
+        });
     }
+    plots
 }
 
 impl Plot {
@@ -176,7 +123,7 @@ impl Plot {
 
 pub fn part_one(input: &str) -> Option<usize> {
     let grid = Grid::from(input);
-    let plots = grid.plots();
+    let plots = plots(&grid);
     let mut total_price = 0;
     for plot in plots {
         total_price += plot.area() * plot.perimeter();
@@ -186,7 +133,7 @@ pub fn part_one(input: &str) -> Option<usize> {
 
 pub fn part_two(input: &str) -> Option<usize> {
     let grid = Grid::from(input);
-    let plots = grid.plots();
+    let plots = plots(&grid);
     let mut total_cost = 0;
     for plot in plots {
         let corners = plot.perimeter_corners();
@@ -199,18 +146,6 @@ pub fn part_two(input: &str) -> Option<usize> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1930));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1206));
-    }
-
     #[test]
     fn test_part_two_simple() {
         let result = part_two("AAAA\nBBCD\nBBCC\nEEEC");
@@ -1,7 +1,31 @@
 use advent_of_code::helpers::Point2;
+use advent_of_code::template::examples::Example;
+use advent_of_code::template::params::PuzzleParams;
 use std::collections::HashSet;
 use std::fmt::Display;
-advent_of_code::solution!(14);
+advent_of_code::solution!(14, Room);
+
+/// Size of the room the robots move in.
+pub struct Room {
+    width: i32,
+    height: i32,
+}
+
+impl PuzzleParams for Room {
+    fn real() -> Self {
+        Room {
+            width: 101,
+            height: 103,
+        }
+    }
+
+    fn example(example: &Example) -> Self {
+        Room {
+            width: example.param("width").unwrap_or(11),
+            height: example.param("height").unwrap_or(7),
+        }
+    }
+}
 
 struct Robot {
     position: Point2<i32>,
@@ -14,8 +38,8 @@ struct Input {
     height: i32,
 }
 
-impl From<&str> for Input {
-    fn from(input: &str) -> Self {
+impl Input {
+    fn new(input: &str, room: &Room) -> Self {
         let regex = regex::Regex::new(r"p=([-\d]+),([-\d]+)\s+v=([-\d]+),([-\d]+)").unwrap();
         let robots = regex
             .captures_iter(input)
@@ -26,8 +50,8 @@ impl From<&str> for Input {
             .collect();
         Input {
             robots,
-            width: 101,
-            height: 103,
+            width: room.width,
+            height: room.height,
         }
     }
 }
@@ -100,21 +124,33 @@ impl Input {
             .collect::<HashSet<_>>();
         positions.len() == self.robots.len()
     }
+
+    #[cfg(test)]
+    fn symmetric(&self) -> bool {
+        let positions = self
+            .robots
+            .iter()
+            .map(|robot| robot.position)
+            .collect::<HashSet<_>>();
+        positions.iter().all(|position| {
+            positions.contains(&Point2::new(self.width - 1 - position.x, position.y))
+        })
+    }
 }
 
-pub fn part_one(input: &str) -> Option<i32> {
-    let robots = Input::from(input);
+pub fn part_one(input: &str, room: &Room) -> Option<i32> {
+    let robots = Input::new(input, room);
     let moved_robots = robots.move_seconds(100);
     let quadrants = moved_robots.count_quadrants();
     Some(quadrants.iter().product())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str, room: &Room) -> Option<u32> {
     // couple of heuristics tried
     // 1. checking if the image is symetric
     // 2. checking some statistical properties of coordinates
     // ^^ both of these did not work, simple unique position check worked
-    let mut robots = Input::from(input);
+    let mut robots = Input::new(input, room);
     let mut seconds = 0;
     loop {
         robots = robots.move_seconds(1);
@@ -134,9 +170,12 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input: &str = &advent_of_code::template::read_file("examples", DAY);
-        let mut robots = Input::from(input);
+        let room = Room {
+            width: 11,
+            height: 7,
+        };
+        let robots = Input::new(input, &room);
         assert_eq!(robots.robots.len(), 12);
-        (robots.width, robots.height) = (11, 7);
         let moved_robots = robots.move_seconds(100);
         println!("{}", moved_robots);
         let quadrants = moved_robots.count_quadrants();
@@ -1,10 +1,10 @@
-use advent_of_code::helpers::Point2;
+use advent_of_code::helpers::{Direction, Grid, Point2};
 use std::fmt::{Display, Formatter};
 advent_of_code::solution!(15);
 
 #[derive(Debug)]
 struct Warehouse {
-    grid: Vec<Vec<char>>,
+    grid: Grid<char>,
     robot: Point2<i32>,
     instructions: Vec<Point2<i32>>,
 }
@@ -12,35 +12,14 @@ struct Warehouse {
 impl From<&str> for Warehouse {
     fn from(value: &str) -> Self {
         let (warehouse, instructions) = value.trim().split_once("\n\n").unwrap();
-        let mut robot = Point2::new(0, 0);
-        let grid = warehouse
-            .lines()
-            .enumerate()
-            .map(|(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .map(|(x, c)| {
-                        if c == '@' {
-                            robot = Point2::new(x as i32, y as i32);
-                            '.'
-                        } else {
-                            c
-                        }
-                    })
-                    .collect()
-            })
-            .collect();
+        let mut grid = Grid::from(warehouse);
+        let robot = grid.position(&'@').unwrap();
+        grid[robot] = '.';
 
         let instructions = instructions
             .trim()
             .chars()
-            .flat_map(|c| match c {
-                '^' => Some(Point2::new(0, -1)),
-                'v' => Some(Point2::new(0, 1)),
-                '<' => Some(Point2::new(-1, 0)),
-                '>' => Some(Point2::new(1, 0)),
-                _ => None,
-            })
+            .flat_map(|c| Direction::try_from(c).ok().map(Point2::from))
             .collect();
 
         Warehouse {
@@ -53,10 +32,7 @@ impl From<&str> for Warehouse {
 
 impl Display for Warehouse {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for line in &self.grid {
-            writeln!(f, "{}", line.iter().collect::<String>())?;
-        }
-        Ok(())
+        write!(f, "{}", self.grid)
     }
 }
 
@@ -64,21 +40,21 @@ impl Warehouse {
     fn run_instructions(&mut self) {
         for &instruction in &self.instructions {
             let next = self.robot + instruction;
-            match self.grid[next.y as usize][next.x as usize] {
+            match self.grid[next] {
                 '.' => {
                     self.robot = next;
                 }
                 'O' => {
                     // find all boxes in the direction that would be pushed
                     let mut next_box = next;
-                    while self.grid[next_box.y as usize][next_box.x as usize] == 'O' {
+                    while self.grid[next_box] == 'O' {
                         next_box += instruction;
                     }
                     // if the next cell is empty, move the robot and the boxes
-                    if self.grid[next_box.y as usize][next_box.x as usize] == '.' {
+                    if self.grid[next_box] == '.' {
                         self.robot = next;
-                        self.grid[next.y as usize][next.x as usize] = '.';
-                        self.grid[next_box.y as usize][next_box.x as usize] = 'O';
+                        self.grid[next] = '.';
+                        self.grid[next_box] = 'O';
                     }
                 }
                 _ => {}
@@ -89,7 +65,7 @@ impl Warehouse {
     fn to_part_2(&self) -> Warehouse {
         let grid = self
             .grid
-            .iter()
+            .rows()
             .map(|line| {
                 line.iter()
                     .flat_map(|&c| match c {
@@ -103,7 +79,7 @@ impl Warehouse {
             })
             .collect::<Vec<_>>();
         Warehouse {
-            grid,
+            grid: Grid::from_rows(grid),
             robot: Point2::new(self.robot.x * 2, self.robot.y),
             instructions: self.instructions.clone(),
         }
@@ -112,7 +88,7 @@ impl Warehouse {
     fn run_instructions_part2(&mut self) {
         for &instruction in &self.instructions {
             let next = self.robot + instruction;
-            match self.grid[next.y as usize][next.x as usize] {
+            match self.grid[next] {
                 '.' => {
                     self.robot = next;
                 }
@@ -130,13 +106,11 @@ impl Warehouse {
                     // we hit a box
                     if instruction.x.abs() > 0 {
                         let mut next_box = next + instruction + instruction;
-                        while ['[', ']']
-                            .contains(&self.grid[next_box.y as usize][next_box.x as usize])
-                        {
+                        while ['[', ']'].contains(&self.grid[next_box]) {
                             boxes_to_move.push(next_box);
                             next_box += instruction;
                         }
-                        if self.grid[next_box.y as usize][next_box.x as usize] == '#' {
+                        if self.grid[next_box] == '#' {
                             blocked = true;
                         }
                     } else {
@@ -150,23 +124,21 @@ impl Warehouse {
                             let mut next_boxes = Vec::new();
                             for current_box in current_boxes {
                                 let next_box = current_box + instruction;
-                                match self.grid[next_box.y as usize][next_box.x as usize] {
+                                match self.grid[next_box] {
                                     '#' => {
                                         blocked = true;
                                         break;
                                     }
-                                    part @ '[' | part @ ']' => {
-                                        // if it already is in the list, we have already moved it
-                                        if !next_boxes.contains(&next_box) {
-                                            boxes_to_move.push(next_box);
-                                            next_boxes.push(next_box);
-                                            if part == '[' {
-                                                boxes_to_move.push(next_box + Point2::new(1, 0));
-                                                next_boxes.push(next_box + Point2::new(1, 0));
-                                            } else {
-                                                boxes_to_move.push(next_box + Point2::new(-1, 0));
-                                                next_boxes.push(next_box + Point2::new(-1, 0));
-                                            }
+                                    // if it already is in the list, we have already moved it
+                                    part @ '[' | part @ ']' if !next_boxes.contains(&next_box) => {
+                                        boxes_to_move.push(next_box);
+                                        next_boxes.push(next_box);
+                                        if part == '[' {
+                                            boxes_to_move.push(next_box + Point2::new(1, 0));
+                                            next_boxes.push(next_box + Point2::new(1, 0));
+                                        } else {
+                                            boxes_to_move.push(next_box + Point2::new(-1, 0));
+                                            next_boxes.push(next_box + Point2::new(-1, 0));
                                         }
                                     }
                                     _ => {}
@@ -180,9 +152,8 @@ impl Warehouse {
                         // move all boxes in reverse order
                         for &box_pos in boxes_to_move.iter().rev() {
                             let next_box = box_pos + instruction;
-                            self.grid[next_box.y as usize][next_box.x as usize] =
-                                self.grid[box_pos.y as usize][box_pos.x as usize];
-                            self.grid[box_pos.y as usize][box_pos.x as usize] = '.';
+                            self.grid[next_box] = self.grid[box_pos];
+                            self.grid[box_pos] = '.';
                         }
                         self.robot = next;
                     }
@@ -199,14 +170,8 @@ pub fn part_one(input: &str) -> Option<u32> {
     // println!("{}", warehouse);
     let sum = warehouse
         .grid
-        .iter()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.iter().enumerate().filter_map(move |(x, c)| match c {
-                'O' => Some(100 * y as u32 + x as u32),
-                _ => None,
-            })
-        })
+        .find_all(|&c| c == 'O')
+        .map(|p| 100 * p.y as u32 + p.x as u32)
         .sum();
     Some(sum)
 }
@@ -217,14 +182,8 @@ pub fn part_two(input: &str) -> Option<u32> {
     // println!("{}", warehouse);
     let sum = warehouse
         .grid
-        .iter()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.iter().enumerate().filter_map(move |(x, c)| match c {
-                '[' => Some(100 * y as u32 + x as u32),
-                _ => None,
-            })
-        })
+        .find_all(|&c| c == '[')
+        .map(|p| 100 * p.y as u32 + p.x as u32)
         .sum();
     Some(sum)
 }
@@ -233,18 +192,6 @@ pub fn part_two(input: &str) -> Option<u32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(10092));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(9021));
-    }
-
     #[test]
     fn test_small_example() {
         let input = "########\n\
@@ -1,4 +1,4 @@
-use advent_of_code::helpers::{Direction, Point2};
+use advent_of_code::helpers::{Direction, Grid, Point2};
 use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 advent_of_code::solution!(16);
 
@@ -108,36 +108,19 @@ Analyze your map carefully. *What is the lowest score a Reindeer could possibly
 
 #[derive(Debug)]
 struct Maze {
-    walls: HashSet<Point2<i32>>,
+    /// `true` where there is a wall.
+    walls: Grid<bool>,
     start: Point2<i32>,
     end: Point2<i32>,
 }
 
 impl From<&str> for Maze {
     fn from(value: &str) -> Self {
-        let mut walls = HashSet::new();
-        let mut start = None;
-        let mut end = None;
-        for (y, line) in value.lines().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                match c {
-                    '#' => {
-                        walls.insert(Point2::new(x as i32, y as i32));
-                    }
-                    'S' => {
-                        start = Some(Point2::new(x as i32, y as i32));
-                    }
-                    'E' => {
-                        end = Some(Point2::new(x as i32, y as i32));
-                    }
-                    _ => {}
-                }
-            }
-        }
+        let map = Grid::from(value);
         Self {
-            walls,
-            start: start.expect("No start found"),
-            end: end.expect("No end found"),
+            walls: map.map(|&c| c == '#'),
+            start: map.position(&'S').expect("No start found"),
+            end: map.position(&'E').expect("No end found"),
         }
     }
 }
@@ -163,30 +146,17 @@ impl SearchNode {
     }
 
     fn forward(&self) -> Self {
-        let next_position = match self.direction {
-            Direction::North => Point2::new(self.point.x, self.point.y - 1),
-            Direction::South => Point2::new(self.point.x, self.point.y + 1),
-            Direction::East => Point2::new(self.point.x + 1, self.point.y),
-            Direction::West => Point2::new(self.point.x - 1, self.point.y),
-            _ => panic!("Invalid direction"),
-        };
         Self {
-            point: next_position,
+            point: self.point.neighbor(self.direction),
             direction: self.direction,
         }
     }
 
     fn rotate(&self, clockwise: bool) -> Self {
-        let next_direction = match (self.direction, clockwise) {
-            (Direction::North, true) => Direction::East,
-            (Direction::North, false) => Direction::West,
-            (Direction::South, true) => Direction::West,
-            (Direction::South, false) => Direction::East,
-            (Direction::East, true) => Direction::South,
-            (Direction::East, false) => Direction::North,
-            (Direction::West, true) => Direction::North,
-            (Direction::West, false) => Direction::South,
-            _ => panic!("Invalid direction"),
+        let next_direction = if clockwise {
+            self.direction.rotate_clockwise()
+        } else {
+            self.direction.rotate_counterclockwise()
         };
         Self {
             point: self.point,
@@ -199,16 +169,12 @@ type Path = Vec<SearchNode>;
 
 impl Maze {
     fn is_wall(&self, point: &Point2<i32>) -> bool {
-        self.walls.contains(point)
+        // the maze is walled in, outside of it counts as wall too
+        self.walls.get(*point).copied().unwrap_or(true)
     }
 
     fn end_nodes(&self) -> [SearchNode; 4] {
-        [
-            SearchNode::new(self.end, Direction::North),
-            SearchNode::new(self.end, Direction::South),
-            SearchNode::new(self.end, Direction::East),
-            SearchNode::new(self.end, Direction::West),
-        ]
+        Direction::CARDINALS.map(|direction| SearchNode::new(self.end, direction))
     }
 
     fn shortest_paths(&self) -> (i32, Vec<Path>) {
@@ -284,27 +250,3 @@ pub fn part_two(input: &str) -> Option<usize> {
         best_paths.iter().flatten().map(|node| node.point).collect();
     Some(points_on_paths.len())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7036));
-    }
-    #[test]
-    fn test_part_one_second_example() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(11048));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(45));
-    }
-}
@@ -205,12 +205,6 @@ pub fn part_two(input: &str) -> Option<i64> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some("4,6,3,5,6,3,5,2,1,0".to_string()));
-    }
-
     #[test]
     fn test_program_1() {
         let mut input = Input {
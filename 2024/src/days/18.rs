@@ -1,6 +1,8 @@
 use advent_of_code::helpers::Point2;
+use advent_of_code::template::examples::Example;
+use advent_of_code::template::params::PuzzleParams;
 use std::collections::HashSet;
-advent_of_code::solution!(18);
+advent_of_code::solution!(18, Memory);
 /*
 \--- Day 18: RAM Run ---
 ----------
@@ -119,6 +121,29 @@ Simulate more of the bytes that are about to corrupt your memory space. *What ar
 
  */
 
+/// Size of the memory space and the number of bytes that fall before part one.
+pub struct Memory {
+    /// The highest coordinate, which is also the coordinate of the exit.
+    size: i32,
+    bytes: usize,
+}
+
+impl PuzzleParams for Memory {
+    fn real() -> Self {
+        Memory {
+            size: 70,
+            bytes: 1024,
+        }
+    }
+
+    fn example(example: &Example) -> Self {
+        Memory {
+            size: example.param("size").unwrap_or(6),
+            bytes: example.param("bytes").unwrap_or(12),
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Input {
     bytes: Vec<Point2<i32>>,
@@ -183,17 +208,17 @@ impl Input {
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
+pub fn part_one(input: &str, memory: &Memory) -> Option<usize> {
     let input = Input::from(input);
     let start = Point2::new(0, 0);
-    let exit = Point2::new(70, 70);
-    input.path_find(1024, start, exit)
+    let exit = Point2::new(memory.size, memory.size);
+    input.path_find(memory.bytes, start, exit)
 }
 
-pub fn part_two(input: &str) -> Option<Point2<i32>> {
+pub fn part_two(input: &str, memory: &Memory) -> Option<Point2<i32>> {
     let input = Input::from(input);
     let start = Point2::new(0, 0);
-    let exit = Point2::new(70, 70);
+    let exit = Point2::new(memory.size, memory.size);
     input.unreachable_byte(start, exit)
 }
 
@@ -201,19 +226,13 @@ pub fn part_two(input: &str) -> Option<Point2<i32>> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input_str: &str = &advent_of_code::template::read_file("examples", DAY);
-        let input = Input::from(input_str);
-        let start = Point2::new(0, 0);
-        let exit = Point2::new(6, 6);
-        let result = input.path_find(12, start, exit);
-        assert_eq!(result, Some(22));
-    }
-
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let memory = Memory { size: 6, bytes: 12 };
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", DAY),
+            &memory,
+        );
+        assert_eq!(result, Some(Point2::new(6, 1)));
     }
 }
@@ -94,20 +94,3 @@ pub fn part_two(input: &str) -> Option<usize> {
     let possible_designs = input.possible_design_combinations();
     Some(possible_designs.values().sum::<usize>())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(16));
-    }
-}
@@ -1,44 +1,41 @@
-use advent_of_code::helpers::Point2;
+use advent_of_code::helpers::{Grid, Point2};
+use advent_of_code::template::examples::Example;
+use advent_of_code::template::params::PuzzleParams;
 use itertools::Itertools;
 use std::collections::{HashMap, VecDeque};
 use std::fmt::Display;
-advent_of_code::solution!(20);
+advent_of_code::solution!(20, Threshold);
+
+/// The number of picoseconds a cheat has to save to be counted.
+pub struct Threshold(usize);
+
+impl PuzzleParams for Threshold {
+    fn real() -> Self {
+        Threshold(100)
+    }
+
+    fn example(example: &Example) -> Self {
+        Threshold(example.param("min_saving").unwrap_or(50))
+    }
+}
 
 struct Input {
-    map: Vec<Vec<char>>,
+    map: Grid<char>,
     end: Point2<i32>,
 }
 
 impl From<&str> for Input {
     fn from(input: &str) -> Self {
-        let mut end = Point2::new(0, 0);
-        let map = input
-            .lines()
-            .enumerate()
-            .map(|(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .map(|(x, char)| match char {
-                        'S' => '.',
-                        'E' => {
-                            end = Point2::new(x as i32, y as i32);
-                            '.'
-                        }
-                        _ => char,
-                    })
-                    .collect()
-            })
-            .collect();
+        let map = Grid::from(input);
+        let end = map.position(&'E').unwrap();
+        let map = map.map(|&c| if c == '#' { '#' } else { '.' });
         Input { map, end }
     }
 }
 
 impl Display for Input {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in &self.map {
-            writeln!(f, "{}", row.iter().collect::<String>())?;
-        }
-        Ok(())
+        write!(f, "{}", self.map)
     }
 }
 
@@ -81,15 +78,13 @@ impl Input {
         let mut distances = HashMap::new();
         let mut queue = VecDeque::new();
         queue.push_back((from_point, 0));
-        let width = self.map[0].len() as i32;
-        let height = self.map.len() as i32;
         while let Some((point, distance)) = queue.pop_front() {
             if distances.contains_key(&point) {
                 continue;
             }
             distances.insert(point, distance);
-            for neighbor in point.neighbors_checked(width, height) {
-                if self.map[neighbor.y as usize][neighbor.x as usize] == '#' {
+            for (neighbor, &tile) in self.map.neighbors(point) {
+                if tile == '#' {
                     continue;
                 }
                 queue.push_back((neighbor, distance + 1));
@@ -99,17 +94,17 @@ impl Input {
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
+pub fn part_one(input: &str, threshold: &Threshold) -> Option<usize> {
     let input = Input::from(input);
     let cheats = input.enumerate_cheats(2);
-    let good_cheat_count = cheats.values().filter(|&&time| time >= 100).count();
+    let good_cheat_count = cheats.values().filter(|&&time| time >= threshold.0).count();
     Some(good_cheat_count)
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
+pub fn part_two(input: &str, threshold: &Threshold) -> Option<usize> {
     let input = Input::from(input);
     let cheats = input.enumerate_cheats(20);
-    let good_cheat_count = cheats.values().filter(|&&time| time >= 100).count();
+    let good_cheat_count = cheats.values().filter(|&&time| time >= threshold.0).count();
     Some(good_cheat_count)
 }
 
@@ -119,21 +119,9 @@ pub fn part_two(input: &str) -> Option<u32> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(37327623));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-
     #[test]
     fn test_secret_step() {
-        let mut number = SecretNumber::new(123);
+        let number = SecretNumber::new(123);
         println!("{:?}", number.price_changes(10));
     }
 
@@ -261,22 +261,3 @@ pub fn part_two(input: &str) -> Option<String> {
     let solution_swaps = ["z11", "vkq", "mmk", "z24", "hqh", "z38", "pvb", "qdq"];
     Some(solution_swaps.iter().sorted().join(","))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4));
-    }
-
-    #[test]
-    fn test_part_one_larger() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(2024));
-    }
-}
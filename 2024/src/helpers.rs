@@ -3,6 +3,11 @@
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
 
+mod grid;
+
+pub use aoc_core::direction::{Direction, ParseDirectionError};
+pub use grid::Grid;
+
 use num::{CheckedAdd, CheckedSub, One, Zero};
 use std::fmt::{Display, Formatter};
 use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
@@ -19,16 +24,12 @@ impl<T: Display> Display for Point2<T> {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
-pub enum Direction {
-    North,
-    South,
-    East,
-    West,
-    NorthEast,
-    NorthWest,
-    SouthEast,
-    SouthWest,
+/// The unit step of a direction, see [`Direction::offset`].
+impl<T: From<i8>> From<Direction> for Point2<T> {
+    fn from(direction: Direction) -> Self {
+        let (x, y) = direction.offset();
+        Point2::new(x, y)
+    }
 }
 
 impl<T> Point2<T> {
@@ -135,20 +136,18 @@ where
         .filter_map(|(x, y)| x.and_then(|x| y.map(|y| Point2::new(x, y))))
         .collect()
     }
+
+    pub fn neighbors_diagonals(&self, width: T, height: T) -> Vec<Point2<T>> {
+        self.neighbors_with_diagonal()
+            .into_iter()
+            .filter(|p| p.x >= T::zero() && p.x < width && p.y >= T::zero() && p.y < height)
+            .collect()
+    }
 }
 
-impl Point2<i32> {
-    pub fn neighbor(&self, direction: Direction) -> Point2<i32> {
-        match direction {
-            Direction::North => Point2::new(self.x, self.y - 1),
-            Direction::South => Point2::new(self.x, self.y + 1),
-            Direction::East => Point2::new(self.x + 1, self.y),
-            Direction::West => Point2::new(self.x - 1, self.y),
-            Direction::NorthEast => Point2::new(self.x + 1, self.y - 1),
-            Direction::NorthWest => Point2::new(self.x - 1, self.y - 1),
-            Direction::SouthEast => Point2::new(self.x + 1, self.y + 1),
-            Direction::SouthWest => Point2::new(self.x - 1, self.y + 1),
-        }
+impl<T: Add<Output = T> + From<i8> + Copy> Point2<T> {
+    pub fn neighbor(&self, direction: Direction) -> Point2<T> {
+        *self + Point2::from(direction)
     }
 }
 
@@ -244,4 +243,16 @@ mod tests {
         assert!(neighbors.contains(&Point2::new(2, 1)));
         assert!(neighbors.contains(&Point2::new(2, 2)));
     }
+    #[test]
+    fn test_direction_units() {
+        use super::{Direction, Point2};
+        assert_eq!(
+            Point2::<i64>::from(Direction::NorthEast),
+            Point2::new(1, -1)
+        );
+        assert_eq!(
+            Point2::new(0.5, 0.5).neighbor(Direction::West),
+            Point2::new(-0.5, 0.5)
+        );
+    }
 }
@@ -0,0 +1,295 @@
+use super::{Direction, Point2};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row by row, indexed by `Point2<i32>` with `x` going right and `y` going down.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Panics if the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows differ in length"
+        );
+        Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        }
+    }
+
+    /// Parses a grid with one line per row, mapping every character to a cell.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        Self::from_rows(
+            input
+                .lines()
+                .map(|line| line.chars().map(&mut cell).collect())
+                .collect(),
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point2<i32>) -> bool {
+        point.x >= 0
+            && (point.x as usize) < self.width
+            && point.y >= 0
+            && (point.y as usize) < self.height
+    }
+
+    fn offset(&self, point: Point2<i32>) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    fn point(&self, offset: usize) -> Point2<i32> {
+        Point2::new((offset % self.width) as i32, (offset / self.width) as i32)
+    }
+
+    pub fn get(&self, point: Point2<i32>) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, point: Point2<i32>) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point2<i32>> + '_ {
+        (0..self.cells.len()).map(|offset| self.point(offset))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<i32>, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(offset, cell)| (self.point(offset), cell))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on an empty chunk size, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The cells from `from` on, stepping in `direction` until leaving the grid.
+    /// Walks rows, columns and diagonals depending on the direction.
+    pub fn ray(
+        &self,
+        from: Point2<i32>,
+        direction: Direction,
+    ) -> impl Iterator<Item = (Point2<i32>, &T)> {
+        std::iter::successors(Some(from), move |point| Some(point.neighbor(direction)))
+            .map_while(|point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    /// The orthogonal neighbors of `point` inside the grid.
+    pub fn neighbors(&self, point: Point2<i32>) -> impl Iterator<Item = (Point2<i32>, &T)> {
+        self.neighbors_in(point, &Direction::CARDINALS)
+    }
+
+    /// The orthogonal and diagonal neighbors of `point` inside the grid.
+    pub fn neighbors_with_diagonal(
+        &self,
+        point: Point2<i32>,
+    ) -> impl Iterator<Item = (Point2<i32>, &T)> {
+        self.neighbors_in(point, &Direction::ALL)
+    }
+
+    fn neighbors_in<'a>(
+        &'a self,
+        point: Point2<i32>,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Point2<i32>, &'a T)> {
+        directions.iter().filter_map(move |&direction| {
+            let neighbor = point.neighbor(direction);
+            self.get(neighbor).map(|cell| (neighbor, cell))
+        })
+    }
+
+    /// The first point, row by row, whose cell matches `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point2<i32>> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|offset| self.point(offset))
+    }
+
+    /// Every point whose cell matches `predicate`, row by row.
+    pub fn find_all<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point2<i32>> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    pub fn position(&self, value: &T) -> Option<Point2<i32>>
+    where
+        T: PartialEq,
+    {
+        self.find(|cell| cell == value)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_rows(
+            self.columns()
+                .map(|column| column.cloned().collect())
+                .collect(),
+        )
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_rows(
+            self.columns()
+                .map(|column| {
+                    let mut column: Vec<T> = column.cloned().collect();
+                    column.reverse();
+                    column
+                })
+                .collect(),
+        )
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self::from_rows(
+            (0..self.width)
+                .rev()
+                .map(|x| self.column(x).cloned().collect())
+                .collect(),
+        )
+    }
+}
+
+impl From<&str> for Grid<char> {
+    fn from(input: &str) -> Self {
+        Self::parse(input, |c| c)
+    }
+}
+
+impl<T> Index<Point2<i32>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<i32>) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point2<i32>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<i32>) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", point))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::from("abc\ndef")
+    }
+
+    #[test]
+    fn test_parse_and_index() {
+        let mut grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point2::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point2::new(3, 0)), None);
+        assert_eq!(grid.get(Point2::new(0, -1)), None);
+        grid[Point2::new(0, 0)] = 'x';
+        assert_eq!(grid.to_string(), "xbc\ndef\n");
+
+        let digits = Grid::parse("12\n34", |c| c.to_digit(10).unwrap());
+        assert_eq!(digits.row(1), &[3, 4]);
+        assert_eq!(
+            digits.map(|d| d * 2).column(0).collect::<Vec<_>>(),
+            [&2, &6]
+        );
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = grid();
+        let ray: String = grid
+            .ray(Point2::new(0, 0), Direction::SouthEast)
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!(ray, "ae");
+        assert_eq!(grid.neighbors(Point2::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbors_with_diagonal(Point2::new(1, 0)).count(), 5);
+        assert_eq!(grid.position(&'e'), Some(Point2::new(1, 1)));
+        assert_eq!(grid.find_all(|c| *c > 'b').count(), 4);
+        assert_eq!(grid.points().last(), Some(Point2::new(2, 1)));
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+}
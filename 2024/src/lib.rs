@@ -1,5 +1,3 @@
-mod day;
 pub mod helpers;
-pub mod template;
 
-pub use day::*;
+pub use aoc_core::*;
@@ -0,0 +1,145 @@
+/// Entry point for every Rust year of the repository, see the `cargo` aliases in `.cargo/config.toml`.
+/// Picks the year from `--year` (or `AOC_YEAR`, or the latest year) and forwards the remaining arguments to its binary.
+/// The year is built with `--profile` (or the profile this binary was built with) and `--features`, if given.
+use std::{
+    env,
+    ffi::OsString,
+    fmt, fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+#[derive(Debug)]
+enum CliError {
+    Args(pico_args::Error),
+    NoYears,
+    UnknownYear(u16),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::Args(err) => write!(f, "{err}"),
+            CliError::NoYears => write!(f, "no Rust years found in the repository."),
+            CliError::UnknownYear(year) => {
+                write!(f, "year {year} does not exist or is not a Rust year.")
+            }
+        }
+    }
+}
+
+impl From<pico_args::Error> for CliError {
+    fn from(err: pico_args::Error) -> Self {
+        CliError::Args(err)
+    }
+}
+
+/// The root of the workspace, where every year has its own directory.
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+/// Years that have a cargo package, in ascending order.
+fn rust_years(root: &Path) -> Vec<u16> {
+    let mut years: Vec<u16> = fs::read_dir(root)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let entry = entry.ok()?;
+                    let year = entry.file_name().to_str()?.parse().ok()?;
+                    entry.path().join("Cargo.toml").is_file().then_some(year)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    years.sort_unstable();
+    years
+}
+
+/// How to build and run the binary of a year.
+struct Invocation {
+    year: u16,
+    profile: String,
+    features: Option<String>,
+    rest: Vec<OsString>,
+}
+
+/// The profile this binary was built with, so `cargo run` and `cargo run --release` carry over to the years.
+fn default_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "dev"
+    } else {
+        "release"
+    }
+}
+
+fn parse(root: &Path) -> Result<Invocation, CliError> {
+    let mut args = pico_args::Arguments::from_env();
+    let years = rust_years(root);
+
+    let year = match args.opt_value_from_str("--year")? {
+        Some(year) => year,
+        None => match env::var("AOC_YEAR").ok().and_then(|y| y.parse().ok()) {
+            Some(year) => year,
+            None => *years.last().ok_or(CliError::NoYears)?,
+        },
+    };
+    if !years.contains(&year) {
+        return Err(CliError::UnknownYear(year));
+    }
+
+    let profile = args
+        .opt_value_from_str("--profile")?
+        .unwrap_or_else(|| default_profile().to_string());
+    let features = args.opt_value_from_str("--features")?;
+
+    Ok(Invocation {
+        year,
+        profile,
+        features,
+        rest: args.finish(),
+    })
+}
+
+fn main() {
+    let root = root();
+
+    let Invocation {
+        year,
+        profile,
+        features,
+        rest,
+    } = match parse(&root) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            process::exit(1);
+        }
+    };
+
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command
+        .args(["run", "--quiet", "--profile", &profile, "-p"])
+        .arg(format!("advent_of_code_{year}"));
+    if let Some(features) = features {
+        command.args(["--features", &features]);
+    }
+    let status = command
+        .arg("--")
+        .args(rest)
+        .current_dir(root.join(year.to_string()))
+        .env("AOC_YEAR", year.to_string())
+        .status();
+
+    match status {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(err) => {
+            eprintln!("Error: failed to run cargo: {err}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! The eight directions of a grid, with `y` growing southwards.
+//!
+//! Every year has its own point type, so a direction only knows its unit step as an `(x, y)` pair,
+//! see [`Direction::offset`].
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The variants are declared clockwise from north, so `direction as usize` indexes [`Direction::ALL`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from north.
+    pub const CARDINALS: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Turns clockwise by `eighths` of a full turn, counterclockwise if negative.
+    fn rotate(self, eighths: i32) -> Direction {
+        Self::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn rotate_clockwise(self) -> Direction {
+        self.rotate(2)
+    }
+
+    pub fn rotate_counterclockwise(self) -> Direction {
+        self.rotate(-2)
+    }
+
+    pub fn rotate_clockwise_45(self) -> Direction {
+        self.rotate(1)
+    }
+
+    pub fn rotate_counterclockwise_45(self) -> Direction {
+        self.rotate(-1)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_cardinal(self) -> bool {
+        Self::CARDINALS.contains(&self)
+    }
+
+    /// The step one cell in this direction as `(x, y)`.
+    pub fn offset<T: From<i8>>(self) -> (T, T) {
+        let (x, y): (i8, i8) = match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        };
+        (T::from(x), T::from(y))
+    }
+
+    /// The lowercase compass name, like `north` or `southwest`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Direction::North => "north",
+            Direction::NorthEast => "northeast",
+            Direction::East => "east",
+            Direction::SouthEast => "southeast",
+            Direction::South => "south",
+            Direction::SouthWest => "southwest",
+            Direction::West => "west",
+            Direction::NorthWest => "northwest",
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(String);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid direction {:?}", self.0)
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+/// Arrows `^>v<`, compass letters `NESW` and moves `UDLR`.
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'N' | 'U' => Ok(Direction::North),
+            '>' | 'E' | 'R' => Ok(Direction::East),
+            'v' | 'S' | 'D' => Ok(Direction::South),
+            '<' | 'W' | 'L' => Ok(Direction::West),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+}
+
+/// A single character as in [`TryFrom<char>`], a diagonal like `NE` or a compass name like `north`.
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => match s {
+                "NE" => Ok(Direction::NorthEast),
+                "NW" => Ok(Direction::NorthWest),
+                "SE" => Ok(Direction::SouthEast),
+                "SW" => Ok(Direction::SouthWest),
+                _ => Self::ALL
+                    .into_iter()
+                    .find(|direction| direction.name() == s)
+                    .ok_or_else(|| ParseDirectionError(s.to_string())),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Direction;
+
+    #[test]
+    fn rotates() {
+        assert_eq!(Direction::North.rotate_clockwise(), Direction::East);
+        assert_eq!(Direction::North.rotate_counterclockwise(), Direction::West);
+        assert_eq!(Direction::West.rotate_clockwise_45(), Direction::NorthWest);
+        assert_eq!(
+            Direction::North.rotate_counterclockwise_45(),
+            Direction::NorthWest
+        );
+        assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+        assert!(Direction::ALL
+            .iter()
+            .all(|d| d.rotate_clockwise().rotate_counterclockwise() == *d));
+        assert!(!Direction::SouthWest.is_cardinal());
+    }
+
+    #[test]
+    fn parses_and_steps() {
+        assert_eq!("^".parse(), Ok(Direction::North));
+        assert_eq!("R".parse(), Ok(Direction::East));
+        assert_eq!("SW".parse(), Ok(Direction::SouthWest));
+        assert_eq!("west".parse(), Ok(Direction::West));
+        assert_eq!(Direction::try_from('v'), Ok(Direction::South));
+        assert!("x".parse::<Direction>().is_err());
+        assert_eq!(Direction::NorthEast.offset::<i64>(), (1, -1));
+        assert_eq!(Direction::SouthWest.offset::<f32>(), (-1.0, 1.0));
+        assert_eq!(Direction::NorthWest.to_string(), "northwest");
+    }
+}
@@ -0,0 +1,10 @@
+/// Code shared by the solutions of every year: the `solution!` macro, the runner, the commands of the year binaries
+/// and puzzle utilities like the grid [`direction`]s, the maze contraction in [`maze`] and the unbounded grid in [`sparse`].
+mod day;
+pub mod direction;
+pub mod maze;
+pub mod registry;
+pub mod sparse;
+pub mod template;
+
+pub use day::*;
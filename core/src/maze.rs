@@ -0,0 +1,204 @@
+//! Contracts a maze to the cells that matter for a puzzle, like keys, portals or junctions.
+//!
+//! Every corridor between two points of interest becomes a single weighted edge, so searches over the
+//! [`PoiGraph`] visit a handful of nodes instead of every open cell of the grid.
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A grid cell as `(x, y)`.
+pub type Cell = (usize, usize);
+
+/// The points of interest of a maze, connected by the lengths of the corridors between them.
+#[derive(Debug, Clone)]
+pub struct PoiGraph<P> {
+    points: Vec<P>,
+    index: HashMap<P, usize>,
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl<P: Copy + Eq + Hash> PoiGraph<P> {
+    /// Walks from every point of interest until the next ones, moving along `neighbors`.
+    ///
+    /// The edge to a point is the shortest walk that does not pass another point of interest.
+    /// Edges are directed, so `neighbors` can describe one-way moves.
+    pub fn new<I, N>(points: impl IntoIterator<Item = P>, neighbors: N) -> Self
+    where
+        N: Fn(&P) -> I,
+        I: IntoIterator<Item = P>,
+    {
+        let mut index: HashMap<P, usize> = HashMap::new();
+        let points: Vec<P> = points
+            .into_iter()
+            .filter(|point| {
+                let next = index.len();
+                *index.entry(*point).or_insert(next) == next
+            })
+            .collect();
+
+        let edges = points
+            .iter()
+            .map(|source| {
+                let mut edges = Vec::new();
+                let mut visited = HashSet::from([*source]);
+                let mut queue = VecDeque::from([(*source, 0)]);
+                while let Some((point, distance)) = queue.pop_front() {
+                    for next in neighbors(&point) {
+                        if !visited.insert(next) {
+                            continue;
+                        }
+                        match index.get(&next) {
+                            Some(&target) => edges.push((target, distance + 1)),
+                            None => queue.push_back((next, distance + 1)),
+                        }
+                    }
+                }
+                edges
+            })
+            .collect();
+
+        Self {
+            points,
+            index,
+            edges,
+        }
+    }
+
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+
+    pub fn contains(&self, point: &P) -> bool {
+        self.index.contains_key(point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The points of interest reachable from `point` without passing another, with their distances.
+    pub fn edges(&self, point: &P) -> Vec<(P, usize)> {
+        self.index
+            .get(point)
+            .map(|&i| {
+                self.edges[i]
+                    .iter()
+                    .map(|&(target, distance)| (self.points[target], distance))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// The length of the longest path from `from` to `to` that visits every point at most once.
+    ///
+    /// This tries every simple path, which is only feasible because the graph is small.
+    pub fn longest_path(&self, from: &P, to: &P) -> Option<usize> {
+        let (&from, &to) = (self.index.get(from)?, self.index.get(to)?);
+        let mut visited = vec![false; self.points.len()];
+        visited[from] = true;
+        self.longest_from(from, to, &mut visited)
+    }
+
+    fn longest_from(&self, point: usize, to: usize, visited: &mut [bool]) -> Option<usize> {
+        if point == to {
+            return Some(0);
+        }
+        let mut longest = None;
+        for &(next, distance) in &self.edges[point] {
+            if visited[next] {
+                continue;
+            }
+            visited[next] = true;
+            if let Some(rest) = self.longest_from(next, to, visited) {
+                longest = longest.max(Some(distance + rest));
+            }
+            visited[next] = false;
+        }
+        longest
+    }
+}
+
+impl PoiGraph<Cell> {
+    /// Contracts a `width` by `height` grid where moves go to the four orthogonal neighbors that are `passable`.
+    ///
+    /// Besides the `interesting` cells, every junction (a passable cell with more than two passable neighbors)
+    /// becomes a point of interest, so the edges follow single corridors.
+    pub fn from_grid(
+        width: usize,
+        height: usize,
+        passable: impl Fn(Cell) -> bool,
+        interesting: impl Fn(Cell) -> bool,
+    ) -> Self {
+        let neighbors = |&(x, y): &Cell| {
+            [
+                x.checked_sub(1).map(|x| (x, y)),
+                (x + 1 < width).then_some((x + 1, y)),
+                y.checked_sub(1).map(|y| (x, y)),
+                (y + 1 < height).then_some((x, y + 1)),
+            ]
+            .into_iter()
+            .flatten()
+            .filter(|&cell| passable(cell))
+            .collect::<Vec<_>>()
+        };
+
+        let points: Vec<Cell> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .filter(|&cell| passable(cell))
+            .filter(|&cell| interesting(cell) || neighbors(&cell).len() > 2)
+            .collect();
+
+        Self::new(points, neighbors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "\
+#########
+#a..#..b#
+###.#.###
+#c......#
+#########";
+
+    fn maze() -> PoiGraph<Cell> {
+        let grid: Vec<Vec<char>> = MAZE.lines().map(|line| line.chars().collect()).collect();
+        PoiGraph::from_grid(
+            grid[0].len(),
+            grid.len(),
+            |(x, y)| grid[y][x] != '#',
+            |(x, y)| grid[y][x].is_alphabetic(),
+        )
+    }
+
+    #[test]
+    fn contracts_corridors() {
+        let graph = maze();
+        // the three keys and the junctions below the two openings
+        assert_eq!(graph.len(), 5);
+        assert!(graph.contains(&(3, 3)) && graph.contains(&(5, 3)));
+        assert_eq!(graph.edges(&(1, 1)), vec![((3, 3), 4)]);
+        let mut edges = graph.edges(&(3, 3));
+        edges.sort();
+        assert_eq!(edges, vec![((1, 1), 4), ((1, 3), 2), ((5, 3), 2)]);
+        assert!(graph.edges(&(2, 1)).is_empty());
+    }
+
+    #[test]
+    fn longest_path() {
+        // a ring of eight cells with points of interest at 0, 2 and 5
+        let ring = PoiGraph::new([0, 2, 5], |&p: &usize| [(p + 1) % 8, (p + 7) % 8]);
+        assert_eq!(ring.longest_path(&0, &2), Some(6));
+        assert_eq!(ring.longest_path(&0, &0), Some(0));
+        assert_eq!(ring.longest_path(&0, &1), None);
+
+        let one_way = PoiGraph::new([0, 2, 5], |&p: &usize| [(p + 1) % 8]);
+        assert_eq!(one_way.edges(&0), vec![(2, 2)]);
+        assert_eq!(one_way.longest_path(&2, &0), Some(6));
+    }
+}
@@ -0,0 +1,49 @@
+//! Generates the solution registry of a year's binary, call [`generate`] from the `build.rs` of the year.
+//! Every scaffolded day in `src/days/` is included as a module, and its `SOLUTION` constant is collected into `SOLUTIONS`.
+//! A day is either a single file `DD.rs` or a directory `DD/mod.rs` with submodules.
+use std::{env, fmt::Write, fs, path::Path};
+
+pub fn generate() {
+    println!("cargo:rerun-if-changed=src/days");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let days_dir = Path::new(&manifest_dir).join("src").join("days");
+
+    let mut days: Vec<(String, String)> = fs::read_dir(&days_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let name = entry.file_name().into_string().ok()?;
+            let (day, path) = if entry.path().is_dir() {
+                let path = entry.path().join("mod.rs");
+                (name, path.is_file().then_some(path)?)
+            } else {
+                (name.strip_suffix(".rs")?.to_string(), entry.path())
+            };
+            (day.len() == 2 && day.chars().all(|c| c.is_ascii_digit()))
+                .then(|| (day, path.display().to_string()))
+        })
+        .collect();
+    days.sort();
+
+    let mut registry = String::new();
+
+    for (day, path) in &days {
+        writeln!(registry, "#[path = {path:?}]").unwrap();
+        writeln!(registry, "mod day_{day};").unwrap();
+    }
+
+    let entries: Vec<String> = days
+        .iter()
+        .map(|(day, _)| format!("day_{day}::SOLUTION"))
+        .collect();
+    writeln!(
+        registry,
+        "pub static SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[{}];",
+        entries.join(", ")
+    )
+    .unwrap();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+}
@@ -0,0 +1,291 @@
+//! An unbounded grid that only stores the cells that were set, for simulations that spread in every direction.
+//!
+//! Every year has its own point type, so the grid is keyed by anything that implements [`GridPoint`].
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+/// A point of a grid with `x` going right and `y` going down.
+pub trait GridPoint: Copy + Eq + Hash {
+    fn x(&self) -> i64;
+    fn y(&self) -> i64;
+    fn from_xy(x: i64, y: i64) -> Self;
+}
+
+macro_rules! tuple_point {
+    ($($t:ty),*) => {
+        $(
+            impl GridPoint for ($t, $t) {
+                fn x(&self) -> i64 {
+                    self.0 as i64
+                }
+
+                fn y(&self) -> i64 {
+                    self.1 as i64
+                }
+
+                fn from_xy(x: i64, y: i64) -> Self {
+                    (x as $t, y as $t)
+                }
+            }
+        )*
+    };
+}
+
+tuple_point!(i32, i64, isize);
+
+/// The cells that were set, with the bounding box of their points kept up to date as cells come and go.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<P, T> {
+    cells: HashMap<P, T>,
+    bounds: Option<(P, P)>,
+}
+
+impl<P: GridPoint, T: PartialEq> PartialEq for SparseGrid<P, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<P: GridPoint, T: Eq> Eq for SparseGrid<P, T> {}
+
+impl<P, T> Default for SparseGrid<P, T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<P: GridPoint, T> SparseGrid<P, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: P) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn get(&self, point: P) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: P) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// Sets a cell, returning its previous value.
+    pub fn insert(&mut self, point: P, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => grow(bounds, point),
+            None => (point, point),
+        });
+        self.cells.insert(point, value)
+    }
+
+    /// Clears a cell, shrinking the bounds if it was on their edge.
+    pub fn remove(&mut self, point: P) -> Option<T> {
+        let value = self.cells.remove(&point)?;
+        if let Some((min, max)) = self.bounds {
+            if point.x() == min.x()
+                || point.y() == min.y()
+                || point.x() == max.x()
+                || point.y() == max.y()
+            {
+                self.bounds = bounds_of(self.cells.keys().copied());
+            }
+        }
+        Some(value)
+    }
+
+    /// The smallest and largest corner of the box around every set cell, both included.
+    pub fn bounds(&self) -> Option<(P, P)> {
+        self.bounds
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (P, &T)> {
+        self.cells.iter().map(|(point, value)| (*point, value))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = P> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    /// Keeps only the cells for which `keep` returns `true`.
+    pub fn retain(&mut self, mut keep: impl FnMut(P, &mut T) -> bool) {
+        self.cells.retain(|point, value| keep(*point, value));
+        self.bounds = bounds_of(self.cells.keys().copied());
+    }
+
+    /// The cells inside the box from `min` to `max`, both included.
+    pub fn crop(&self, min: P, max: P) -> Self
+    where
+        T: Clone,
+    {
+        self.iter()
+            .filter(|(point, _)| {
+                let (x, y) = (point.x(), point.y());
+                (min.x()..=max.x()).contains(&x) && (min.y()..=max.y()).contains(&y)
+            })
+            .map(|(point, value)| (point, value.clone()))
+            .collect()
+    }
+
+    /// Moves every cell by `offset`.
+    pub fn translate(&self, offset: P) -> Self
+    where
+        T: Clone,
+    {
+        self.iter()
+            .map(|(point, value)| {
+                let moved = P::from_xy(point.x() + offset.x(), point.y() + offset.y());
+                (moved, value.clone())
+            })
+            .collect()
+    }
+
+    /// The bounding box row by row, with `default` in the unset cells.
+    pub fn to_rows(&self, default: T) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.rows()
+            .map(|row| {
+                row.map(|point| self.get(point).cloned().unwrap_or_else(|| default.clone()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Draws the bounding box row by row, every cell as the character `cell` picks for its point and value.
+    pub fn render_with(&self, cell: impl Fn(P, Option<&T>) -> char) -> String {
+        self.rows()
+            .map(|row| {
+                let mut line: String = row.map(|point| cell(point, self.get(point))).collect();
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+
+    /// Draws the bounding box row by row, with `default` in the unset cells.
+    pub fn render(&self, default: char) -> String
+    where
+        T: Display,
+    {
+        self.rows()
+            .map(|row| {
+                let mut line: String = row
+                    .map(|point| match self.get(point) {
+                        Some(value) => value.to_string(),
+                        None => default.to_string(),
+                    })
+                    .collect();
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+
+    /// The points of the bounding box, row by row.
+    fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = P>> {
+        let (min, max) = match self.bounds {
+            Some((min, max)) => ((min.x(), min.y()), (max.x(), max.y())),
+            // an empty range of rows
+            None => ((0, 0), (-1, -1)),
+        };
+        (min.1..=max.1).map(move |y| (min.0..=max.0).map(move |x| P::from_xy(x, y)))
+    }
+}
+
+fn grow<P: GridPoint>((min, max): (P, P), point: P) -> (P, P) {
+    (
+        P::from_xy(min.x().min(point.x()), min.y().min(point.y())),
+        P::from_xy(max.x().max(point.x()), max.y().max(point.y())),
+    )
+}
+
+fn bounds_of<P: GridPoint>(points: impl Iterator<Item = P>) -> Option<(P, P)> {
+    points.fold(None, |bounds, point| {
+        Some(match bounds {
+            Some(bounds) => grow(bounds, point),
+            None => (point, point),
+        })
+    })
+}
+
+impl<P: GridPoint, T> FromIterator<(P, T)> for SparseGrid<P, T> {
+    fn from_iter<I: IntoIterator<Item = (P, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<P: GridPoint, T> Extend<(P, T)> for SparseGrid<P, T> {
+    fn extend<I: IntoIterator<Item = (P, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+impl<P: GridPoint, T: Display> Display for SparseGrid<P, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render('.'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sparse() -> SparseGrid<(i64, i64), char> {
+        [((-2, 1), 'a'), ((1, -1), 'b')].into_iter().collect()
+    }
+
+    #[test]
+    fn tracks_bounds() {
+        let mut grid = sparse();
+        assert_eq!(grid.bounds(), Some(((-2, -1), (1, 1))));
+        grid.insert((0, 0), 'c');
+        grid.remove((-2, 1));
+        assert_eq!(grid.bounds(), Some(((0, -1), (1, 0))));
+        grid.retain(|point, _| point != (0, 0));
+        assert_eq!(grid.bounds(), Some(((1, -1), (1, -1))));
+        grid.remove((1, -1));
+        assert_eq!(grid.bounds(), None);
+        assert!(grid.is_empty());
+        assert_eq!(grid.render('.'), "");
+    }
+
+    #[test]
+    fn renders_the_bounding_box() {
+        let grid = sparse();
+        assert_eq!(grid.render(' '), "   b\n    \na   \n");
+        assert_eq!(grid.to_string(), "...b\n....\na...\n");
+        assert_eq!(
+            grid.render_with(|_, c| if c.is_some() { '#' } else { ' ' }),
+            "   #\n    \n#   \n"
+        );
+        assert_eq!(grid.to_rows('.')[2], vec!['a', '.', '.', '.']);
+        let cropped = grid.crop((0, -5), (5, 5));
+        assert_eq!(cropped.to_string(), "b\n");
+        let moved = grid.translate((10, 10));
+        assert_eq!(moved.get((8, 11)), Some(&'a'));
+        assert_eq!(moved.to_string(), grid.to_string());
+    }
+}
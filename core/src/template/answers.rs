@@ -0,0 +1,262 @@
+/// Module that stores the accepted answers of a day, so that refactoring a solution can be checked against them.
+/// Answers live in `data/answers/{day}.txt`, one `partN: <answer>` line per part.
+/// Newlines and backslashes in answers are escaped as `\n` and `\\`.
+use std::{fs, io, path::PathBuf};
+
+use crate::template::runner::PartResult;
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::Day;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_1 = Some(answer),
+            2 => self.part_2 = Some(answer),
+            _ => {}
+        }
+    }
+
+    /// Checks a result against the recorded answer, returns `None` if no answer was recorded for the part.
+    #[must_use]
+    pub fn verify(&self, result: &PartResult) -> Option<bool> {
+        self.get(result.part)
+            .map(|expected| result.answer.as_deref() == Some(expected))
+    }
+
+    /// Overwrites the recorded answers with the answers of all solved parts.
+    pub fn record(&mut self, results: &[PartResult]) {
+        for result in results {
+            if let Some(answer) = &result.answer {
+                self.set(result.part, answer.clone());
+            }
+        }
+    }
+}
+
+fn get_path(day: Day) -> PathBuf {
+    PathBuf::from("data")
+        .join("answers")
+        .join(format!("{day}.txt"))
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                result.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                result.push('\\');
+                chars.next();
+            }
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+fn parse(s: &str) -> Answers {
+    let mut answers = Answers::default();
+
+    for line in s.lines() {
+        let Some((key, answer)) = line.split_once(':') else {
+            continue;
+        };
+
+        if let Some(Ok(part)) = key.trim().strip_prefix("part").map(str::parse) {
+            // Only the separator is stripped, leading spaces are part of e.g. rendered images.
+            let answer = answer.strip_prefix(' ').unwrap_or(answer);
+            answers.set(part, unescape(answer));
+        }
+    }
+
+    answers
+}
+
+fn serialize(answers: &Answers) -> String {
+    [(1, &answers.part_1), (2, &answers.part_2)]
+        .iter()
+        .filter_map(|(part, answer)| {
+            answer
+                .as_ref()
+                .map(|answer| format!("part{part}: {}\n", escape(answer)))
+        })
+        .collect()
+}
+
+/// Reads the recorded answers of a day. Days without an answer file have no recorded answers.
+pub fn load(day: Day) -> io::Result<Answers> {
+    match fs::read_to_string(get_path(day)) {
+        Ok(content) => Ok(parse(&content)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn save(day: Day, answers: &Answers) -> io::Result<()> {
+    let path = get_path(day);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serialize(answers))
+}
+
+/// Records the answers of the solved parts if `is_recording` is set, verifies the results against the recorded answers otherwise.
+/// Returns the recorded answers and whether all results matched them.
+pub fn check(
+    day: Day,
+    results: &[PartResult],
+    is_recording: bool,
+    is_printed: bool,
+) -> (Answers, bool) {
+    let mut answers = load(day).unwrap_or_else(|e| {
+        eprintln!("Failed to read recorded answers for day {day}: {e}");
+        Answers::default()
+    });
+
+    if is_recording {
+        answers.record(results);
+        match save(day, &answers) {
+            Ok(()) if is_printed => {
+                println!("Recorded answers to \"{}\".", get_path(day).display())
+            }
+            Ok(()) => {}
+            Err(e) => eprintln!("Failed to record answers for day {day}: {e}"),
+        }
+        return (answers, true);
+    }
+
+    if is_printed {
+        print_verification(&answers, results);
+    }
+
+    let is_verified = results
+        .iter()
+        .all(|result| answers.verify(result).unwrap_or(true));
+
+    (answers, is_verified)
+}
+
+/// Prints ✔ or ✘ for every part that has a recorded answer.
+fn print_verification(answers: &Answers, results: &[PartResult]) {
+    let checks: Vec<String> = results
+        .iter()
+        .filter_map(|result| {
+            let expected = answers.get(result.part)?;
+            let check = if answers.verify(result)? {
+                format!("Part {}: ✔", result.part)
+            } else {
+                format!(
+                    "Part {}: {ANSI_BOLD}✘{ANSI_RESET} (expected {})",
+                    result.part,
+                    escape(expected)
+                )
+            };
+            Some(check)
+        })
+        .collect();
+
+    if !checks.is_empty() {
+        println!("Answers: {}", checks.join(" · "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, serialize, unescape, Answers};
+    use crate::template::runner::{BenchStats, PartResult, Status};
+    use std::time::Duration;
+
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            part,
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+            answer: answer.map(Into::into),
+            stats: BenchStats::from_samples(&[Duration::from_nanos(1)]),
+        }
+    }
+
+    #[test]
+    fn parses_answers() {
+        let answers = parse("part1: 161\npart2: 48\n");
+        assert_eq!(answers.get(1), Some("161"));
+        assert_eq!(answers.get(2), Some("48"));
+    }
+
+    #[test]
+    fn parses_partial_answers() {
+        let answers = parse("part2: 48\n");
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.get(2), Some("48"));
+    }
+
+    #[test]
+    fn round_trips_multiline_answers() {
+        let answers = Answers {
+            part_1: Some("1,2".into()),
+            part_2: Some("#.\\\n.#".into()),
+        };
+        let s = serialize(&answers);
+        assert_eq!(s, "part1: 1,2\npart2: #.\\\\\\n.#\n");
+        assert_eq!(parse(&s), answers);
+    }
+
+    #[test]
+    fn round_trips_leading_spaces() {
+        let answers = Answers {
+            part_1: Some(" █ \n█  ".into()),
+            part_2: None,
+        };
+        assert_eq!(parse(&serialize(&answers)), answers);
+    }
+
+    #[test]
+    fn unescapes_trailing_backslash() {
+        assert_eq!(unescape("a\\"), "a\\");
+    }
+
+    #[test]
+    fn verifies_results() {
+        let answers = parse("part1: 161\n");
+        assert_eq!(answers.verify(&result(1, Some("161"))), Some(true));
+        assert_eq!(answers.verify(&result(1, Some("162"))), Some(false));
+        assert_eq!(answers.verify(&result(1, None)), Some(false));
+        assert_eq!(answers.verify(&result(2, Some("48"))), None);
+    }
+
+    #[test]
+    fn records_solved_parts() {
+        let mut answers = parse("part1: 1\npart2: 2\n");
+        answers.record(&[result(1, Some("3")), result(2, None)]);
+        assert_eq!(answers.get(1), Some("3"));
+        assert_eq!(answers.get(2), Some("2"));
+    }
+}
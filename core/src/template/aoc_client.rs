@@ -0,0 +1,467 @@
+/// Client for the Advent of Code website. Downloads inputs and puzzle descriptions and submits answers.
+///
+/// The session cookie is read from the `AOC_SESSION` environment variable, falling back to the `~/.adventofcode.session` file.
+/// Requests go to `https://adventofcode.com` unless `AOC_BASE_URL` is set, e.g. to test against a local server.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Day;
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static USER_AGENT: &str = "github.com/Tommassino/advent-of-code";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    YearNotSet,
+    BadStatus(u16),
+    Transport(String),
+    IoError(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found, set AOC_SESSION or create ~/.adventofcode.session."
+            ),
+            AocClientError::YearNotSet => write!(f, "AOC_YEAR is not set to a valid year."),
+            AocClientError::BadStatus(status) => {
+                write!(f, "adventofcode.com responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "could not reach adventofcode.com: {e}"),
+            AocClientError::IoError(e) => {
+                write!(f, "could not write output files to file system: {e}")
+            }
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IoError(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+/// The response of the site to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubmissionOutcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, the site asks to wait for the given time.
+    RateLimited(Option<Duration>),
+    /// The part was already solved, or part two is not unlocked yet.
+    WrongLevel,
+    /// The response could not be classified, contains the text of the response.
+    Unknown(String),
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmissionOutcome::Wrong => write!(f, "That's not the right answer."),
+            SubmissionOutcome::TooHigh => write!(f, "That's not the right answer, it is too high."),
+            SubmissionOutcome::TooLow => write!(f, "That's not the right answer, it is too low."),
+            SubmissionOutcome::RateLimited(Some(wait)) => {
+                write!(
+                    f,
+                    "You gave an answer too recently, wait {}s.",
+                    wait.as_secs()
+                )
+            }
+            SubmissionOutcome::RateLimited(None) => write!(f, "You gave an answer too recently."),
+            SubmissionOutcome::WrongLevel => write!(
+                f,
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+            SubmissionOutcome::Unknown(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: u16,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(base_url: &str, session: &str, year: u16) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            year,
+        }
+    }
+
+    /// Creates a client from `AOC_BASE_URL`, `AOC_YEAR` and the session cookie.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let year = get_year().ok_or(AocClientError::YearNotSet)?;
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+        Ok(Self::new(&base_url, &session, year))
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = self.agent.get(url).set("Cookie", &self.cookie()).call()?;
+        Ok(response.into_string()?)
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Fetches the puzzle description of a day, converted to markdown.
+    pub fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(day))?;
+        Ok(html_to_markdown(&html))
+    }
+
+    /// Submits an answer to one part of a day.
+    pub fn submit(
+        &self,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionOutcome, AocClientError> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(day)))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+        Ok(parse_submission(&html_to_markdown(
+            &response.into_string()?,
+        )))
+    }
+}
+
+fn get_year() -> Option<u16> {
+    match env::var("AOC_YEAR") {
+        Ok(x) => x.parse().ok().or(None),
+        Err(_) => None,
+    }
+}
+
+fn get_session() -> Option<String> {
+    let session = env::var("AOC_SESSION").ok().or_else(|| {
+        let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+        fs::read_to_string(PathBuf::from(home).join(".adventofcode.session")).ok()
+    })?;
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+fn get_input_path(day: Day) -> String {
+    format!("data/inputs/{day}.txt")
+}
+
+fn get_puzzle_path(day: Day) -> String {
+    format!("data/puzzles/{day}.md")
+}
+
+/// Writes `contents` to `path`, creating the missing directories of a fresh checkout first.
+fn write_file(path: &str, contents: &str) -> io::Result<()> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+pub fn download(day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let input_path = get_input_path(day);
+    let puzzle_path = get_puzzle_path(day);
+
+    write_file(&input_path, &client.input(day)?)?;
+    write_file(&puzzle_path, &client.puzzle(day)?)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+pub fn read(day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let puzzle = client.puzzle(day)?;
+    write_file(&get_puzzle_path(day), &puzzle)?;
+    println!("{puzzle}");
+    Ok(())
+}
+
+pub fn submit(day: Day, part: u8, answer: &str) -> Result<SubmissionOutcome, AocClientError> {
+    AocClient::from_env()?.submit(day, part, answer)
+}
+
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let amount = before.rsplit("You have ").next()?;
+
+    amount
+        .split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+fn parse_submission(text: &str) -> SubmissionOutcome {
+    if text.contains("That's the right answer") {
+        SubmissionOutcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            SubmissionOutcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            SubmissionOutcome::TooLow
+        } else {
+            SubmissionOutcome::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        SubmissionOutcome::RateLimited(parse_wait(text))
+    } else if text.contains("You don't seem to be solving the right level") {
+        SubmissionOutcome::WrongLevel
+    } else {
+        SubmissionOutcome::Unknown(text.trim().to_string())
+    }
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Converts the `<article>` elements of a page to markdown. Everything outside of articles is dropped.
+fn html_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut rest = html;
+    let mut article_depth: usize = 0;
+    let mut in_pre = false;
+
+    while let Some(start) = rest.find('<') {
+        let text = &rest[..start];
+        // Line breaks between block elements are formatting of the page, not content.
+        let is_layout = !in_pre && text.trim().is_empty() && text.contains('\n');
+        if article_depth > 0 && !is_layout {
+            markdown.push_str(&decode_entities(text));
+        }
+
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), is_closing) {
+            ("article", false) => article_depth += 1,
+            // A stray closing tag must not end up below zero and let the text around the articles in.
+            ("article", true) if article_depth > 0 => {
+                article_depth -= 1;
+                markdown.push('\n');
+            }
+            _ if article_depth == 0 => {}
+            ("h2", false) => markdown.push_str("## "),
+            ("h2" | "p", true) => markdown.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                markdown.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => markdown.push('`'),
+            ("em", _) => markdown.push('*'),
+            ("li", false) => markdown.push_str("- "),
+            ("li", true) => markdown.push('\n'),
+            ("ul", true) => markdown.push('\n'),
+            _ => {}
+        }
+    }
+
+    markdown.trim().to_string() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{html_to_markdown, parse_submission, write_file, AocClient, SubmissionOutcome};
+    use crate::day;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+    use std::time::Duration;
+
+    /// Serves a single request with the given body, returns the base url and a handle resolving to the raw request.
+    fn serve_once(body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn creates_missing_directories() {
+        let dir = std::env::temp_dir().join(format!("aoc-client-{}", std::process::id()));
+        let path = dir.join("data/puzzles/05.md");
+        write_file(path.to_str().unwrap(), "puzzle").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "puzzle");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (base_url, handle) = serve_once("1\n2\n3\n");
+        let client = AocClient::new(&base_url, "secret", 2024);
+
+        assert_eq!(client.input(day!(5)).unwrap(), "1\n2\n3\n");
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/5/input HTTP/1.1"));
+        assert!(request.contains("session=secret"));
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (base_url, handle) = serve_once(
+            "<main><article><p>That's not the right answer; your answer is too low.</p></article></main>",
+        );
+        let client = AocClient::new(&base_url, "secret", 2024);
+
+        assert_eq!(
+            client.submit(day!(5), 2, "42").unwrap(),
+            SubmissionOutcome::TooLow
+        );
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/5/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn parses_submission_outcomes() {
+        assert_eq!(
+            parse_submission("That's the right answer! You are one gold star closer."),
+            SubmissionOutcome::Correct
+        );
+        assert_eq!(
+            parse_submission("That's not the right answer; your answer is too high."),
+            SubmissionOutcome::TooHigh
+        );
+        assert_eq!(
+            parse_submission("That's not the right answer. If you're stuck, ..."),
+            SubmissionOutcome::Wrong
+        );
+        assert_eq!(
+            parse_submission("You gave an answer too recently. You have 1m 5s left to wait."),
+            SubmissionOutcome::RateLimited(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            parse_submission("You don't seem to be solving the right level."),
+            SubmissionOutcome::WrongLevel
+        );
+    }
+
+    #[test]
+    fn converts_articles_to_markdown() {
+        let html = r#"<html><body><header>skip</header><main>
+<article class="day-desc"><h2>--- Day 1: Test ---</h2><p>Find <em>the</em> <code>x &lt; 3</code>:</p>
+<pre><code>1 &amp; 2
+</code></pre>
+<ul><li>one</li><li>two</li></ul></article>
+<p>Your puzzle answer was <code>1</code>.</p></main></body></html>"#;
+        assert_eq!(
+            html_to_markdown(html),
+            "## --- Day 1: Test ---\n\nFind *the* `x < 3`:\n\n```\n1 & 2\n```\n\n- one\n- two\n"
+        );
+    }
+
+    #[test]
+    fn ignores_unbalanced_closing_articles() {
+        let html = "<main></article><p>outside</p><article><p>inside</p></article></article><p>after</p></main>";
+        assert_eq!(html_to_markdown(html), "inside\n");
+    }
+}
@@ -0,0 +1,280 @@
+/// Module that persists the timings of every `cargo all --time` run and detects regressions between runs.
+/// The history is stored as JSON lines, one record per solution part.
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::readme_benchmarks::Timings;
+use crate::Day;
+
+static HISTORY_PATH: &str = "data/benchmarks.jsonl";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Parser(e.to_string())
+    }
+}
+
+/// Timing of one solution part in one benchmark run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: Day,
+    pub part: u8,
+    /// Short hash of the git commit the run was made on, `unknown` outside of a git checkout.
+    pub revision: String,
+    /// Unix timestamp (in seconds) of the run. All records of one run share the same timestamp.
+    pub timestamp: u64,
+    pub release: bool,
+    pub nanos: f64,
+}
+
+/// A solution part that got slower than the baseline by more than the threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: Day,
+    pub part: u8,
+    pub baseline_nanos: f64,
+    pub current_nanos: f64,
+}
+
+impl Regression {
+    /// Relative change versus the baseline in percent.
+    #[must_use]
+    pub fn change_percent(&self) -> f64 {
+        (self.current_nanos / self.baseline_nanos - 1.0) * 100.0
+    }
+}
+
+/// A set of records that were appended by the same run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub revision: String,
+    pub timestamp: u64,
+    pub release: bool,
+    pub records: Vec<Record>,
+}
+
+fn get_revision() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".into())
+}
+
+fn get_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|x| x.as_secs())
+        .unwrap_or_default()
+}
+
+fn to_records(timings: &[Timings], revision: &str, timestamp: u64, release: bool) -> Vec<Record> {
+    timings
+        .iter()
+        .flat_map(|timing| {
+            [(1, timing.part_1_nanos), (2, timing.part_2_nanos)]
+                .into_iter()
+                .filter_map(move |(part, nanos)| {
+                    nanos.map(|nanos| Record {
+                        day: timing.day,
+                        part,
+                        revision: revision.to_string(),
+                        timestamp,
+                        release,
+                        nanos,
+                    })
+                })
+        })
+        .collect()
+}
+
+/// Appends the timings of a run to the history file.
+pub fn append(timings: &[Timings], release: bool) -> Result<(), Error> {
+    let records = to_records(timings, &get_revision(), get_timestamp(), release);
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_PATH)?;
+
+    for record in records {
+        writeln!(file, "{}", serde_json::to_string(&record)?)?;
+    }
+
+    Ok(())
+}
+
+fn parse_records(s: &str) -> Result<Vec<Record>, Error> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(Error::from))
+        .collect()
+}
+
+/// Reads all runs from the history file, oldest first.
+pub fn load() -> Result<Vec<Run>, Error> {
+    let content = match fs::read_to_string(HISTORY_PATH) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(group_runs(parse_records(&content)?))
+}
+
+fn group_runs(records: Vec<Record>) -> Vec<Run> {
+    let mut runs: Vec<Run> = vec![];
+
+    for record in records {
+        match runs.last_mut() {
+            Some(run) if run.timestamp == record.timestamp && run.revision == record.revision => {
+                run.records.push(record);
+            }
+            _ => runs.push(Run {
+                revision: record.revision.clone(),
+                timestamp: record.timestamp,
+                release: record.release,
+                records: vec![record],
+            }),
+        }
+    }
+
+    runs
+}
+
+/// Finds the run the latest run should be compared against.
+/// This is the most recent earlier run built with the same profile, optionally restricted to a git revision.
+#[must_use]
+pub fn find_baseline<'a>(runs: &'a [Run], revision: Option<&str>) -> Option<&'a Run> {
+    let (current, previous) = runs.split_last()?;
+
+    previous.iter().rev().find(|run| {
+        run.release == current.release && revision.is_none_or(|rev| run.revision.starts_with(rev))
+    })
+}
+
+/// Lists the parts of `current` that are slower than in `baseline` by more than `threshold_percent`.
+#[must_use]
+pub fn regressions(baseline: &Run, current: &Run, threshold_percent: f64) -> Vec<Regression> {
+    current
+        .records
+        .iter()
+        .filter_map(|record| {
+            let base = baseline
+                .records
+                .iter()
+                .find(|x| x.day == record.day && x.part == record.part)?;
+
+            let regression = Regression {
+                day: record.day,
+                part: record.part,
+                baseline_nanos: base.nanos,
+                current_nanos: record.nanos,
+            };
+
+            (regression.change_percent() > threshold_percent).then_some(regression)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_baseline, group_runs, parse_records, regressions, to_records, Record};
+    use crate::day;
+    use crate::template::readme_benchmarks::Timings;
+
+    fn record(day: u8, part: u8, revision: &str, timestamp: u64, nanos: f64) -> Record {
+        Record {
+            day: crate::Day::new(day).unwrap(),
+            part,
+            revision: revision.into(),
+            timestamp,
+            release: true,
+            nanos,
+        }
+    }
+
+    #[test]
+    fn converts_timings_to_records() {
+        let timings = vec![Timings {
+            day: day!(3),
+            part_1: Some("10ms".into()),
+            part_2: None,
+            part_1_nanos: Some(1e+7),
+            part_2_nanos: None,
+            total_nanos: 1e+7,
+        }];
+        let records = to_records(&timings, "abc1234", 42, true);
+        assert_eq!(records, vec![record(3, 1, "abc1234", 42, 1e+7)]);
+    }
+
+    #[test]
+    fn round_trips_records() {
+        let line = serde_json::to_string(&record(3, 2, "abc1234", 42, 15.5)).unwrap();
+        assert_eq!(
+            line,
+            r#"{"day":3,"part":2,"revision":"abc1234","timestamp":42,"release":true,"nanos":15.5}"#
+        );
+        assert_eq!(
+            parse_records(&format!("{line}\n\n")).unwrap(),
+            vec![record(3, 2, "abc1234", 42, 15.5)]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_day() {
+        let line = r#"{"day":26,"part":2,"revision":"abc1234","timestamp":42,"release":true,"nanos":15.5}"#;
+        assert!(parse_records(line).is_err());
+    }
+
+    #[test]
+    fn detects_regressions_against_previous_run() {
+        let runs = group_runs(vec![
+            record(1, 1, "aaaaaaa", 1, 100.0),
+            record(1, 2, "aaaaaaa", 1, 100.0),
+            record(1, 1, "bbbbbbb", 2, 100.0),
+            record(1, 2, "bbbbbbb", 2, 100.0),
+            record(1, 1, "ccccccc", 3, 105.0),
+            record(1, 2, "ccccccc", 3, 150.0),
+        ]);
+        assert_eq!(runs.len(), 3);
+
+        let baseline = find_baseline(&runs, None).unwrap();
+        assert_eq!(baseline.revision, "bbbbbbb");
+
+        let found = regressions(baseline, runs.last().unwrap(), 10.0);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].part, 2);
+        assert_eq!(found[0].change_percent(), 50.0);
+    }
+
+    #[test]
+    fn finds_baseline_by_revision() {
+        let runs = group_runs(vec![
+            record(1, 1, "aaaaaaa", 1, 100.0),
+            record(1, 1, "bbbbbbb", 2, 100.0),
+            record(1, 1, "ccccccc", 3, 100.0),
+        ]);
+        assert_eq!(find_baseline(&runs, Some("aaa")).unwrap().timestamp, 1);
+        assert!(find_baseline(&runs, Some("ccc")).is_none());
+    }
+}
@@ -0,0 +1,113 @@
+/// Command line interface of a year's binary, see the `cargo` aliases in `.cargo/config.toml`.
+use std::process;
+
+use crate::template::commands::{all, compare, download, read, scaffold, solve};
+use crate::template::output::OutputFormat;
+use crate::template::runner::Solution;
+use crate::Day;
+
+pub enum AppArguments {
+    Download {
+        day: Day,
+    },
+    Read {
+        day: Day,
+    },
+    Scaffold {
+        day: Day,
+    },
+    Solve {
+        day: Day,
+        time: bool,
+        submit: Option<u8>,
+        format: OutputFormat,
+        record: bool,
+    },
+    All {
+        time: bool,
+        format: OutputFormat,
+        record: bool,
+    },
+    Compare {
+        threshold: f64,
+        baseline: Option<String>,
+    },
+}
+
+pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    let mut args = pico_args::Arguments::from_env();
+
+    let app_args = match args.subcommand()?.as_deref() {
+        Some("all") => AppArguments::All {
+            time: args.contains("--time"),
+            format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            record: args.contains("--record"),
+        },
+        Some("compare") => AppArguments::Compare {
+            threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10.0),
+            baseline: args.opt_value_from_str("--baseline")?,
+        },
+        Some("download") => AppArguments::Download {
+            day: args.free_from_str()?,
+        },
+        Some("read") => AppArguments::Read {
+            day: args.free_from_str()?,
+        },
+        Some("scaffold") => AppArguments::Scaffold {
+            day: args.free_from_str()?,
+        },
+        Some("solve") => AppArguments::Solve {
+            day: args.free_from_str()?,
+            submit: args.opt_value_from_str("--submit")?,
+            time: args.contains("--time"),
+            format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            record: args.contains("--record"),
+        },
+        Some(x) => {
+            eprintln!("Unknown command: {x}");
+            process::exit(1);
+        }
+        None => {
+            eprintln!("No command specified.");
+            process::exit(1);
+        }
+    };
+
+    let remaining = args.finish();
+    if !remaining.is_empty() {
+        eprintln!("Warning: unknown argument(s): {remaining:?}.");
+    }
+
+    Ok(app_args)
+}
+
+/// Runs the command given on the command line against the solutions of a year.
+pub fn main(solutions: &[Solution]) {
+    match parse() {
+        Err(err) => {
+            eprintln!("Error: {err}");
+            std::process::exit(1);
+        }
+        Ok(args) => match args {
+            AppArguments::All {
+                time,
+                format,
+                record,
+            } => all::handle(solutions, time, format, record),
+            AppArguments::Compare {
+                threshold,
+                baseline,
+            } => compare::handle(threshold, baseline.as_deref()),
+            AppArguments::Download { day } => download::handle(day),
+            AppArguments::Read { day } => read::handle(day),
+            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::Solve {
+                day,
+                time,
+                submit,
+                format,
+                record,
+            } => solve::handle(solutions, day, time, submit, format, record),
+        },
+    };
+}
@@ -0,0 +1,168 @@
+use std::process;
+
+use crate::template::{
+    answers, benchmark_history,
+    output::{self, OutputFormat},
+    readme_benchmarks::{self, Timings},
+    runner::{PartResult, RunOptions, Solution},
+    try_read_file, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use crate::{all_days, Day};
+
+pub fn handle(solutions: &[Solution], is_timed: bool, format: OutputFormat, is_recording: bool) {
+    let is_release = !cfg!(debug_assertions);
+    let is_text = format == OutputFormat::Text;
+    let options = RunOptions {
+        is_timed,
+        is_printed: is_text,
+    };
+    let mut timings: Vec<Timings> = vec![];
+    let mut mismatched_days: Vec<Day> = vec![];
+
+    output::print_header(format);
+
+    all_days().for_each(|day| {
+        if is_text {
+            if day > 1 {
+                println!();
+            }
+
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+            println!("------");
+        }
+
+        let Some(solution) = solutions.iter().find(|solution| solution.day == day) else {
+            if is_text {
+                println!("Not solved.");
+            }
+            return;
+        };
+
+        let Ok(input) = try_read_file("inputs", day) else {
+            if is_text {
+                println!("Missing input.");
+            } else {
+                eprintln!("Missing input for day {day}.");
+            }
+            return;
+        };
+
+        let results = solution.run(&input, options);
+        let (answers, is_verified) = answers::check(day, &results, is_recording, is_text);
+        output::print_results(format, day, &results, &answers);
+        timings.push(get_timings(day, &results));
+
+        if !is_verified {
+            mismatched_days.push(day);
+        }
+    });
+
+    if is_timed {
+        let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+
+        if is_text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
+
+        match benchmark_history::append(&timings, is_release) {
+            Ok(()) if is_text => println!("Successfully appended timings to benchmark history."),
+            Ok(()) => {}
+            Err(_) => {
+                eprintln!("Failed to append timings to benchmark history.");
+            }
+        }
+
+        if is_release {
+            match readme_benchmarks::update(timings, total_millis) {
+                Ok(()) if is_text => println!("Successfully updated README with benchmarks."),
+                Ok(()) => {}
+                Err(_) => {
+                    eprintln!("Failed to update readme with benchmarks.");
+                }
+            }
+        }
+    }
+
+    if !mismatched_days.is_empty() {
+        let days: Vec<String> = mismatched_days.iter().map(Day::to_string).collect();
+        eprintln!(
+            "Answers no longer match the recorded ones for day(s): {}",
+            days.join(", ")
+        );
+        process::exit(1);
+    }
+}
+
+/// Collects the timings of the solved parts of a day.
+fn get_timings(day: Day, results: &[PartResult]) -> Timings {
+    let mut timings = Timings {
+        day,
+        part_1: None,
+        part_2: None,
+        part_1_nanos: None,
+        part_2_nanos: None,
+        total_nanos: 0_f64,
+    };
+
+    for result in results.iter().filter(|result| result.answer.is_some()) {
+        let timing_str = format!("{:.1?}", result.stats.mean);
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = result.stats.mean.as_nanos() as f64;
+
+        if result.part == 1 {
+            timings.part_1 = Some(timing_str);
+            timings.part_1_nanos = Some(nanos);
+        } else {
+            timings.part_2 = Some(timing_str);
+            timings.part_2_nanos = Some(nanos);
+        }
+
+        timings.total_nanos += nanos;
+    }
+
+    timings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_timings;
+    use std::time::Duration;
+
+    use crate::day;
+    use crate::template::runner::{BenchStats, PartResult, Status};
+
+    fn result(part: u8, answer: Option<&str>, nanos: u64) -> PartResult {
+        PartResult {
+            part,
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+            answer: answer.map(Into::into),
+            stats: BenchStats::from_samples(&[Duration::from_nanos(nanos)]),
+        }
+    }
+
+    #[test]
+    fn collects_timings() {
+        let res = get_timings(
+            day!(1),
+            &[result(1, Some("0"), 74), result(2, Some("10"), 74_130_000)],
+        );
+        assert_eq!(res.total_nanos, 74_130_074_f64);
+        assert_eq!(res.part_1.unwrap(), "74.0ns");
+        assert_eq!(res.part_2.unwrap(), "74.1ms");
+        assert_eq!(res.part_2_nanos, Some(74_130_000_f64));
+    }
+
+    #[test]
+    fn skips_unsolved_parts() {
+        let res = get_timings(day!(1), &[result(1, None, 74), result(2, None, 74)]);
+        assert_eq!(res.total_nanos, 0_f64);
+        assert!(res.part_1.is_none());
+        assert!(res.part_2.is_none());
+    }
+}
@@ -0,0 +1,61 @@
+use std::process;
+use std::time::Duration;
+
+use crate::template::benchmark_history::{self, Regression};
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
+}
+
+fn print_regression(regression: &Regression) {
+    println!(
+        "Day {} Part {}: {} -> {} ({ANSI_BOLD}+{:.1}%{ANSI_RESET}) ✖",
+        regression.day,
+        regression.part,
+        format_nanos(regression.baseline_nanos),
+        format_nanos(regression.current_nanos),
+        regression.change_percent()
+    );
+}
+
+pub fn handle(threshold: f64, baseline: Option<&str>) {
+    let runs = match benchmark_history::load() {
+        Ok(runs) => runs,
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {e:?}");
+            process::exit(1);
+        }
+    };
+
+    let Some(current) = runs.last() else {
+        eprintln!("Benchmark history is empty. Run `cargo time` to record a run.");
+        process::exit(1);
+    };
+
+    let Some(base) = benchmark_history::find_baseline(&runs, baseline) else {
+        eprintln!(
+            "No baseline run found to compare {} against.",
+            current.revision
+        );
+        process::exit(1);
+    };
+
+    println!(
+        "Comparing {ANSI_BOLD}{}{ANSI_RESET} against baseline {ANSI_BOLD}{}{ANSI_RESET} (threshold: {threshold}%)",
+        current.revision, base.revision
+    );
+    println!("------");
+
+    let regressions = benchmark_history::regressions(base, current, threshold);
+
+    if regressions.is_empty() {
+        println!("No regressions.");
+        return;
+    }
+
+    regressions.iter().for_each(print_regression);
+    println!("\n{} part(s) regressed.", regressions.len());
+    process::exit(1);
+}
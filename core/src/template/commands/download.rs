@@ -0,0 +1,10 @@
+use crate::template::aoc_client;
+use crate::Day;
+use std::process;
+
+pub fn handle(day: Day) {
+    if let Err(e) = aoc_client::download(day) {
+        eprintln!("failed to download day {day}: {e}");
+        process::exit(1);
+    };
+}
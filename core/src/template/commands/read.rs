@@ -0,0 +1,11 @@
+use std::process;
+
+use crate::template::aoc_client;
+use crate::Day;
+
+pub fn handle(day: Day) {
+    if let Err(e) = aoc_client::read(day) {
+        eprintln!("failed to read day {day}: {e}");
+        process::exit(1);
+    };
+}
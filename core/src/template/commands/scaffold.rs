@@ -15,25 +15,11 @@ pub fn part_one(input: &str) -> Option<u32> {
 pub fn part_two(input: &str) -> Option<u32> {
     None
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-}
 "#;
 
+/// Header of a new example file, fill in the answers to have the example checked by `cargo test`.
+const EXAMPLE_TEMPLATE: &str = "# part1:\n# part2:\n";
+
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
@@ -49,7 +35,7 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
 pub fn handle(day: Day) {
     let input_path = format!("data/inputs/{day}.txt");
     let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+    let module_path = format!("src/days/{day}.rs");
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -83,9 +69,11 @@ pub fn handle(day: Day) {
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    match create_file(&example_path)
+        .and_then(|mut file| file.write_all(EXAMPLE_TEMPLATE.as_bytes()))
+    {
+        Ok(()) => {
+            println!("Created example file \"{}\"", &example_path);
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
@@ -0,0 +1,57 @@
+use std::process;
+
+use crate::template::answers;
+use crate::template::output::{self, OutputFormat};
+use crate::template::runner::{self, RunOptions, Solution};
+use crate::template::try_read_file;
+use crate::Day;
+
+pub fn handle(
+    solutions: &[Solution],
+    day: Day,
+    time: bool,
+    submit_part: Option<u8>,
+    format: OutputFormat,
+    is_recording: bool,
+) {
+    let Some(solution) = solutions.iter().find(|solution| solution.day == day) else {
+        eprintln!("Day {day} has not been scaffolded yet. Try running \"cargo scaffold {day}\".");
+        process::exit(1);
+    };
+
+    let input = match try_read_file("inputs", day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input for day {day}: {e}");
+            process::exit(1);
+        }
+    };
+
+    let options = RunOptions {
+        is_timed: time,
+        is_printed: format == OutputFormat::Text,
+    };
+    let results = solution.run(&input, options);
+    let (answers, _) = answers::check(day, &results, is_recording, options.is_printed);
+
+    output::print_header(format);
+    output::print_results(format, day, &results, &answers);
+
+    let Some(submit_part) = submit_part else {
+        return;
+    };
+
+    match results.iter().find(|result| result.part == submit_part) {
+        Some(runner::PartResult {
+            answer: Some(answer),
+            ..
+        }) => {
+            let _ = runner::submit(day, submit_part, answer);
+        }
+        Some(_) => eprintln!("Part {submit_part} has no answer to submit."),
+        None => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,231 @@
+/// Module that reads example files and checks solutions against the answers declared in them.
+///
+/// An example file may start with header lines of the form `# key: value`. The keys `part1` and `part2` declare the
+/// expected answers, an empty value leaves the answer undeclared. Any other key is a parameter of the example
+/// (e.g. `# width: 11`). The header ends at the first line that does not match, the rest of the file is the example input.
+/// Every `data/examples/{day}.txt` and `data/examples/{day}-*.txt` file belongs to a day.
+use std::{collections::BTreeMap, env, fs, io, str::FromStr};
+
+use crate::template::params::InputKind;
+use crate::template::runner::{RunOptions, Solution};
+use crate::Day;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Example {
+    /// File name of the example, e.g. `03-2.txt`.
+    pub name: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    pub params: BTreeMap<String, String>,
+    pub input: String,
+}
+
+impl Example {
+    #[must_use]
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Parses a parameter declared in the header, returns `None` if it is missing or does not parse.
+    #[must_use]
+    pub fn param<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.params.get(key)?.parse().ok()
+    }
+}
+
+/// A part whose answer differs from the answer declared in its example file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub name: String,
+    pub part: u8,
+    pub expected: String,
+    pub actual: Option<String>,
+}
+
+fn parse_header_line(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.strip_prefix("# ")?.split_once(':')?;
+    let is_key = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    is_key.then(|| (key, value.trim()))
+}
+
+/// Parses the content of an example file.
+#[must_use]
+pub fn parse(name: &str, content: &str) -> Example {
+    let mut example = Example {
+        name: name.to_string(),
+        ..Example::default()
+    };
+    let mut rest = content;
+
+    while let Some((key, value)) = rest.lines().next().and_then(parse_header_line) {
+        match key {
+            "part1" => example.part_1 = (!value.is_empty()).then(|| value.to_string()),
+            "part2" => example.part_2 = (!value.is_empty()).then(|| value.to_string()),
+            _ => {
+                example.params.insert(key.to_string(), value.to_string());
+            }
+        }
+        rest = rest.split_once('\n').map_or("", |(_, rest)| rest);
+    }
+
+    example.input = rest.to_string();
+    example
+}
+
+/// Strips the header of an example file, leaving only the input.
+#[must_use]
+pub fn strip_header(content: &str) -> String {
+    parse("", content).input
+}
+
+/// Reads all example files of a day, sorted by name.
+pub fn discover(day: Day) -> io::Result<Vec<Example>> {
+    let folder = env::current_dir()?.join("data").join("examples");
+    let prefix = day.to_string();
+
+    let mut names: Vec<String> = fs::read_dir(&folder)?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| {
+            name.strip_prefix(&prefix)
+                .and_then(|rest| rest.strip_suffix(".txt"))
+                .is_some_and(|suffix| suffix.is_empty() || suffix.starts_with('-'))
+        })
+        .collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| Ok(parse(&name, &fs::read_to_string(folder.join(&name))?)))
+        .collect()
+}
+
+/// Runs both parts of a solution on an example and lists the parts that do not match the declared answers.
+/// Parts without a declared answer are not run.
+#[must_use]
+pub fn check(solution: &Solution, example: &Example) -> Vec<Mismatch> {
+    let options = RunOptions {
+        is_timed: false,
+        is_printed: false,
+    };
+    [solution.part_one, solution.part_two]
+        .into_iter()
+        .zip(1..)
+        .filter_map(|(func, part)| {
+            let expected = example.expected(part)?;
+            let result = func(&example.input, InputKind::Example(example), options);
+            (result.answer.as_deref() != Some(expected)).then(|| Mismatch {
+                name: example.name.clone(),
+                part,
+                expected: expected.to_string(),
+                actual: result.answer,
+            })
+        })
+        .collect()
+}
+
+/// Checks a solution against all examples of its day, panicking with a list of mismatches.
+/// This is the test that the `solution!` macro generates for every day.
+pub fn assert_examples(solution: &Solution) {
+    let examples = discover(solution.day).expect("could not read example files");
+
+    let mismatches: Vec<String> = examples
+        .iter()
+        .flat_map(|example| check(solution, example))
+        .map(|mismatch| {
+            format!(
+                "{} part {}: expected {}, got {}",
+                mismatch.name,
+                mismatch.part,
+                mismatch.expected,
+                mismatch.actual.as_deref().unwrap_or("no answer")
+            )
+        })
+        .collect();
+
+    assert!(
+        mismatches.is_empty(),
+        "examples do not match:\n{}",
+        mismatches.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, parse, strip_header, Mismatch};
+    use crate::day;
+    use crate::template::runner::{solve_part, Solution};
+
+    #[test]
+    fn parses_header() {
+        let example = parse(
+            "14.txt",
+            "# part1: 12\n# width: 11\n# height: 7\np=0,4 v=3,-3\n",
+        );
+        assert_eq!(example.expected(1), Some("12"));
+        assert_eq!(example.expected(2), None);
+        assert_eq!(example.param::<i32>("width"), Some(11));
+        assert_eq!(example.param::<i32>("depth"), None);
+        assert_eq!(example.input, "p=0,4 v=3,-3\n");
+    }
+
+    #[test]
+    fn keeps_grids_starting_with_hash() {
+        let content = "###\n#.#\n###\n";
+        assert_eq!(strip_header(content), content);
+        assert_eq!(strip_header("# part2: 4\n# .#\n"), "# .#\n");
+    }
+
+    #[test]
+    fn parses_header_only() {
+        let example = parse("01.txt", "# part1: 1\n# part2:");
+        assert_eq!(example.expected(1), Some("1"));
+        assert_eq!(example.expected(2), None);
+        assert_eq!(example.input, "");
+    }
+
+    fn count_lines(input: &str) -> Option<usize> {
+        Some(input.lines().count())
+    }
+
+    fn unsolved(_: &str) -> Option<usize> {
+        None
+    }
+
+    #[test]
+    fn checks_declared_parts() {
+        let solution = Solution {
+            day: day!(1),
+            part_one: |input, _, options| solve_part(count_lines, input, 1, options),
+            part_two: |input, _, options| solve_part(unsolved, input, 2, options),
+        };
+
+        let example = parse("01.txt", "# part1: 2\na\nb\n");
+        assert!(check(&solution, &example).is_empty());
+
+        let example = parse("01-2.txt", "# part1: 3\n# part2: 1\na\nb\n");
+        assert_eq!(
+            check(&solution, &example),
+            vec![
+                Mismatch {
+                    name: "01-2.txt".into(),
+                    part: 1,
+                    expected: "3".into(),
+                    actual: Some("2".into())
+                },
+                Mismatch {
+                    name: "01-2.txt".into(),
+                    part: 2,
+                    expected: "1".into(),
+                    actual: None
+                }
+            ]
+        );
+    }
+}
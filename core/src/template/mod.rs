@@ -0,0 +1,106 @@
+use crate::Day;
+use std::{env, fs, io};
+
+pub mod answers;
+pub mod aoc_client;
+pub mod benchmark_history;
+pub mod cli;
+pub mod commands;
+pub mod examples;
+pub mod output;
+pub mod params;
+pub mod readme_benchmarks;
+pub mod runner;
+pub mod submissions;
+
+pub const ANSI_ITALIC: &str = "\x1b[3m";
+pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_RESET: &str = "\x1b[0m";
+
+/// Helper function that reads a text file to a string.
+/// The header of example files is stripped, see [`examples`].
+#[must_use]
+pub fn read_file(folder: &str, day: Day) -> String {
+    try_read_file(folder, day).expect("could not open input file")
+}
+
+/// Helper function that reads a text file to a string, returning an error if the file is missing.
+pub fn try_read_file(folder: &str, day: Day) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let content = fs::read_to_string(filepath)?;
+    Ok(strip_example_header(folder, content))
+}
+
+/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+#[must_use]
+pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd
+        .join("data")
+        .join(folder)
+        .join(format!("{day}-{part}.txt"));
+    let f = fs::read_to_string(filepath);
+    strip_example_header(folder, f.expect("could not open input file"))
+}
+
+fn strip_example_header(folder: &str, content: String) -> String {
+    if folder == "examples" {
+        examples::strip_header(&content)
+    } else {
+        content
+    }
+}
+
+/// Creates the constant `DAY` and the constant `SOLUTION`, which registers the day with the binary of its year.
+/// Also creates a test that checks the solution against the answers declared in the example files of the day.
+///
+/// With a second argument, `solution!(day, Params)`, the parts take a `&Params` implementing
+/// [`params::PuzzleParams`] after the input, chosen by whether they run on an example or on the real input.
+#[macro_export]
+macro_rules! solution {
+    ($day:expr) => {
+        $crate::solution!(
+            @impl $day,
+            |input, _, options| $crate::template::runner::solve_part(part_one, input, 1, options),
+            |input, _, options| $crate::template::runner::solve_part(part_two, input, 2, options)
+        );
+    };
+    ($day:expr, $params:ty) => {
+        $crate::solution!(
+            @impl $day,
+            |input, kind, options| {
+                use $crate::template::params::PuzzleParams;
+                let params = <$params>::for_input(kind);
+                let func = |input| part_one(input, &params);
+                $crate::template::runner::solve_part(func, input, 1, options)
+            },
+            |input, kind, options| {
+                use $crate::template::params::PuzzleParams;
+                let params = <$params>::for_input(kind);
+                let func = |input| part_two(input, &params);
+                $crate::template::runner::solve_part(func, input, 2, options)
+            }
+        );
+    };
+    (@impl $day:expr, $part_one:expr, $part_two:expr) => {
+        /// The current day.
+        const DAY: $crate::Day = $crate::day!($day);
+
+        /// Entry of the current day in the solution registry of its year.
+        pub const SOLUTION: $crate::template::runner::Solution =
+            $crate::template::runner::Solution {
+                day: DAY,
+                part_one: $part_one,
+                part_two: $part_two,
+            };
+
+        #[cfg(test)]
+        mod example_tests {
+            #[test]
+            fn test_examples() {
+                $crate::template::examples::assert_examples(&super::SOLUTION);
+            }
+        }
+    };
+}
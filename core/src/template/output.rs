@@ -0,0 +1,200 @@
+/// Module that formats solution results as machine-readable records for `--format json|csv`.
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::template::answers::Answers;
+use crate::template::runner::{PartResult, Status};
+use crate::Day;
+
+/// How `solve` and `all` report results on stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Human-readable output, decorated with ANSI escape codes.
+    #[default]
+    Text,
+    /// One JSON object per solution part and line.
+    Json,
+    /// Comma-separated values with a header line.
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `text`, `json` or `csv`")
+    }
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    day: Day,
+    part: u8,
+    answer: Option<&'a str>,
+    status: Status,
+    duration_nanos: u128,
+    /// Samples kept after outlier rejection, the statistics are computed over these.
+    samples: u128,
+    /// Samples rejected as outliers.
+    outliers: usize,
+    /// Whether the answer matches the recorded one, `None` if no answer was recorded.
+    verified: Option<bool>,
+}
+
+impl<'a> Record<'a> {
+    fn new(day: Day, result: &'a PartResult, answers: &Answers) -> Self {
+        Self {
+            day,
+            part: result.part,
+            answer: result.answer.as_deref(),
+            status: result.status,
+            duration_nanos: result.stats.mean.as_nanos(),
+            samples: result.stats.samples,
+            outliers: result.stats.outliers,
+            verified: answers.verify(result),
+        }
+    }
+}
+
+static CSV_HEADER: &str = "day,part,answer,status,duration_nanos,samples,outliers,verified";
+
+fn escape_csv(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn format_record(format: OutputFormat, record: &Record) -> Option<String> {
+    match format {
+        OutputFormat::Text => None,
+        OutputFormat::Json => serde_json::to_string(record).ok(),
+        OutputFormat::Csv => Some(format!(
+            "{},{},{},{},{},{},{},{}",
+            record.day.into_inner(),
+            record.part,
+            escape_csv(record.answer.unwrap_or_default()),
+            record.status,
+            record.duration_nanos,
+            record.samples,
+            record.outliers,
+            record.verified.map(|x| x.to_string()).unwrap_or_default()
+        )),
+    }
+}
+
+/// Prints the header of the format, if it has one. Call this once before printing any results.
+pub fn print_header(format: OutputFormat) {
+    if format == OutputFormat::Csv {
+        println!("{CSV_HEADER}");
+    }
+}
+
+/// Prints one record per part. Does nothing for [`OutputFormat::Text`], which is printed while solving.
+pub fn print_results(format: OutputFormat, day: Day, results: &[PartResult], answers: &Answers) {
+    results
+        .iter()
+        .filter_map(|result| format_record(format, &Record::new(day, result, answers)))
+        .for_each(|line| println!("{line}"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_record, OutputFormat, Record};
+    use crate::day;
+    use crate::template::answers::Answers;
+    use crate::template::runner::{BenchStats, PartResult, Status};
+    use std::time::Duration;
+
+    fn result(status: Status, answer: Option<&str>) -> PartResult {
+        PartResult {
+            part: 2,
+            status,
+            answer: answer.map(Into::into),
+            stats: BenchStats::from_samples(&[Duration::from_nanos(1500)]),
+        }
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert_eq!("csv".parse::<OutputFormat>().unwrap(), OutputFormat::Csv);
+        assert_eq!("text".parse::<OutputFormat>().unwrap(), OutputFormat::Text);
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn formats_json() {
+        let result = result(Status::Solved, Some("42"));
+        let line = format_record(
+            OutputFormat::Json,
+            &Record::new(day!(3), &result, &Answers::default()),
+        );
+        assert_eq!(
+            line.unwrap(),
+            r#"{"day":3,"part":2,"answer":"42","status":"solved","duration_nanos":1500,"samples":1,"outliers":0,"verified":null}"#
+        );
+    }
+
+    #[test]
+    fn formats_csv() {
+        let result = result(Status::Panicked, None);
+        let line = format_record(
+            OutputFormat::Csv,
+            &Record::new(day!(3), &result, &Answers::default()),
+        );
+        assert_eq!(line.unwrap(), "3,2,,panicked,1500,1,0,");
+    }
+
+    #[test]
+    fn escapes_csv() {
+        let result = result(Status::Solved, Some("#.\n\"#\","));
+        let line = format_record(
+            OutputFormat::Csv,
+            &Record::new(day!(3), &result, &Answers::default()),
+        );
+        assert_eq!(line.unwrap(), "3,2,\"#.\n\"\"#\"\",\",solved,1500,1,0,");
+    }
+
+    #[test]
+    fn formats_verification() {
+        let result = result(Status::Solved, Some("42"));
+        let answers = Answers {
+            part_1: None,
+            part_2: Some("43".into()),
+        };
+        let line = format_record(OutputFormat::Csv, &Record::new(day!(3), &result, &answers));
+        assert_eq!(line.unwrap(), "3,2,42,solved,1500,1,0,false");
+    }
+
+    #[test]
+    fn skips_text() {
+        let result = result(Status::Unsolved, None);
+        assert!(format_record(
+            OutputFormat::Text,
+            &Record::new(day!(3), &result, &Answers::default())
+        )
+        .is_none());
+    }
+}
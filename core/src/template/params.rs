@@ -0,0 +1,57 @@
+/// Module for puzzle parameters that differ between the examples and the real input, e.g. the size of a grid.
+/// A day opts in with `solution!(day, Params)`, its parts then take the parameters as a second argument.
+use crate::template::examples::Example;
+
+/// The input a solution part runs on.
+#[derive(Debug, Clone, Copy)]
+pub enum InputKind<'a> {
+    /// The puzzle input in `data/inputs`.
+    Real,
+    /// One of the example files in `data/examples`.
+    Example(&'a Example),
+}
+
+pub trait PuzzleParams: Sized {
+    /// The parameters of the real input.
+    fn real() -> Self;
+
+    /// The parameters of an example, usually read from the `# key: value` lines of its header.
+    fn example(example: &Example) -> Self;
+
+    fn for_input(kind: InputKind) -> Self {
+        match kind {
+            InputKind::Real => Self::real(),
+            InputKind::Example(example) => Self::example(example),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputKind, PuzzleParams};
+    use crate::template::examples::{parse, Example};
+
+    #[derive(Debug, PartialEq)]
+    struct Size(u32);
+
+    impl PuzzleParams for Size {
+        fn real() -> Self {
+            Size(71)
+        }
+
+        fn example(example: &Example) -> Self {
+            Size(example.param("size").unwrap_or(7))
+        }
+    }
+
+    #[test]
+    fn selects_params_by_input() {
+        assert_eq!(Size::for_input(InputKind::Real), Size(71));
+
+        let example = parse("18.txt", "# size: 9\n1,2\n");
+        assert_eq!(Size::for_input(InputKind::Example(&example)), Size(9));
+
+        let example = parse("18-2.txt", "1,2\n");
+        assert_eq!(Size::for_input(InputKind::Example(&example)), Size(7));
+    }
+}
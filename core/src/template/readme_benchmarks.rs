@@ -23,6 +23,8 @@ pub struct Timings {
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_nanos: Option<f64>,
+    pub part_2_nanos: Option<f64>,
     pub total_nanos: f64,
 }
 
@@ -33,7 +35,7 @@ pub struct TablePosition {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    format!("./src/days/{day}.rs")
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -102,7 +104,7 @@ pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
     Ok(())
 }
 
-#[cfg(feature = "test_lib")]
+#[cfg(test)]
 mod tests {
     use super::{update_content, Timings, MARKER};
     use crate::day;
@@ -113,18 +115,24 @@ mod tests {
                 day: day!(1),
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
+                part_1_nanos: Some(10e+6),
+                part_2_nanos: Some(20e+6),
                 total_nanos: 3e+10,
             },
             Timings {
                 day: day!(2),
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
+                part_1_nanos: Some(30e+6),
+                part_2_nanos: Some(40e+6),
                 total_nanos: 7e+10,
             },
             Timings {
                 day: day!(4),
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
+                part_1_nanos: Some(40e+6),
+                part_2_nanos: Some(50e+6),
                 total_nanos: 9e+10,
             },
         ]
@@ -148,7 +156,7 @@ mod tests {
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
         update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        assert!(s.contains("## Benchmarks"));
     }
 
     #[test]
@@ -172,9 +180,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/days/01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/days/02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/days/04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -0,0 +1,392 @@
+/// Encapsulates code that interacts with solution functions.
+use crate::template::params::InputKind;
+use crate::template::submissions::{self, Submission, SubmitError};
+use crate::template::{aoc_client, ANSI_ITALIC, ANSI_RESET};
+use crate::Day;
+use serde::Serialize;
+use std::fmt::Display;
+use std::io::{stdout, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+use std::{cmp, thread};
+
+use super::ANSI_BOLD;
+
+/// Runs one part of a day's solution, see `solve_part`.
+pub type PartFn = fn(&str, InputKind, RunOptions) -> PartResult;
+
+/// A day's solution as compiled into the main binary, see the `solution!` macro.
+pub struct Solution {
+    pub day: Day,
+    pub part_one: PartFn,
+    pub part_two: PartFn,
+}
+
+impl Solution {
+    /// Runs both parts against the real input, see `solve_part`.
+    #[must_use]
+    pub fn run(&self, input: &str, options: RunOptions) -> [PartResult; 2] {
+        [
+            (self.part_one)(input, InputKind::Real, options),
+            (self.part_two)(input, InputKind::Real, options),
+        ]
+    }
+}
+
+/// Controls how `solve_part` executes and reports a solution part.
+#[derive(Debug, Clone, Copy)]
+pub struct RunOptions {
+    /// Bench the part instead of executing it once.
+    pub is_timed: bool,
+    /// Print the human-readable result to stdout.
+    pub is_printed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Panicked,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::Panicked => write!(f, "panicked"),
+        }
+    }
+}
+
+/// The outcome of running one solution part.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub stats: BenchStats,
+}
+
+/// Runs a solution part and returns its result together with its timings.
+/// A panic inside the solution is caught and reported as [`Status::Panicked`].
+pub fn solve_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    part: u8,
+    options: RunOptions,
+) -> PartResult {
+    let part_str = format!("Part {part}");
+
+    let (result, stats) = run_timed(func, input, options, |result| {
+        if options.is_printed {
+            print_result(result, &part_str, "");
+        }
+    });
+
+    if options.is_printed {
+        print_result(&result, &part_str, &format_duration(&stats));
+
+        if stats.samples > 1 {
+            print_stats(&stats);
+        }
+    }
+
+    let (status, answer) = match result {
+        Ok(Some(answer)) => (Status::Solved, Some(answer.to_string())),
+        Ok(None) => (Status::Unsolved, None),
+        Err(_) => (Status::Panicked, None),
+    };
+
+    PartResult {
+        part,
+        status,
+        answer,
+        stats,
+    }
+}
+
+/// Run a solution part. The behavior differs depending on whether we are timing the solution:
+///  1. by default, the function is executed once.
+///  2. with `--time`, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// The solution is not benched if its first execution panicked.
+fn run_timed<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    options: RunOptions,
+    hook: impl Fn(&thread::Result<T>),
+) -> (thread::Result<T>, BenchStats) {
+    let timer = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| func(input.clone())));
+    let base_time = timer.elapsed();
+
+    hook(&result);
+
+    let stats = if options.is_timed && result.is_ok() {
+        bench(func, input, &base_time, options.is_printed)
+    } else {
+        BenchStats::from_samples(&[base_time])
+    };
+
+    (result, stats)
+}
+
+/// Summary of the samples collected while benching a solution part.
+/// All values except `min` are computed after outliers have been rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchStats {
+    pub mean: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    /// Number of samples the statistics are based on.
+    pub samples: u128,
+    /// Number of samples that were rejected as outliers.
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    /// Computes statistics over the samples. Samples outside of the Tukey fences
+    /// (1.5 times the interquartile range below the first or above the third quartile) are rejected as outliers.
+    ///
+    /// # Panics
+    /// Panics if `samples` is empty.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(
+            !samples.is_empty(),
+            "cannot compute statistics without samples"
+        );
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let q1 = percentile(&sorted, 25.0).as_nanos();
+        let q3 = percentile(&sorted, 75.0).as_nanos();
+        let fence = (q3 - q1) * 3 / 2;
+        let lower = q1.saturating_sub(fence);
+        let upper = q3 + fence;
+
+        let kept: Vec<Duration> = sorted
+            .iter()
+            .copied()
+            .filter(|x| (lower..=upper).contains(&x.as_nanos()))
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let mean_nanos =
+            kept.iter().map(Duration::as_nanos).sum::<u128>() as f64 / kept.len() as f64;
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = kept
+            .iter()
+            .map(|x| (x.as_nanos() as f64 - mean_nanos).powi(2))
+            .sum::<f64>()
+            / kept.len() as f64;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let from_nanos = |nanos: f64| Duration::from_nanos(nanos.round() as u64);
+
+        Self {
+            mean: from_nanos(mean_nanos),
+            min: sorted[0],
+            median: percentile(&kept, 50.0),
+            p95: percentile(&kept, 95.0),
+            stddev: from_nanos(variance.sqrt()),
+            samples: kept.len() as u128,
+            outliers: sorted.len() - kept.len(),
+        }
+    }
+}
+
+/// Nearest-rank percentile of an already sorted, non-empty slice.
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Number of warmup iterations executed before sampling starts, as a fraction of the sampled iterations.
+const WARMUP_FRACTION: u128 = 10;
+
+/// Only prints its progress if `is_printed`, so structured output on stdout stays parseable.
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    is_printed: bool,
+) -> BenchStats {
+    if is_printed {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
+
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+
+    // warmup runs are discarded so that cold caches and frequency scaling do not skew the samples.
+    for _ in 0..cmp::max(bench_iterations / WARMUP_FRACTION, 1) {
+        func(input.clone());
+    }
+
+    let mut timers: Vec<Duration> = vec![];
+
+    for _ in 0..bench_iterations {
+        // need a clone here to make the borrow checker happy.
+        let cloned = input.clone();
+        let timer = Instant::now();
+        func(cloned);
+        timers.push(timer.elapsed());
+    }
+
+    BenchStats::from_samples(&timers)
+}
+
+fn format_duration(stats: &BenchStats) -> String {
+    if stats.samples == 1 {
+        format!(" ({:.1?})", stats.mean)
+    } else {
+        format!(" ({:.1?} @ {} samples)", stats.mean, stats.samples)
+    }
+}
+
+fn print_stats(stats: &BenchStats) {
+    println!(
+        "  {ANSI_ITALIC}min {:.1?} · median {:.1?} · p95 {:.1?} · stddev {:.1?} · {} outliers rejected{ANSI_RESET}",
+        stats.min, stats.median, stats.p95, stats.stddev, stats.outliers
+    );
+}
+
+fn print_result<T: Display>(result: &thread::Result<Option<T>>, part: &str, duration_str: &str) {
+    let is_intermediate_result = duration_str.is_empty();
+
+    match result {
+        Ok(Some(result)) => {
+            if result.to_string().contains('\n') {
+                let str = format!("{part}: ▼ {duration_str}");
+                if is_intermediate_result {
+                    print!("{str}");
+                } else {
+                    print!("\r");
+                    println!("{str}");
+                    println!("{result}");
+                }
+            } else {
+                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
+                if is_intermediate_result {
+                    print!("{str}");
+                } else {
+                    print!("\r");
+                    println!("{str}");
+                }
+            }
+        }
+        Ok(None) => {
+            if is_intermediate_result {
+                print!("{part}: ✖");
+            } else {
+                print!("\r");
+                println!("{part}: ✖             ");
+            }
+        }
+        Err(_) => {
+            if !is_intermediate_result {
+                println!("{part}: ✖ (panicked)");
+            }
+        }
+    }
+}
+
+/// Submit the answer to one part of a solution and print the response of the site.
+/// Answers that are known to be wrong from earlier submissions are not sent, the reason is printed instead.
+/// Nothing is sent while the site still makes the account wait after a submission of any day,
+/// or when the earlier submissions can't be read.
+pub fn submit(
+    day: Day,
+    part: u8,
+    answer: &str,
+) -> Result<aoc_client::SubmissionOutcome, SubmitError> {
+    let result = submissions::load(day)
+        .and_then(|history| Ok((history, submissions::load_all()?)))
+        .map_err(SubmitError::from)
+        .and_then(|(history, account)| {
+            submissions::check(&history, part, answer)?;
+            submissions::check_cooldown(&account, submissions::get_timestamp())?;
+            println!("Submitting result...");
+            Ok((history, aoc_client::submit(day, part, answer)?))
+        });
+
+    let (history, outcome) = match result {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("{e}");
+            return Err(e);
+        }
+    };
+
+    println!("{outcome}");
+
+    let submission = Submission::new(part, answer, outcome.clone());
+    if let Err(e) = submissions::append(day, &submission) {
+        eprintln!("Failed to record submission for day {day}: {e}");
+    }
+
+    if matches!(
+        outcome,
+        aoc_client::SubmissionOutcome::TooHigh | aoc_client::SubmissionOutcome::TooLow
+    ) {
+        let mut history = history;
+        history.push(submission);
+        println!("Known bounds: {}", submissions::bounds(&history, part));
+    }
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BenchStats;
+    use std::time::Duration;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_nanos(x)).collect()
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = BenchStats::from_samples(&nanos(&[42]));
+        assert_eq!(stats.mean, Duration::from_nanos(42));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p95, Duration::from_nanos(42));
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn summary_statistics() {
+        let stats = BenchStats::from_samples(&nanos(&[40, 10, 30, 20]));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.mean, Duration::from_nanos(25));
+        assert_eq!(stats.median, Duration::from_nanos(20));
+        assert_eq!(stats.p95, Duration::from_nanos(40));
+        assert_eq!(stats.stddev.as_nanos(), 11);
+        assert_eq!(stats.samples, 4);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[100, 101, 99, 100, 102, 98, 100, 5000]));
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.samples, 7);
+        assert_eq!(stats.mean, Duration::from_nanos(100));
+        assert_eq!(stats.p95, Duration::from_nanos(102));
+    }
+}
@@ -0,0 +1,428 @@
+/// Module that keeps a local log of submitted answers and the responses of the site.
+/// The log guards `--submit` against answers that are known to be wrong, so that they don't cost a cooldown.
+/// Submissions live in `data/submissions/{day}.jsonl`, one JSON record per submission.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::aoc_client::{AocClientError, SubmissionOutcome};
+use crate::Day;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "invalid submission record: {e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Parser(e.to_string())
+    }
+}
+
+/// One answer that was sent to the site.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+    /// Unix timestamp (in seconds) of the submission.
+    pub timestamp: u64,
+}
+
+/// The reason an answer is not sent to the site.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    AlreadyRejected(SubmissionOutcome),
+    /// The answer is not lower than an answer that was too high.
+    AboveBound(String),
+    /// The answer is not higher than an answer that was too low.
+    BelowBound(String),
+    RateLimited(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "the part was already solved with \"{answer}\".")
+            }
+            Refusal::AlreadyRejected(outcome) => {
+                write!(f, "the answer was submitted before: {outcome}")
+            }
+            Refusal::AboveBound(bound) => {
+                write!(f, "the answer is too high, \"{bound}\" already was.")
+            }
+            Refusal::BelowBound(bound) => {
+                write!(f, "the answer is too low, \"{bound}\" already was.")
+            }
+            Refusal::RateLimited(wait) => {
+                write!(f, "the site asked to wait another {}s.", wait.as_secs())
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    /// The earlier submissions could not be read, so the answer can't be checked against them.
+    History(Error),
+    Client(AocClientError),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "refusing to submit, {refusal}"),
+            SubmitError::History(e) => {
+                write!(
+                    f,
+                    "refusing to submit, failed to read earlier submissions: {e}"
+                )
+            }
+            SubmitError::Client(e) => write!(f, "failed to submit result: {e}"),
+        }
+    }
+}
+
+impl From<Refusal> for SubmitError {
+    fn from(refusal: Refusal) -> Self {
+        SubmitError::Refused(refusal)
+    }
+}
+
+impl From<Error> for SubmitError {
+    fn from(e: Error) -> Self {
+        SubmitError::History(e)
+    }
+}
+
+impl From<AocClientError> for SubmitError {
+    fn from(e: AocClientError) -> Self {
+        SubmitError::Client(e)
+    }
+}
+
+/// The range a numeric answer has to lie in, according to the too high / too low responses so far.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bounds {
+    /// The highest answer that was too low.
+    pub lower: Option<i128>,
+    /// The lowest answer that was too high.
+    pub upper: Option<i128>,
+}
+
+impl Display for Bounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.lower, self.upper) {
+            (Some(lower), Some(upper)) => write!(f, "{lower} < answer < {upper}"),
+            (Some(lower), None) => write!(f, "answer > {lower}"),
+            (None, Some(upper)) => write!(f, "answer < {upper}"),
+            (None, None) => write!(f, "no known bounds"),
+        }
+    }
+}
+
+impl Submission {
+    #[must_use]
+    pub fn new(part: u8, answer: &str, outcome: SubmissionOutcome) -> Self {
+        Self {
+            part,
+            answer: answer.to_string(),
+            outcome,
+            timestamp: get_timestamp(),
+        }
+    }
+}
+
+/// The site makes the account wait at least a minute after a wrong answer.
+const WRONG_ANSWER_WAIT: Duration = Duration::from_secs(60);
+
+fn get_dir() -> PathBuf {
+    PathBuf::from("data").join("submissions")
+}
+
+fn get_path(day: Day) -> PathBuf {
+    get_dir().join(format!("{day}.jsonl"))
+}
+
+#[must_use]
+pub fn get_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|x| x.as_secs())
+        .unwrap_or_default()
+}
+
+fn parse_submissions(s: &str) -> Result<Vec<Submission>, Error> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(Error::from))
+        .collect()
+}
+
+/// Reads all submissions of a day, oldest first.
+pub fn load(day: Day) -> Result<Vec<Submission>, Error> {
+    match fs::read_to_string(get_path(day)) {
+        Ok(content) => parse_submissions(&content),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Reads the submissions of every day of the year, oldest first.
+pub fn load_all() -> Result<Vec<Submission>, Error> {
+    let entries = match fs::read_dir(get_dir()) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut submissions = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "jsonl") {
+            submissions.extend(parse_submissions(&fs::read_to_string(path)?)?);
+        }
+    }
+    submissions.sort_by_key(|x| x.timestamp);
+    Ok(submissions)
+}
+
+pub fn append(day: Day, submission: &Submission) -> Result<(), Error> {
+    let path = get_path(day);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(submission)?)?;
+    Ok(())
+}
+
+/// Computes the bounds of a part from the numeric answers that were too high or too low.
+#[must_use]
+pub fn bounds(history: &[Submission], part: u8) -> Bounds {
+    history
+        .iter()
+        .filter(|submission| submission.part == part)
+        .fold(Bounds::default(), |bounds, submission| {
+            let Ok(answer) = submission.answer.trim().parse::<i128>() else {
+                return bounds;
+            };
+            match submission.outcome {
+                SubmissionOutcome::TooLow => Bounds {
+                    lower: Some(bounds.lower.map_or(answer, |x| x.max(answer))),
+                    ..bounds
+                },
+                SubmissionOutcome::TooHigh => Bounds {
+                    upper: Some(bounds.upper.map_or(answer, |x| x.min(answer))),
+                    ..bounds
+                },
+                _ => bounds,
+            }
+        })
+}
+
+/// Checks an answer against the submissions of its day so far.
+pub fn check(history: &[Submission], part: u8, answer: &str) -> Result<(), Refusal> {
+    let part_history: Vec<&Submission> = history.iter().filter(|x| x.part == part).collect();
+
+    if let Some(solved) = part_history
+        .iter()
+        .find(|x| x.outcome == SubmissionOutcome::Correct)
+    {
+        return Err(Refusal::AlreadySolved(solved.answer.clone()));
+    }
+
+    if let Some(rejected) = part_history.iter().find(|x| {
+        x.answer == answer
+            && matches!(
+                x.outcome,
+                SubmissionOutcome::Wrong | SubmissionOutcome::TooHigh | SubmissionOutcome::TooLow
+            )
+    }) {
+        return Err(Refusal::AlreadyRejected(rejected.outcome.clone()));
+    }
+
+    if let Ok(value) = answer.trim().parse::<i128>() {
+        let bounds = bounds(history, part);
+        if let Some(upper) = bounds.upper.filter(|&upper| value >= upper) {
+            return Err(Refusal::AboveBound(upper.to_string()));
+        }
+        if let Some(lower) = bounds.lower.filter(|&lower| value <= lower) {
+            return Err(Refusal::BelowBound(lower.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the site is not making the account wait after its latest submission.
+/// The wait applies to every day, so `account` holds the submissions of all days, see [`load_all`].
+/// `now` is the current unix timestamp in seconds.
+pub fn check_cooldown(account: &[Submission], now: u64) -> Result<(), Refusal> {
+    let Some(latest) = account.iter().max_by_key(|x| x.timestamp) else {
+        return Ok(());
+    };
+
+    let wait = match latest.outcome {
+        SubmissionOutcome::RateLimited(Some(wait)) => wait,
+        SubmissionOutcome::Wrong | SubmissionOutcome::TooHigh | SubmissionOutcome::TooLow => {
+            WRONG_ANSWER_WAIT
+        }
+        _ => return Ok(()),
+    };
+
+    let until = latest.timestamp + wait.as_secs();
+    if until > now {
+        return Err(Refusal::RateLimited(Duration::from_secs(until - now)));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bounds, check, check_cooldown, parse_submissions, Bounds, Refusal, Submission};
+    use crate::template::aoc_client::SubmissionOutcome;
+    use std::time::Duration;
+
+    fn submission(part: u8, answer: &str, outcome: SubmissionOutcome) -> Submission {
+        Submission {
+            part,
+            answer: answer.into(),
+            outcome,
+            timestamp: 100,
+        }
+    }
+
+    #[test]
+    fn round_trips_submissions() {
+        let submissions = vec![
+            submission(1, "42", SubmissionOutcome::TooLow),
+            submission(
+                1,
+                "43",
+                SubmissionOutcome::RateLimited(Some(Duration::from_secs(30))),
+            ),
+        ];
+        let lines: Vec<String> = submissions
+            .iter()
+            .map(|x| serde_json::to_string(x).unwrap())
+            .collect();
+        assert_eq!(
+            lines[0],
+            r#"{"part":1,"answer":"42","outcome":"too_low","timestamp":100}"#
+        );
+        assert_eq!(parse_submissions(&lines.join("\n")).unwrap(), submissions);
+    }
+
+    #[test]
+    fn computes_bounds() {
+        let history = vec![
+            submission(1, "10", SubmissionOutcome::TooLow),
+            submission(1, "50", SubmissionOutcome::TooHigh),
+            submission(1, "20", SubmissionOutcome::TooLow),
+            submission(1, "40", SubmissionOutcome::TooHigh),
+            submission(2, "30", SubmissionOutcome::TooLow),
+        ];
+        assert_eq!(
+            bounds(&history, 1),
+            Bounds {
+                lower: Some(20),
+                upper: Some(40)
+            }
+        );
+        assert_eq!(bounds(&history, 1).to_string(), "20 < answer < 40");
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let history = vec![
+            submission(1, "abc", SubmissionOutcome::Wrong),
+            submission(1, "10", SubmissionOutcome::TooLow),
+            submission(1, "50", SubmissionOutcome::TooHigh),
+        ];
+        assert_eq!(
+            check(&history, 1, "abc"),
+            Err(Refusal::AlreadyRejected(SubmissionOutcome::Wrong))
+        );
+        assert_eq!(
+            check(&history, 1, "60"),
+            Err(Refusal::AboveBound("50".into()))
+        );
+        assert_eq!(
+            check(&history, 1, "10"),
+            Err(Refusal::AlreadyRejected(SubmissionOutcome::TooLow))
+        );
+        assert_eq!(
+            check(&history, 1, "5"),
+            Err(Refusal::BelowBound("10".into()))
+        );
+        assert_eq!(check(&history, 1, "30"), Ok(()));
+        assert_eq!(check(&history, 2, "60"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let history = vec![submission(1, "30", SubmissionOutcome::Correct)];
+        assert_eq!(
+            check(&history, 1, "31"),
+            Err(Refusal::AlreadySolved("30".into()))
+        );
+    }
+
+    #[test]
+    fn waits_for_rate_limit() {
+        let account = vec![submission(
+            1,
+            "30",
+            SubmissionOutcome::RateLimited(Some(Duration::from_secs(60))),
+        )];
+        assert_eq!(
+            check_cooldown(&account, 130),
+            Err(Refusal::RateLimited(Duration::from_secs(30)))
+        );
+        assert_eq!(check_cooldown(&account, 160), Ok(()));
+    }
+
+    #[test]
+    fn waits_after_wrong_answers_of_any_day() {
+        let mut account = vec![
+            submission(1, "10", SubmissionOutcome::Correct),
+            submission(2, "20", SubmissionOutcome::Wrong),
+        ];
+        account[0].timestamp = 150;
+        assert_eq!(check_cooldown(&account, 155), Ok(()));
+        account[0].timestamp = 50;
+        assert_eq!(
+            check_cooldown(&account, 150),
+            Err(Refusal::RateLimited(Duration::from_secs(10)))
+        );
+        assert_eq!(check_cooldown(&account, 160), Ok(()));
+    }
+}